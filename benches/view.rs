@@ -0,0 +1,64 @@
+//! Measures how long `AppState::view` takes with a large inventory.
+//!
+//! Run with `cargo bench --bench view`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use win_apps_updater::app::AppState;
+use win_apps_updater::models::{AppItem, UpdatableApp};
+
+const ITEMS: usize = 5_000;
+const ITERATIONS: u32 = 200;
+
+fn inventory() -> AppState {
+    let mut state = AppState {
+        loading: false,
+        ..Default::default()
+    };
+    state.apps = (0..ITEMS)
+        .map(|i| {
+            AppItem::from_app(UpdatableApp::new(
+                format!("Application {i}"),
+                format!("Vendor{}.App{i}", i % 97),
+                format!("1.{}.0", i % 13),
+                format!("2.{}.1", i % 7),
+                String::from(if i % 5 == 0 { "msstore" } else { "winget" }),
+            ))
+        })
+        .collect();
+    state
+}
+
+fn bench(label: &str, state: &AppState) {
+    // Warm up allocator and caches.
+    for _ in 0..10 {
+        black_box(state.view());
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(state.view());
+    }
+    let per_view: Duration = start.elapsed() / ITERATIONS;
+    println!("{label:<28} {per_view:>12.2?} per view ({ITEMS} items)");
+}
+
+fn main() {
+    let mut state = inventory();
+    bench("flat, top", &state);
+
+    state.list_scroll = 0.5;
+    state.list_viewport_height = 800.0;
+    bench("flat, middle", &state);
+
+    state.settings.group_by_source = true;
+    bench("grouped by source", &state);
+
+    state.settings.group_by_source = false;
+    state.search_query = String::from("source:winget app 1");
+    bench("filtered", &state);
+
+    state.settings.fuzzy_search = true;
+    state.search_query = String::from("app12");
+    bench("fuzzy", &state);
+}
@@ -0,0 +1,355 @@
+# German UI strings. Missing messages fall back to en.ftl.
+
+app-title = Windows Apps Updater
+title-selected = { $count } ausgewählt
+
+## Toolbar
+
+toolbar-refresh = Aktualisieren
+toolbar-select-all = Alle auswählen
+toolbar-deselect-all = Auswahl aufheben
+toolbar-invert = Umkehren
+toolbar-update-selected = Auswahl updaten
+toolbar-updating = Wird aktualisiert...
+toolbar-settings = Einstellungen
+toolbar-shortcuts = Tastenkürzel
+
+## Search bar
+
+search-placeholder = Apps filtern...  z. B. chrome  source:winget  -id:Microsoft.*  major:true
+search-fuzzy = Unscharf
+search-group-by-source = Nach Quelle gruppieren
+query-unterminated-quote = Anführungszeichen nicht geschlossen
+query-missing-value = Wert für „{ $field }:“ fehlt
+query-nothing-to-negate = Nach „-“ fehlt ein Begriff zum Ausschließen
+query-unknown-filter = Unbekannter Filter „{ $field }:“ (möglich sind name:, id:, source:, major:, selected:)
+query-expects-bool = „{ $field }:“ erwartet true oder false, nicht „{ $value }“
+
+## Status bar
+
+status-loading = Verfügbare Updates werden geladen...
+status-available =
+    { $count ->
+        [one] { $count } App kann aktualisiert werden
+       *[other] { $count } Apps können aktualisiert werden
+    }
+status-changes-new = neu: { $names }
+status-changes-gone = entfernt: { $names }
+status-error = Fehler: { $error }
+status-showing = { $visible } von { $total } angezeigt
+status-no-selection = Keine Apps ausgewählt
+status-updating =
+    { $total ->
+        [one] { $done }/{ $total } App wird aktualisiert...
+       *[other] { $done }/{ $total } Apps werden aktualisiert...
+    }
+status-update-cancelled = Update abgebrochen
+status-update-complete = Update abgeschlossen
+status-updated-app = { $name } aktualisiert
+status-update-app-failed = { $name } konnte nicht aktualisiert werden
+status-update-app-finished = Update von { $name } beendet
+status-scheduled-running =
+    { $action ->
+        [update] Geplantes Update läuft...
+       *[refresh] Geplante Aktualisierung läuft...
+    }
+status-scheduled-nothing = Geplantes Update: nichts zu aktualisieren
+schedule-next-update = Nächstes geplantes Update: { $when }
+schedule-next-refresh = Nächste geplante Aktualisierung: { $when }
+schedule-now = jetzt
+schedule-at = { $day } { $time }
+weekday-mon = Mo
+weekday-tue = Di
+weekday-wed = Mi
+weekday-thu = Do
+weekday-fri = Fr
+weekday-sat = Sa
+weekday-sun = So
+
+## App list
+
+list-loading = Wird geladen...
+list-up-to-date = Alle Apps sind aktuell!
+list-check-again = Klicken Sie auf „Aktualisieren“, um erneut zu prüfen.
+list-no-match = Keine Apps entsprechen dem Filter.
+column-application = Anwendung
+column-id = ID
+column-installed = Installiert
+column-available = Verfügbar
+column-source = Quelle
+group-other = sonstige
+group-selected = { $selected } von { $total } ausgewählt
+countdown-days = { $days } T. { $hours } Std.
+countdown-hours = { $hours } Std. { $minutes } Min.
+countdown-minutes = { $minutes } Min.
+row-available-in = verfügbar in { $time }
+row-new = NEU
+row-update = Update
+row-updating = Läuft...
+
+## Dialogs
+
+common-close = Schließen
+common-done = Fertig
+common-yes = ja
+common-no = nein
+confirm-title = Update bestätigen
+confirm-intro =
+    { $count ->
+        [one] Die folgende App wird aktualisiert:
+       *[other] Die folgenden { $count } Apps werden aktualisiert:
+    }
+confirm-note = Diese Apps müssen vor dem Update eventuell geschlossen werden.
+confirm-proceed = Ja, fortfahren
+confirm-cancel = Abbrechen
+details-first-seen = Zuerst gesehen
+details-cooling-off = Wartezeit
+details-held = zurückgehalten, verfügbar in { $time }
+details-selected = Ausgewählt
+details-scope = Bereich
+details-architecture = Architektur
+details-installer-type = Installertyp
+details-locale = Installersprache
+details-options = Update-Optionen
+details-options-hint = Wird für dieses Paket gespeichert und bei jedem Update verwendet; hat Vorrang vor den Optionsregeln in den Einstellungen.
+arch-any = Beliebige Architektur
+installer-type-any = Beliebiger Installertyp
+details-override-args = Ersetzende Argumente
+details-custom-args = Zusätzliche Argumente
+details-args-hint = Werden als --override (ersetzt die Schalter des Installers) oder --custom (ergänzt sie) übergeben. {"{"}id{"}"} und {"{"}version{"}"} werden durch die Paket-ID und die zu installierende Version ersetzt; Shell-Zeichen wie & | ; < > " sind nicht erlaubt.
+results-title = Update-Ergebnisse
+results-succeeded = { $count } erfolgreich
+results-failed = { $count } fehlgeschlagen
+results-other = { $count } sonstige
+progress-updated =
+    { $total ->
+        [one] { $done }/{ $total } App aktualisiert
+       *[other] { $done }/{ $total } Apps aktualisiert
+    }
+
+## Installed tab
+
+tab-updates = Updates
+tab-installed = Installiert
+inventory-search-placeholder = Installierte Apps nach Name oder ID filtern...
+inventory-unmanaged-only = Nur Apps, die winget nicht verwalten kann
+inventory-unmanaged = nicht verwaltet
+inventory-empty = Keine installierten Apps gefunden.
+status-inventory-loading = Installierte Apps werden geladen...
+status-inventory =
+    { $count ->
+        [one] { $count } Paket installiert
+       *[other] { $count } Pakete installiert
+    }, { $unmanaged } nicht von winget verwaltet
+toolbar-uninstall-selected = Auswahl deinstallieren
+toolbar-uninstalling = Wird deinstalliert...
+status-uninstalling =
+    { $total ->
+        [one] { $done }/{ $total } Paket wird deinstalliert...
+       *[other] { $done }/{ $total } Pakete werden deinstalliert...
+    }
+status-uninstall-cancelled = Deinstallation abgebrochen
+status-uninstall-complete = Deinstallation abgeschlossen
+uninstall-confirm-title = Deinstallation bestätigen
+uninstall-confirm-intro =
+    { $count ->
+        [one] Das folgende Paket wird entfernt:
+       *[other] Die folgenden { $count } Pakete werden entfernt:
+    }
+uninstall-confirm-note = Pakete werden still entfernt; manche Deinstallationsprogramme bestehen auf einem eigenen Fenster und werden für eine interaktive Deinstallation gemeldet.
+uninstall-confirm-proceed = Ja, deinstallieren
+uninstall-results-title = Deinstallationsergebnisse
+progress-uninstalled =
+    { $total ->
+        [one] { $done }/{ $total } Paket deinstalliert
+       *[other] { $done }/{ $total } Pakete deinstalliert
+    }
+
+## Discover tab
+
+tab-discover = Entdecken
+toolbar-search = Suchen
+toolbar-install-selected = Auswahl installieren
+toolbar-installing = Wird installiert...
+discover-search-placeholder = Pakete nach Name, ID, Moniker oder Tag suchen, dann Enter drücken...
+discover-empty = Durchsuchen Sie die konfigurierten Quellen nach Paketen zum Installieren.
+discover-version-latest = neueste
+column-latest = Neueste
+column-match = Treffer
+column-install-version = Zu installieren
+scope-any = Standardbereich
+scope-user = Aktueller Benutzer
+scope-machine = Alle Benutzer
+status-searching = Suche nach „{ $query }“...
+status-search-results =
+    { $count ->
+        [one] { $count } Paket für „{ $query }“ gefunden
+       *[other] { $count } Pakete für „{ $query }“ gefunden
+    }
+status-installing =
+    { $total ->
+        [one] { $done }/{ $total } Paket wird installiert...
+       *[other] { $done }/{ $total } Pakete werden installiert...
+    }
+status-install-cancelled = Installation abgebrochen
+status-install-complete = Installation abgeschlossen
+install-confirm-title = Installation bestätigen
+install-confirm-intro =
+    { $count ->
+        [one] Das folgende Paket wird installiert:
+       *[other] Die folgenden { $count } Pakete werden installiert:
+    }
+install-confirm-note = Bereich: { $scope }. Installationsprogramme laufen still und akzeptieren die Quell- und Paketvereinbarungen.
+install-confirm-proceed = Ja, installieren
+install-results-title = Installationsergebnisse
+progress-installed =
+    { $total ->
+        [one] { $done }/{ $total } Paket installiert
+       *[other] { $done }/{ $total } Pakete installiert
+    }
+
+## Package sets
+
+toolbar-export = Exportieren
+toolbar-import = Importieren
+package-file-placeholder = Pfad zu packages.json
+status-export-nothing = Wählen Sie von winget verwaltete Pakete zum Exportieren aus
+status-exported =
+    { $count ->
+        [one] { $count } Paket nach { $path } exportiert
+       *[other] { $count } Pakete nach { $path } exportiert
+    }
+status-import-loading = Paketliste wird gelesen...
+status-import-nothing = Nichts zu importieren: alle Pakete sind bereits installiert
+status-import-cancelled = Import abgebrochen
+import-title = Paketliste importieren
+import-intro = Nötige Änderungen, um { $path } zu entsprechen:
+import-installs = { $count } zu installieren
+import-upgrades = { $count } zu aktualisieren
+import-skips = { $count } aktuell
+import-action-install = installieren
+import-action-upgrade = aktualisieren von { $installed }
+import-action-skip = installiert ({ $installed })
+import-proceed = Installieren & aktualisieren
+
+## Desired state
+
+toolbar-check-drift = Abweichungen prüfen
+settings-manifest = Sollzustands-Manifest
+settings-manifest-hint = TOML-Datei mit Paket-IDs und "latest", ">=Version", "=Version" oder "absent"
+status-drift-no-manifest = Legen Sie zuerst in den Einstellungen ein Sollzustands-Manifest fest
+status-drift-checking = Abweichungen werden geprüft...
+status-drift =
+    { $count ->
+        [0] Keine Abweichungen: Alle Pakete entsprechen dem Manifest
+        [one] { $count } Paket weicht vom Manifest ab
+       *[other] { $count } Pakete weichen vom Manifest ab
+    }
+status-drift-nothing = Nichts anzuwenden: Die übrigen Abweichungen erfordern eine Änderung des Manifests
+drift-title = Abweichungsbericht
+drift-intro = Pakete, die nicht { $path } entsprechen:
+drift-none = Alle Pakete entsprechen dem Manifest.
+drift-missing = fehlt
+drift-outdated = veraltet: { $installed } → { $target }
+drift-forbidden = unerwünscht: { $installed } installiert
+drift-over-constrained = nicht erfüllbar: { $installed } installiert, { $available } verfügbar
+drift-nothing-newer = nichts Neueres
+drift-note = Beim Anwenden werden fehlende Pakete installiert und veraltete aktualisiert. Unerwünschte Pakete werden im Tab "Installiert" zur Deinstallation ausgewählt; nicht erfüllbare bleiben unverändert.
+drift-apply = Anwenden
+
+## Sources
+
+tab-sources = Quellen
+toolbar-update-sources = Alle aktualisieren
+toolbar-reset-sources = Alle zurücksetzen
+source-name-placeholder = Name
+source-argument-placeholder = URL, z. B. https://packages.contoso.com/api
+source-type-placeholder = Typ (optional), z. B. Microsoft.Rest
+source-accept-agreements = Quellvereinbarungen akzeptieren
+source-add = Quelle hinzufügen
+source-explicit = explizit
+source-update = Aktualisieren
+source-reset = Zurücksetzen
+source-remove = Entfernen
+sources-empty = Keine Quellen konfiguriert
+column-enabled = Aktiv
+column-argument = Argument
+status-sources-loading = Quellen werden geladen...
+status-sources =
+    { $count ->
+        [one] { $count } Quelle konfiguriert
+       *[other] { $count } Quellen konfiguriert
+    }
+status-source-incomplete = Geben Sie einen Namen und eine URL für die neue Quelle ein
+status-source-running = winget-Quellbefehl wird ausgeführt...
+status-sources-failed = einige Quellen konnten nicht abgefragt werden: { $errors }
+source-msstore = Microsoft Store
+store-app-name = Microsoft Store-App { $id }
+
+## Notifications
+
+notify-updates-title =
+    { $count ->
+        [one] { $count } neues Update verfügbar
+       *[other] { $count } neue Updates verfügbar
+    }
+notify-more-names = { $names } und { $count } weitere
+notify-batch-title = Update abgeschlossen
+
+## Keyboard shortcuts
+
+help-title = Tastenkürzel
+shortcut-refresh = Liste aktualisieren
+shortcut-focus-search = Suchfeld fokussieren
+shortcut-select-all = Alle sichtbaren Apps auswählen
+shortcut-deselect-all = Auswahl aller sichtbaren Apps aufheben
+shortcut-move-cursor = Zeilencursor bewegen
+shortcut-toggle-cursor = App am Cursor aus- oder abwählen
+shortcut-details = Details der App am Cursor anzeigen
+shortcut-update = Ausgewählte Apps aktualisieren
+shortcut-close = Geöffneten Dialog schließen
+shortcut-help = Diese Hilfe ein- oder ausblenden
+
+## Settings
+
+settings-title = Einstellungen
+settings-language = Sprache
+settings-language-system = Systemstandard
+settings-theme = Design
+settings-theme-hint = „Systemeinstellung“ wechselt mit dem Desktop zwischen dunkel und hell.
+theme-system = Systemeinstellung
+theme-dark = Dunkel
+theme-light = Hell
+theme-high-contrast = Hoher Kontrast
+settings-cooldown = Wartezeit (Tage)
+settings-cooldown-hint = Neue Versionen sind erst auswählbar, wenn sie so lange verfügbar sind. 0 deaktiviert.
+settings-refresh = Hintergrundaktualisierung (Minuten)
+settings-refresh-hint = Sucht im Leerlauf erneut nach Updates und behält Ihre Auswahl. 0 deaktiviert.
+settings-schedules = Zeitpläne
+settings-schedules-hint = <refresh|update> <daily|weekdays|weekends|mon-fri|sat,sun> <HH:MM>-<HH:MM>, getrennt durch ';'.
+settings-ignored = Ignorierte Pakete
+settings-ignored-hint = Kommagetrennte IDs, die geplante Updates nie anfassen.
+settings-notifications = Benachrichtigungen
+settings-mute = Desktop-Benachrichtigungen stummschalten
+settings-muted-hint = Kommagetrennte IDs, die nie Benachrichtigungen auslösen.
+settings-row-updates = Zeilen-Updates
+settings-skip-row-confirmation = Update-Schaltfläche einer Zeile ohne Bestätigung starten
+settings-store = Microsoft Store
+settings-store-exclude = Microsoft Store-Apps nicht auflisten
+settings-store-agreements = Beim Aktualisieren von Store-Apps die Microsoft Store-Vereinbarungen akzeptieren
+settings-store-hint = Store-Apps verwenden Produkt-IDs wie 9NBLGGH4NNS1; manche lassen sich nur über den Store selbst aktualisieren.
+settings-option-rules = Regeln für Update-Optionen
+settings-option-rules-hint = Durch Semikolon getrennte Paket-IDs oder Muster mit den Optionen scope, arch, type und locale. In den Paketdetails gewählte Optionen haben Vorrang.
+schedule-invalid = Ungültiger Zeitplan „{ $schedule }“: erwartet wird „<refresh|update> <Tage> <HH:MM>-<HH:MM>“
+schedule-unknown-action = Unbekannte Zeitplan-Aktion „{ $action }“
+schedule-invalid-window = Ungültiges Zeitfenster „{ $window }“: erwartet wird HH:MM-HH:MM
+schedule-invalid-time = Ungültige Uhrzeit „{ $time }“
+schedule-unknown-day = Unbekannter Tag „{ $day }“
+option-rule-missing-pattern = Paket-ID oder Muster fehlt
+option-rule-no-options = Keine Optionen für „{ $pattern }“ angegeben
+options-expected-pair = Schlüssel=Wert erwartet, gefunden wurde „{ $part }“
+options-unknown-scope = Unbekannter Bereich „{ $value }“
+options-unknown-architecture = Unbekannte Architektur „{ $value }“
+options-unknown-installer-type = Unbekannter Installertyp „{ $value }“
+options-invalid-locale = Ungültiges Gebietsschema „{ $value }“
+options-unknown-option = Unbekannte Option „{ $option }“
@@ -0,0 +1,356 @@
+# English UI strings. This is the reference catalog: every message used by
+# the app must exist here; other languages fall back to it per message.
+
+app-title = Windows Apps Updater
+title-selected = { $count } selected
+
+## Toolbar
+
+toolbar-refresh = Refresh
+toolbar-select-all = Select All
+toolbar-deselect-all = Deselect All
+toolbar-invert = Invert
+toolbar-update-selected = Update Selected
+toolbar-updating = Updating...
+toolbar-settings = Settings
+toolbar-shortcuts = Shortcuts
+
+## Search bar
+
+search-placeholder = Filter apps...  e.g. chrome  source:winget  -id:Microsoft.*  major:true
+search-fuzzy = Fuzzy
+search-group-by-source = Group by source
+query-unterminated-quote = Unterminated quote
+query-missing-value = Missing value for '{ $field }:'
+query-nothing-to-negate = Nothing to negate after '-'
+query-unknown-filter = Unknown filter '{ $field }:' (try name:, id:, source:, major:, selected:)
+query-expects-bool = '{ $field }:' expects true or false, got '{ $value }'
+
+## Status bar
+
+status-loading = Loading updatable apps...
+status-available =
+    { $count ->
+        [one] { $count } app available for update
+       *[other] { $count } apps available for update
+    }
+status-changes-new = new: { $names }
+status-changes-gone = gone: { $names }
+status-error = Error: { $error }
+status-showing = showing { $visible } of { $total }
+status-no-selection = No apps selected
+status-updating =
+    { $total ->
+        [one] Updating { $done }/{ $total } app...
+       *[other] Updating { $done }/{ $total } apps...
+    }
+status-update-cancelled = Update cancelled
+status-update-complete = Update complete
+status-updated-app = Updated { $name }
+status-update-app-failed = Failed to update { $name }
+status-update-app-finished = Finished updating { $name }
+status-scheduled-running =
+    { $action ->
+        [update] Scheduled update running...
+       *[refresh] Scheduled refresh running...
+    }
+status-scheduled-nothing = Scheduled update: nothing to update
+schedule-next-update = Next scheduled update: { $when }
+schedule-next-refresh = Next scheduled refresh: { $when }
+schedule-now = now
+schedule-at = { $day } { $time }
+weekday-mon = Mon
+weekday-tue = Tue
+weekday-wed = Wed
+weekday-thu = Thu
+weekday-fri = Fri
+weekday-sat = Sat
+weekday-sun = Sun
+
+## App list
+
+list-loading = Loading...
+list-up-to-date = All apps are up to date!
+list-check-again = Click Refresh to check again.
+list-no-match = No apps match your filter.
+column-application = Application
+column-id = ID
+column-installed = Installed
+column-available = Available
+column-source = Source
+group-other = other
+group-selected = { $selected } of { $total } selected
+countdown-days = { $days }d { $hours }h
+countdown-hours = { $hours }h { $minutes }m
+countdown-minutes = { $minutes }m
+row-available-in = available in { $time }
+row-new = NEW
+row-update = Update
+row-updating = Updating...
+
+## Dialogs
+
+common-close = Close
+common-done = Done
+common-yes = yes
+common-no = no
+confirm-title = Confirm Update
+confirm-intro =
+    { $count ->
+        [one] The following app will be updated:
+       *[other] The following { $count } apps will be updated:
+    }
+confirm-note = These apps may need to be closed before updating.
+confirm-proceed = Yes, Proceed
+confirm-cancel = Cancel
+details-first-seen = First seen
+details-cooling-off = Cooling-off
+details-held = held, available in { $time }
+details-selected = Selected
+details-scope = Scope
+details-architecture = Architecture
+details-installer-type = Installer type
+details-locale = Installer locale
+details-options = Upgrade options
+details-options-hint = Remembered for this package and used for every upgrade; takes precedence over the option rules in Settings.
+arch-any = Any architecture
+installer-type-any = Any installer type
+details-override-args = Override arguments
+details-custom-args = Custom arguments
+details-args-hint = Passed as --override (replacing the installer's own switches) or --custom (added to them). {"{"}id{"}"} and {"{"}version{"}"} expand to the package ID and the version being installed; shell characters such as & | ; < > " are rejected.
+results-title = Update Results
+results-succeeded = { $count } succeeded
+results-failed = { $count } failed
+results-other = { $count } other
+progress-updated =
+    { $total ->
+        [one] { $done }/{ $total } app updated
+       *[other] { $done }/{ $total } apps updated
+    }
+
+## Installed tab
+
+tab-updates = Updates
+tab-installed = Installed
+inventory-search-placeholder = Filter installed apps by name or ID...
+inventory-unmanaged-only = Only apps winget can't manage
+inventory-unmanaged = unmanaged
+inventory-empty = No installed apps found.
+status-inventory-loading = Loading installed apps...
+status-inventory =
+    { $count ->
+        [one] { $count } package installed
+       *[other] { $count } packages installed
+    }, { $unmanaged } not managed by winget
+toolbar-uninstall-selected = Uninstall Selected
+toolbar-uninstalling = Uninstalling...
+status-uninstalling =
+    { $total ->
+        [one] Uninstalling { $done }/{ $total } package...
+       *[other] Uninstalling { $done }/{ $total } packages...
+    }
+status-uninstall-cancelled = Uninstall cancelled
+status-uninstall-complete = Uninstall complete
+uninstall-confirm-title = Confirm Uninstall
+uninstall-confirm-intro =
+    { $count ->
+        [one] The following package will be removed:
+       *[other] The following { $count } packages will be removed:
+    }
+uninstall-confirm-note = Packages are removed silently; some uninstallers still insist on their own window and are reported for an interactive uninstall.
+uninstall-confirm-proceed = Yes, Uninstall
+uninstall-results-title = Uninstall Results
+progress-uninstalled =
+    { $total ->
+        [one] { $done }/{ $total } package uninstalled
+       *[other] { $done }/{ $total } packages uninstalled
+    }
+
+## Discover tab
+
+tab-discover = Discover
+toolbar-search = Search
+toolbar-install-selected = Install Selected
+toolbar-installing = Installing...
+discover-search-placeholder = Search packages by name, ID, moniker or tag, then press Enter...
+discover-empty = Search the configured sources to find packages to install.
+discover-version-latest = latest
+column-latest = Latest
+column-match = Match
+column-install-version = Install version
+scope-any = Default scope
+scope-user = Current user
+scope-machine = All users
+status-searching = Searching for "{ $query }"...
+status-search-results =
+    { $count ->
+        [one] { $count } package found for "{ $query }"
+       *[other] { $count } packages found for "{ $query }"
+    }
+status-installing =
+    { $total ->
+        [one] Installing { $done }/{ $total } package...
+       *[other] Installing { $done }/{ $total } packages...
+    }
+status-install-cancelled = Install cancelled
+status-install-complete = Install complete
+install-confirm-title = Confirm Install
+install-confirm-intro =
+    { $count ->
+        [one] The following package will be installed:
+       *[other] The following { $count } packages will be installed:
+    }
+install-confirm-note = Scope: { $scope }. Installers run silently and accept the source and package agreements.
+install-confirm-proceed = Yes, Install
+install-results-title = Install Results
+progress-installed =
+    { $total ->
+        [one] { $done }/{ $total } package installed
+       *[other] { $done }/{ $total } packages installed
+    }
+
+## Package sets
+
+toolbar-export = Export
+toolbar-import = Import
+package-file-placeholder = Path to packages.json
+status-export-nothing = Select packages managed by winget to export
+status-exported =
+    { $count ->
+        [one] Exported { $count } package to { $path }
+       *[other] Exported { $count } packages to { $path }
+    }
+status-import-loading = Reading package set...
+status-import-nothing = Nothing to import: every package is already installed
+status-import-cancelled = Import cancelled
+import-title = Import Package Set
+import-intro = Changes needed to match { $path }:
+import-installs = { $count } to install
+import-upgrades = { $count } to upgrade
+import-skips = { $count } up to date
+import-action-install = install
+import-action-upgrade = upgrade from { $installed }
+import-action-skip = installed ({ $installed })
+import-proceed = Install & Upgrade
+
+## Desired state
+
+toolbar-check-drift = Check Drift
+settings-manifest = Desired-state manifest
+settings-manifest-hint = TOML file listing package IDs with "latest", ">=version", "=version" or "absent"
+status-drift-no-manifest = Set a desired-state manifest in Settings first
+status-drift-checking = Checking for drift...
+status-drift =
+    { $count ->
+        [0] No drift: every package matches the manifest
+        [one] { $count } package drifts from the manifest
+       *[other] { $count } packages drift from the manifest
+    }
+status-drift-nothing = Nothing to apply: the remaining drift needs a manifest change
+drift-title = Drift Report
+drift-intro = Packages that don't match { $path }:
+drift-none = Every package matches the manifest.
+drift-missing = missing
+drift-outdated = outdated: { $installed } → { $target }
+drift-forbidden = forbidden: { $installed } installed
+drift-over-constrained = over-constrained: { $installed } installed, { $available } available
+drift-nothing-newer = nothing newer
+drift-note = Applying installs missing and upgrades outdated packages. Forbidden packages are selected on the Installed tab for uninstalling; over-constrained ones are left alone.
+drift-apply = Apply
+
+## Sources
+
+tab-sources = Sources
+toolbar-update-sources = Update All
+toolbar-reset-sources = Reset All
+source-name-placeholder = Name
+source-argument-placeholder = URL, e.g. https://packages.contoso.com/api
+source-type-placeholder = Type (optional), e.g. Microsoft.Rest
+source-accept-agreements = Accept source agreements
+source-add = Add Source
+source-explicit = explicit
+source-update = Update
+source-reset = Reset
+source-remove = Remove
+sources-empty = No sources configured
+column-enabled = Enabled
+column-argument = Argument
+status-sources-loading = Loading sources...
+status-sources =
+    { $count ->
+        [one] { $count } source configured
+       *[other] { $count } sources configured
+    }
+status-source-incomplete = Enter a name and URL for the new source
+status-source-running = Running winget source command...
+status-sources-failed = some sources could not be queried: { $errors }
+source-msstore = Microsoft Store
+store-app-name = Microsoft Store app { $id }
+
+## Notifications
+
+notify-updates-title =
+    { $count ->
+        [one] { $count } new update available
+       *[other] { $count } new updates available
+    }
+notify-more-names = { $names } and { $count } more
+notify-batch-title = Update complete
+
+## Keyboard shortcuts
+
+help-title = Keyboard shortcuts
+shortcut-refresh = Refresh the list
+shortcut-focus-search = Focus the search bar
+shortcut-select-all = Select all visible apps
+shortcut-deselect-all = Deselect all visible apps
+shortcut-move-cursor = Move the row cursor
+shortcut-toggle-cursor = Toggle the app at the cursor
+shortcut-details = Show details of the app at the cursor
+shortcut-update = Update selected apps
+shortcut-close = Close the open dialog
+shortcut-help = Show or hide this help
+
+## Settings
+
+settings-title = Settings
+settings-language = Language
+settings-language-system = System default
+settings-theme = Theme
+settings-theme-hint = "Follow system" switches between dark and light with the desktop.
+theme-system = Follow system
+theme-dark = Dark
+theme-light = Light
+theme-high-contrast = High contrast
+settings-cooldown = Cooling-off period (days)
+settings-cooldown-hint = New releases stay unselectable until they have been available this long. 0 disables.
+settings-refresh = Background refresh interval (minutes)
+settings-refresh-hint = Re-checks for updates while idle, keeping your selection. 0 disables.
+settings-schedules = Schedules
+settings-schedules-hint = <refresh|update> <daily|weekdays|weekends|mon-fri|sat,sun> <HH:MM>-<HH:MM>, separated by ';'.
+settings-ignored = Ignored packages
+settings-ignored-hint = Comma-separated IDs that scheduled updates never touch.
+settings-notifications = Notifications
+settings-mute = Mute desktop notifications
+settings-muted-hint = Comma-separated IDs that never trigger notifications.
+settings-row-updates = Row updates
+settings-skip-row-confirmation = Start a row's Update button without confirmation
+settings-store = Microsoft Store
+settings-store-exclude = Leave Microsoft Store apps out of the lists
+settings-store-agreements = Accept the Microsoft Store agreements when updating store apps
+settings-store-hint = Store apps use product IDs such as 9NBLGGH4NNS1; some can only be updated by the Store itself.
+settings-option-rules = Upgrade option rules
+settings-option-rules-hint = Semicolon-separated package IDs or patterns with scope, arch, type and locale options. Options chosen for a package in its details take precedence.
+schedule-invalid = Invalid schedule '{ $schedule }': expected '<refresh|update> <days> <HH:MM>-<HH:MM>'
+schedule-unknown-action = Unknown schedule action '{ $action }'
+schedule-invalid-window = Invalid time window '{ $window }': expected HH:MM-HH:MM
+schedule-invalid-time = Invalid time '{ $time }'
+schedule-unknown-day = Unknown day '{ $day }'
+option-rule-missing-pattern = Missing package ID pattern
+option-rule-no-options = No options given for '{ $pattern }'
+options-expected-pair = Expected key=value, found '{ $part }'
+options-unknown-scope = Unknown scope '{ $value }'
+options-unknown-architecture = Unknown architecture '{ $value }'
+options-unknown-installer-type = Unknown installer type '{ $value }'
+options-invalid-locale = Invalid locale '{ $value }'
+options-unknown-option = Unknown option '{ $option }'
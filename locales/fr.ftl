@@ -0,0 +1,382 @@
+# French UI strings. Missing messages fall back to en.ftl.
+
+app-title = Windows Apps Updater
+title-selected =
+    { $count ->
+        [one] { $count } sélectionnée
+       *[other] { $count } sélectionnées
+    }
+
+## Toolbar
+
+toolbar-refresh = Actualiser
+toolbar-select-all = Tout sélectionner
+toolbar-deselect-all = Tout désélectionner
+toolbar-invert = Inverser
+toolbar-update-selected = Mettre à jour
+toolbar-updating = Mise à jour...
+toolbar-settings = Paramètres
+toolbar-shortcuts = Raccourcis
+
+## Search bar
+
+search-placeholder = Filtrer les applications...  ex. chrome  source:winget  -id:Microsoft.*  major:true
+search-fuzzy = Approximative
+search-group-by-source = Grouper par source
+query-unterminated-quote = Guillemet non fermé
+query-missing-value = Valeur manquante pour « { $field }: »
+query-nothing-to-negate = Rien à exclure après « - »
+query-unknown-filter = Filtre inconnu « { $field }: » (essayez name:, id:, source:, major:, selected:)
+query-expects-bool = « { $field }: » attend true ou false, pas « { $value } »
+
+## Status bar
+
+status-loading = Chargement des mises à jour disponibles...
+status-available =
+    { $count ->
+        [one] { $count } application à mettre à jour
+       *[other] { $count } applications à mettre à jour
+    }
+status-changes-new = nouvelles : { $names }
+status-changes-gone = disparues : { $names }
+status-error = Erreur : { $error }
+status-showing = { $visible } sur { $total } affichées
+status-no-selection = Aucune application sélectionnée
+status-updating =
+    { $total ->
+        [one] Mise à jour de { $done }/{ $total } application...
+       *[other] Mise à jour de { $done }/{ $total } applications...
+    }
+status-update-cancelled = Mise à jour annulée
+status-update-complete = Mise à jour terminée
+status-updated-app = { $name } mis à jour
+status-update-app-failed = Échec de la mise à jour de { $name }
+status-update-app-finished = Mise à jour de { $name } terminée
+status-scheduled-running =
+    { $action ->
+        [update] Mise à jour planifiée en cours...
+       *[refresh] Actualisation planifiée en cours...
+    }
+status-scheduled-nothing = Mise à jour planifiée : rien à mettre à jour
+schedule-next-update = Prochaine mise à jour planifiée : { $when }
+schedule-next-refresh = Prochaine actualisation planifiée : { $when }
+schedule-now = maintenant
+schedule-at = { $day } { $time }
+weekday-mon = lun.
+weekday-tue = mar.
+weekday-wed = mer.
+weekday-thu = jeu.
+weekday-fri = ven.
+weekday-sat = sam.
+weekday-sun = dim.
+
+## App list
+
+list-loading = Chargement...
+list-up-to-date = Toutes les applications sont à jour !
+list-check-again = Cliquez sur Actualiser pour vérifier à nouveau.
+list-no-match = Aucune application ne correspond au filtre.
+column-application = Application
+column-id = ID
+column-installed = Installée
+column-available = Disponible
+column-source = Source
+group-other = autre
+group-selected =
+    { $selected ->
+        [one] { $selected } sur { $total } sélectionnée
+       *[other] { $selected } sur { $total } sélectionnées
+    }
+countdown-days = { $days } j { $hours } h
+countdown-hours = { $hours } h { $minutes } min
+countdown-minutes = { $minutes } min
+row-available-in = disponible dans { $time }
+row-new = NOUVEAU
+row-update = Mettre à jour
+row-updating = En cours...
+
+## Dialogs
+
+common-close = Fermer
+common-done = Terminé
+common-yes = oui
+common-no = non
+confirm-title = Confirmer la mise à jour
+confirm-intro =
+    { $count ->
+        [one] L'application suivante sera mise à jour :
+       *[other] Les { $count } applications suivantes seront mises à jour :
+    }
+confirm-note = Ces applications devront peut-être être fermées avant la mise à jour.
+confirm-proceed = Oui, continuer
+confirm-cancel = Annuler
+details-first-seen = Vue pour la première fois
+details-cooling-off = Délai d'attente
+details-held = retenue, disponible dans { $time }
+details-selected = Sélectionnée
+details-scope = Portée
+details-architecture = Architecture
+details-installer-type = Type d'installateur
+details-locale = Langue de l'installateur
+details-options = Options de mise à jour
+details-options-hint = Mémorisées pour ce paquet et utilisées à chaque mise à jour ; elles priment sur les règles d'options des paramètres.
+arch-any = Toute architecture
+installer-type-any = Tout type d'installateur
+details-override-args = Arguments de remplacement
+details-custom-args = Arguments supplémentaires
+details-args-hint = Passés via --override (remplace les options de l'installateur) ou --custom (s'y ajoute). {"{"}id{"}"} et {"{"}version{"}"} sont remplacés par l'identifiant du paquet et la version installée ; les caractères du shell comme & | ; < > " sont refusés.
+results-title = Résultats de la mise à jour
+results-succeeded =
+    { $count ->
+        [one] { $count } réussie
+       *[other] { $count } réussies
+    }
+results-failed =
+    { $count ->
+        [one] { $count } échouée
+       *[other] { $count } échouées
+    }
+results-other =
+    { $count ->
+        [one] { $count } autre
+       *[other] { $count } autres
+    }
+progress-updated =
+    { $done ->
+        [one] { $done }/{ $total } application mise à jour
+       *[other] { $done }/{ $total } applications mises à jour
+    }
+
+## Installed tab
+
+tab-updates = Mises à jour
+tab-installed = Installées
+inventory-search-placeholder = Filtrer les applications installées par nom ou ID...
+inventory-unmanaged-only = Seulement celles que winget ne gère pas
+inventory-unmanaged = non gérée
+inventory-empty = Aucune application installée trouvée.
+status-inventory-loading = Chargement des applications installées...
+status-inventory =
+    { $count ->
+        [one] { $count } paquet installé
+       *[other] { $count } paquets installés
+    }, { $unmanaged ->
+        [one] { $unmanaged } non géré par winget
+       *[other] { $unmanaged } non gérés par winget
+    }
+toolbar-uninstall-selected = Désinstaller
+toolbar-uninstalling = Désinstallation...
+status-uninstalling =
+    { $total ->
+        [one] Désinstallation de { $done }/{ $total } paquet...
+       *[other] Désinstallation de { $done }/{ $total } paquets...
+    }
+status-uninstall-cancelled = Désinstallation annulée
+status-uninstall-complete = Désinstallation terminée
+uninstall-confirm-title = Confirmer la désinstallation
+uninstall-confirm-intro =
+    { $count ->
+        [one] Le paquet suivant sera supprimé :
+       *[other] Les { $count } paquets suivants seront supprimés :
+    }
+uninstall-confirm-note = Les paquets sont supprimés en silence ; certains programmes de désinstallation exigent leur propre fenêtre et sont signalés pour une désinstallation interactive.
+uninstall-confirm-proceed = Oui, désinstaller
+uninstall-results-title = Résultats de la désinstallation
+progress-uninstalled =
+    { $done ->
+        [one] { $done }/{ $total } paquet désinstallé
+       *[other] { $done }/{ $total } paquets désinstallés
+    }
+
+## Discover tab
+
+tab-discover = Découvrir
+toolbar-search = Rechercher
+toolbar-install-selected = Installer
+toolbar-installing = Installation...
+discover-search-placeholder = Rechercher des paquets par nom, ID, moniker ou tag, puis Entrée...
+discover-empty = Recherchez dans les sources configurées des paquets à installer.
+discover-version-latest = dernière
+column-latest = Dernière
+column-match = Correspondance
+column-install-version = Version à installer
+scope-any = Portée par défaut
+scope-user = Utilisateur actuel
+scope-machine = Tous les utilisateurs
+status-searching = Recherche de « { $query } »...
+status-search-results =
+    { $count ->
+        [one] { $count } paquet trouvé pour « { $query } »
+       *[other] { $count } paquets trouvés pour « { $query } »
+    }
+status-installing =
+    { $total ->
+        [one] Installation de { $done }/{ $total } paquet...
+       *[other] Installation de { $done }/{ $total } paquets...
+    }
+status-install-cancelled = Installation annulée
+status-install-complete = Installation terminée
+install-confirm-title = Confirmer l'installation
+install-confirm-intro =
+    { $count ->
+        [one] Le paquet suivant sera installé :
+       *[other] Les { $count } paquets suivants seront installés :
+    }
+install-confirm-note = Portée : { $scope }. Les programmes d'installation s'exécutent en silence et acceptent les contrats de la source et du paquet.
+install-confirm-proceed = Oui, installer
+install-results-title = Résultats de l'installation
+progress-installed =
+    { $done ->
+        [one] { $done }/{ $total } paquet installé
+       *[other] { $done }/{ $total } paquets installés
+    }
+
+## Package sets
+
+toolbar-export = Exporter
+toolbar-import = Importer
+package-file-placeholder = Chemin de packages.json
+status-export-nothing = Sélectionnez des paquets gérés par winget à exporter
+status-exported =
+    { $count ->
+        [one] { $count } paquet exporté vers { $path }
+       *[other] { $count } paquets exportés vers { $path }
+    }
+status-import-loading = Lecture de la liste de paquets...
+status-import-nothing = Rien à importer : tous les paquets sont déjà installés
+status-import-cancelled = Importation annulée
+import-title = Importer une liste de paquets
+import-intro = Modifications nécessaires pour correspondre à { $path } :
+import-installs = { $count } à installer
+import-upgrades = { $count } à mettre à jour
+import-skips = { $count } à jour
+import-action-install = installer
+import-action-upgrade = mettre à jour depuis { $installed }
+import-action-skip = installé ({ $installed })
+import-proceed = Installer et mettre à jour
+
+## Desired state
+
+toolbar-check-drift = Vérifier les écarts
+settings-manifest = Manifeste d'état souhaité
+settings-manifest-hint = Fichier TOML listant des ID de paquets avec "latest", ">=version", "=version" ou "absent"
+status-drift-no-manifest = Définissez d'abord un manifeste d'état souhaité dans les paramètres
+status-drift-checking = Vérification des écarts...
+status-drift =
+    { $count ->
+        [0] Aucun écart : tous les paquets correspondent au manifeste
+        [one] { $count } paquet s'écarte du manifeste
+       *[other] { $count } paquets s'écartent du manifeste
+    }
+status-drift-nothing = Rien à appliquer : les écarts restants nécessitent une modification du manifeste
+drift-title = Rapport d'écarts
+drift-intro = Paquets qui ne correspondent pas à { $path } :
+drift-none = Tous les paquets correspondent au manifeste.
+drift-missing = manquant
+drift-outdated = obsolète : { $installed } → { $target }
+drift-forbidden = interdit : { $installed } installé
+drift-over-constrained = contrainte insatisfiable : { $installed } installé, { $available } disponible
+drift-nothing-newer = rien de plus récent
+drift-note = L'application installe les paquets manquants et met à jour les paquets obsolètes. Les paquets interdits sont sélectionnés dans l'onglet Installées pour être désinstallés ; les contraintes insatisfiables sont ignorées.
+drift-apply = Appliquer
+
+## Sources
+
+tab-sources = Sources
+toolbar-update-sources = Tout mettre à jour
+toolbar-reset-sources = Tout réinitialiser
+source-name-placeholder = Nom
+source-argument-placeholder = URL, p. ex. https://packages.contoso.com/api
+source-type-placeholder = Type (facultatif), p. ex. Microsoft.Rest
+source-accept-agreements = Accepter les contrats de la source
+source-add = Ajouter la source
+source-explicit = explicite
+source-update = Mettre à jour
+source-reset = Réinitialiser
+source-remove = Supprimer
+sources-empty = Aucune source configurée
+column-enabled = Active
+column-argument = Argument
+status-sources-loading = Chargement des sources...
+status-sources =
+    { $count ->
+        [one] { $count } source configurée
+       *[other] { $count } sources configurées
+    }
+status-source-incomplete = Saisissez un nom et une URL pour la nouvelle source
+status-source-running = Exécution de la commande de source winget...
+status-sources-failed = certaines sources n'ont pas pu être interrogées : { $errors }
+source-msstore = Microsoft Store
+store-app-name = Application Microsoft Store { $id }
+
+## Notifications
+
+notify-updates-title =
+    { $count ->
+        [one] { $count } nouvelle mise à jour disponible
+       *[other] { $count } nouvelles mises à jour disponibles
+    }
+notify-more-names =
+    { $count ->
+        [one] { $names } et { $count } autre
+       *[other] { $names } et { $count } autres
+    }
+notify-batch-title = Mise à jour terminée
+
+## Keyboard shortcuts
+
+help-title = Raccourcis clavier
+shortcut-refresh = Actualiser la liste
+shortcut-focus-search = Aller à la barre de recherche
+shortcut-select-all = Sélectionner toutes les applications visibles
+shortcut-deselect-all = Désélectionner toutes les applications visibles
+shortcut-move-cursor = Déplacer le curseur de ligne
+shortcut-toggle-cursor = Cocher ou décocher l'application sous le curseur
+shortcut-details = Afficher les détails de l'application sous le curseur
+shortcut-update = Mettre à jour les applications sélectionnées
+shortcut-close = Fermer la boîte de dialogue ouverte
+shortcut-help = Afficher ou masquer cette aide
+
+## Settings
+
+settings-title = Paramètres
+settings-language = Langue
+settings-language-system = Langue du système
+settings-theme = Thème
+settings-theme-hint = « Suivre le système » passe du sombre au clair avec le bureau.
+theme-system = Suivre le système
+theme-dark = Sombre
+theme-light = Clair
+theme-high-contrast = Contraste élevé
+settings-cooldown = Délai d'attente (jours)
+settings-cooldown-hint = Les nouvelles versions restent non sélectionnables tant qu'elles ne sont pas disponibles depuis ce délai. 0 désactive.
+settings-refresh = Intervalle d'actualisation en arrière-plan (minutes)
+settings-refresh-hint = Vérifie à nouveau les mises à jour en cas d'inactivité, en conservant votre sélection. 0 désactive.
+settings-schedules = Planifications
+settings-schedules-hint = <refresh|update> <daily|weekdays|weekends|mon-fri|sat,sun> <HH:MM>-<HH:MM>, séparées par ';'.
+settings-ignored = Paquets ignorés
+settings-ignored-hint = ID séparés par des virgules que les mises à jour planifiées ne touchent jamais.
+settings-notifications = Notifications
+settings-mute = Désactiver les notifications du bureau
+settings-muted-hint = ID séparés par des virgules qui ne déclenchent jamais de notification.
+settings-row-updates = Mises à jour par ligne
+settings-skip-row-confirmation = Lancer le bouton Mettre à jour d'une ligne sans confirmation
+settings-store = Microsoft Store
+settings-store-exclude = Exclure les applications du Microsoft Store des listes
+settings-store-agreements = Accepter les contrats du Microsoft Store lors de la mise à jour des applications du Store
+settings-store-hint = Les applications du Store utilisent des ID de produit comme 9NBLGGH4NNS1 ; certaines ne peuvent être mises à jour que par le Store lui-même.
+settings-option-rules = Règles d'options de mise à jour
+settings-option-rules-hint = Identifiants ou motifs de paquets séparés par des points-virgules, avec les options scope, arch, type et locale. Les options choisies dans les détails d'un paquet priment.
+schedule-invalid = Planification « { $schedule } » invalide : format attendu « <refresh|update> <jours> <HH:MM>-<HH:MM> »
+schedule-unknown-action = Action de planification inconnue « { $action } »
+schedule-invalid-window = Plage horaire « { $window } » invalide : format attendu HH:MM-HH:MM
+schedule-invalid-time = Heure « { $time } » invalide
+schedule-unknown-day = Jour inconnu « { $day } »
+option-rule-missing-pattern = Identifiant ou motif de paquet manquant
+option-rule-no-options = Aucune option indiquée pour « { $pattern } »
+options-expected-pair = clé=valeur attendu, « { $part } » trouvé
+options-unknown-scope = Portée inconnue « { $value } »
+options-unknown-architecture = Architecture inconnue « { $value } »
+options-unknown-installer-type = Type d'installateur inconnu « { $value } »
+options-invalid-locale = Langue « { $value } » invalide
+options-unknown-option = Option inconnue « { $option } »
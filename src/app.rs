@@ -1,7 +1,43 @@
-use crate::models::{AppItem, Message, UpdatableApp};
-use crate::ui::build_view;
-use crate::winget::{get_updatable_apps, update_single_app};
-use iced::{Element, Task};
+use crate::cooldown::{unix_now, FirstSeen, FIRST_SEEN_FILE};
+use crate::filter::{parse_query, Query};
+use crate::fuzzy::{match_app, FuzzyMatch};
+use crate::i18n::{system_language, Catalog, FluentValue, Language, LocalizedError};
+use crate::manifest::{check_manifest, Drift, DriftItem};
+use crate::models::{
+    AppItem, ArgsProfile, BatchAction, InstallRequest, InstallScope, InstalledApp, Message,
+    PackageOptions, SearchResult, SortColumn, SortOrder, SourceAction, SourceInfo, Tab,
+    UpdatableApp, UpgradeRequest,
+};
+use crate::notify::{self, MemoryNotifier, Notification, Notifier};
+use crate::packages::{default_package_file, plan_import, ImportAction, ImportItem, PackageSet};
+use crate::scheduler::{
+    format_schedules, local_now, parse_schedules, weekday_label_id, Schedule, ScheduledAction,
+    SchedulerState, SCHEDULER_FILE, TICK_SECS,
+};
+use crate::settings::{
+    self, format_option_rules, parse_id_list, parse_option_rules, save_json, Settings, ThemeChoice,
+    SETTINGS_FILE,
+};
+use crate::shortcuts::shortcut;
+use crate::ui::{
+    app_list_id, build_view, search_input_id, system_prefers_dark, Palette, SYSTEM_THEME_POLL_SECS,
+};
+use crate::winget::{
+    get_installed_apps, get_installed_apps_excluding, get_updatable_apps_excluding,
+    install_single_app, list_sources, run_source_action, search_packages, uninstall_single_app,
+    update_single_app, STORE_SOURCE,
+};
+use chrono::Datelike;
+use iced::keyboard::{self, Key, Modifiers};
+use iced::widget::{scrollable, text_input};
+use iced::{event, window, Element, Event, Subscription, Task, Theme};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A visible row: index into [`AppState::apps`] and its fuzzy match, if any.
+pub type VisibleRow = (usize, Option<FuzzyMatch>);
 
 /// Main application state.
 pub struct AppState {
@@ -11,11 +47,13 @@ pub struct AppState {
     pub loading: bool,
     /// Status message shown to the user.
     pub status_message: String,
-    /// Whether updates are currently running.
+    /// Whether a batch (updates or uninstalls) is currently running.
     pub updating: bool,
+    /// What the current or last batch does to each package.
+    pub batch_action: BatchAction,
     /// Whether the confirmation dialog is visible.
     pub show_confirmation: bool,
-    /// Apps pending update as `(name, id)` pairs.
+    /// Packages pending the next batch as `(name, id)` pairs.
     pub pending_updates: Vec<(String, String)>,
     /// Whether the results dialog is visible.
     pub show_results_dialog: bool,
@@ -29,15 +67,152 @@ pub struct AppState {
     pub update_completed: usize,
     /// All app IDs queued for the current update batch (`update_completed` is the cursor).
     pub update_queue: Vec<String>,
+    /// User preferences.
+    pub settings: Settings,
+    /// When each offered release was first observed (for the cooling-off period).
+    pub first_seen: FirstSeen,
+    /// Directory where settings and history are persisted (`None` disables persistence).
+    pub data_dir: Option<PathBuf>,
+    /// Whether the settings dialog is visible.
+    pub show_settings: bool,
+    /// Raw text of the cooling-off period input.
+    pub cooldown_input: String,
+    /// When each schedule last ran.
+    pub scheduler: SchedulerState,
+    /// Whether the in-flight refresh was started by an `update` schedule.
+    pub scheduled_update: bool,
+    /// Schedule that started the in-flight refresh; it counts as run once
+    /// the refresh succeeds.
+    pub scheduled_run: Option<Schedule>,
+    /// Raw text of the schedules input.
+    pub schedules_input: String,
+    /// Parse error for the schedules input, if any.
+    pub schedules_error: Option<LocalizedError>,
+    /// Raw text of the upgrade option rules field.
+    pub option_rules_input: String,
+    /// Parse error for the upgrade option rules field, if any.
+    pub option_rules_error: Option<LocalizedError>,
+    /// Errors of the sources that failed during the last refresh.
+    pub source_errors: Vec<String>,
+    /// Raw text of the details dialog's `--override` field.
+    pub override_args_input: String,
+    /// Raw text of the details dialog's `--custom` field.
+    pub custom_args_input: String,
+    /// Validation error for the arguments profile, if any.
+    pub args_profile_error: Option<String>,
+    /// Raw text of the ignore-list input.
+    pub ignored_input: String,
+    /// Whether the in-flight refresh was started without user interaction.
+    pub background_refresh: bool,
+    /// Delivers desktop notifications.
+    pub notifier: Arc<dyn Notifier>,
+    /// Raw text of the muted-packages input.
+    pub muted_input: String,
+    /// Raw text of the background refresh interval input.
+    pub refresh_interval_input: String,
+    /// Unix time of the last successful refresh.
+    pub last_refreshed: Option<u64>,
+    /// Vertical scroll position of the app list (relative, `0.0..=1.0`).
+    pub list_scroll: f32,
+    /// Height of the app list viewport in logical pixels (`0.0` until first reported).
+    pub list_viewport_height: f32,
+    /// Height of the main window in logical pixels (`0.0` until first reported).
+    pub window_height: f32,
+    /// Active sort order of the app list (`None` keeps winget's order).
+    pub sort: Option<SortOrder>,
+    /// Sources whose section is collapsed while grouping by source.
+    pub collapsed_sources: HashSet<String>,
+    /// ID of the app under the keyboard row cursor.
+    pub cursor: Option<String>,
+    /// ID of the app shown in the details dialog.
+    pub details: Option<String>,
+    /// Whether the keyboard shortcut help is visible.
+    pub show_help: bool,
+    /// Currently held keyboard modifiers (for shift-click range selection).
+    pub modifiers: Modifiers,
+    /// ID of the app last toggled individually; anchors range selection.
+    pub selection_anchor: Option<String>,
+    /// Whether the current batch was started from a row's "Update" button.
+    pub single_update: bool,
+    /// Last update result per app ID, shown as a badge on the row.
+    pub row_results: HashMap<String, String>,
+    /// Whether the desktop uses a dark colour scheme (for [`ThemeChoice::System`]).
+    pub system_dark: bool,
+    /// Language of the desktop session (used when no language is chosen).
+    pub system_language: Language,
+    /// Translated UI strings for the active language.
+    pub catalog: Catalog,
+    /// The view shown below the title bar.
+    pub tab: Tab,
+    /// Installed packages from the last `winget list`.
+    pub inventory: Vec<InstalledApp>,
+    /// Whether the inventory is currently loading.
+    pub inventory_loading: bool,
+    /// Shows only packages winget can't manage in the inventory.
+    pub unmanaged_only: bool,
+    /// Vertical scroll position of the inventory (relative, `0.0..=1.0`).
+    pub inventory_scroll: f32,
+    /// Height of the inventory viewport in logical pixels (`0.0` until first reported).
+    pub inventory_viewport_height: f32,
+    /// IDs of the inventory packages selected for uninstalling.
+    pub inventory_selected: HashSet<String>,
+    /// Whether the uninstall confirmation dialog is visible.
+    pub show_uninstall_confirmation: bool,
+    /// Text of the discover search input.
+    pub discover_query: String,
+    /// Packages found by the last `winget search`.
+    pub discover_results: Vec<SearchResult>,
+    /// Whether a search is running.
+    pub discover_loading: bool,
+    /// IDs of the search results selected for installing.
+    pub discover_selected: HashSet<String>,
+    /// Version typed per package ID (missing or empty installs the latest).
+    pub install_versions: HashMap<String, String>,
+    /// Scope used for new installs.
+    pub install_scope: InstallScope,
+    /// Packages queued for the pending or running install batch.
+    pub install_requests: Vec<InstallRequest>,
+    /// Whether the install confirmation dialog is visible.
+    pub show_install_confirmation: bool,
+    /// Vertical scroll position of the search results (relative, `0.0..=1.0`).
+    pub discover_scroll: f32,
+    /// Height of the search results viewport in logical pixels (`0.0` until first reported).
+    pub discover_viewport_height: f32,
+    /// Path of the `packages.json` file to export to or import from.
+    pub package_file: String,
+    /// What importing the package set would do, shown in the preview dialog.
+    pub import_plan: Vec<ImportItem>,
+    /// Whether the import preview dialog is visible.
+    pub show_import_preview: bool,
+    /// Result of the last manifest check; `Some` while the drift dialog is visible.
+    pub drift_report: Option<Vec<DriftItem>>,
+    /// Sources listed by `winget source list`.
+    pub sources: Vec<SourceInfo>,
+    /// Whether the sources are being loaded.
+    pub sources_loading: bool,
+    /// Whether a `winget source` command is running.
+    pub source_busy: bool,
+    /// Name of the source to add, as typed.
+    pub source_name_input: String,
+    /// URL of the source to add, as typed.
+    pub source_argument_input: String,
+    /// Type of the source to add, as typed (empty for winget's default).
+    pub source_type_input: String,
+    /// Whether adding a source accepts its agreements.
+    pub accept_source_agreements: bool,
+    /// Result string of the last source command.
+    pub source_result: Option<String>,
 }
 
 impl Default for AppState {
     fn default() -> Self {
+        let catalog = Catalog::default();
         Self {
             apps: Vec::new(),
             loading: true,
-            status_message: String::from("Loading updatable apps..."),
+            status_message: catalog.tr("status-loading"),
             updating: false,
+            batch_action: BatchAction::Update,
             show_confirmation: false,
             pending_updates: Vec::new(),
             show_results_dialog: false,
@@ -46,6 +221,73 @@ impl Default for AppState {
             update_total: 0,
             update_completed: 0,
             update_queue: Vec::new(),
+            settings: Settings::default(),
+            first_seen: FirstSeen::default(),
+            data_dir: None,
+            show_settings: false,
+            cooldown_input: String::from("0"),
+            scheduler: SchedulerState::default(),
+            scheduled_update: false,
+            scheduled_run: None,
+            schedules_input: String::new(),
+            schedules_error: None,
+            option_rules_input: String::new(),
+            option_rules_error: None,
+            source_errors: Vec::new(),
+            override_args_input: String::new(),
+            custom_args_input: String::new(),
+            args_profile_error: None,
+            ignored_input: String::new(),
+            background_refresh: false,
+            notifier: Arc::new(MemoryNotifier::default()),
+            muted_input: String::new(),
+            refresh_interval_input: String::from("0"),
+            last_refreshed: None,
+            list_scroll: 0.0,
+            list_viewport_height: 0.0,
+            window_height: 0.0,
+            sort: None,
+            collapsed_sources: HashSet::new(),
+            cursor: None,
+            details: None,
+            show_help: false,
+            modifiers: Modifiers::empty(),
+            selection_anchor: None,
+            single_update: false,
+            row_results: HashMap::new(),
+            system_dark: true,
+            system_language: Language::default(),
+            catalog,
+            tab: Tab::Updates,
+            inventory: Vec::new(),
+            inventory_loading: false,
+            unmanaged_only: false,
+            inventory_scroll: 0.0,
+            inventory_viewport_height: 0.0,
+            inventory_selected: HashSet::new(),
+            show_uninstall_confirmation: false,
+            discover_query: String::new(),
+            discover_results: Vec::new(),
+            discover_loading: false,
+            discover_selected: HashSet::new(),
+            install_versions: HashMap::new(),
+            install_scope: InstallScope::default(),
+            install_requests: Vec::new(),
+            show_install_confirmation: false,
+            discover_scroll: 0.0,
+            discover_viewport_height: 0.0,
+            package_file: default_package_file().display().to_string(),
+            import_plan: Vec::new(),
+            show_import_preview: false,
+            drift_report: None,
+            sources: Vec::new(),
+            sources_loading: false,
+            source_busy: false,
+            source_name_input: String::new(),
+            source_argument_input: String::new(),
+            source_type_input: String::new(),
+            accept_source_agreements: false,
+            source_result: None,
         }
     }
 }
@@ -53,32 +295,222 @@ impl Default for AppState {
 impl AppState {
     /// Creates a new `AppState` and returns it with an initial load task.
     pub fn new() -> (Self, Task<Message>) {
-        (
-            Self::default(),
-            Task::perform(async { get_updatable_apps() }, Message::AppsLoaded),
-        )
+        let mut state = Self::with_data_dir(settings::data_dir());
+        state.system_dark = system_prefers_dark();
+        state.system_language = system_language();
+        state.reload_catalog();
+        state.status_message = state.tr("status-loading");
+        let load = state.load_apps_task();
+        (state, load)
     }
 
-    /// Returns the count of currently selected apps.
-    pub fn selected_count(&self) -> usize {
-        self.apps.iter().filter(|a| a.selected).count()
+    /// Creates a state that loads and persists its settings and history in `data_dir`.
+    pub fn with_data_dir(data_dir: Option<PathBuf>) -> Self {
+        let mut state = Self::default();
+        if let Some(dir) = &data_dir {
+            state.settings = settings::load_json(&dir.join(SETTINGS_FILE));
+            state.reload_catalog();
+            state.first_seen = settings::load_json(&dir.join(FIRST_SEEN_FILE));
+            state.scheduler = settings::load_json(&dir.join(SCHEDULER_FILE));
+        }
+        state.reset_settings_inputs();
+        state.data_dir = data_dir;
+        state.notifier = notify::system_notifier();
+        state
     }
 
-    /// Returns indices of apps visible after filtering by the search query.
-    pub fn visible_indices(&self) -> Vec<usize> {
-        self.apps
+    /// Returns the periodic scheduler tick and background refresh timers
+    /// for whichever of them are enabled.
+    pub fn subscription(&self) -> Subscription<Message> {
+        let mut subscriptions = Vec::new();
+        if !self.settings.schedules.is_empty() {
+            subscriptions.push(
+                iced::time::every(Duration::from_secs(TICK_SECS)).map(|_| Message::SchedulerTick),
+            );
+        }
+        if self.settings.refresh_interval_mins > 0 {
+            let interval = Duration::from_secs(u64::from(self.settings.refresh_interval_mins) * 60);
+            subscriptions.push(iced::time::every(interval).map(|_| Message::BackgroundRefresh));
+        }
+        if self.settings.theme == ThemeChoice::System {
+            subscriptions.push(
+                iced::time::every(Duration::from_secs(SYSTEM_THEME_POLL_SECS))
+                    .map(|_| Message::CheckSystemTheme),
+            );
+        }
+        subscriptions.push(keyboard::on_key_press(|key, modifiers| {
+            Some(Message::KeyPressed(key, modifiers))
+        }));
+        subscriptions.push(event::listen_with(|event, _, _| match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                Some(Message::ModifiersChanged(modifiers))
+            }
+            Event::Window(window::Event::Opened { size, .. } | window::Event::Resized(size)) => {
+                Some(Message::WindowResized(size.height))
+            }
+            _ => None,
+        }));
+        Subscription::batch(subscriptions)
+    }
+
+    /// Returns a short description of the next scheduled run, if any.
+    pub fn next_scheduled_run(&self) -> Option<String> {
+        let now = local_now();
+        let (when, schedule) = self.scheduler.next_run(&self.settings.schedules, now)?;
+        let when = if when <= now {
+            self.tr("schedule-now")
+        } else {
+            let day = self.tr(weekday_label_id(when.weekday()));
+            let time = when.format("%H:%M").to_string();
+            self.tr_args("schedule-at", &[("day", day.into()), ("time", time.into())])
+        };
+        let id = match schedule.action {
+            ScheduledAction::Update => "schedule-next-update",
+            ScheduledAction::Refresh => "schedule-next-refresh",
+        };
+        Some(self.tr_args(id, &[("when", when.into())]))
+    }
+
+    /// Returns the inventory indices matching the search box and the
+    /// unmanaged-only filter, in `winget list` order.
+    pub fn visible_inventory(&self) -> Vec<usize> {
+        self.inventory
             .iter()
             .enumerate()
-            .filter(|(_, item)| item.matches_search(&self.search_query))
+            .filter(|(_, app)| !self.unmanaged_only || !app.is_managed())
+            .filter(|(_, app)| app.matches_search(&self.search_query))
             .map(|(i, _)| i)
             .collect()
     }
 
+    /// Returns the count of currently selected apps.
+    pub fn selected_count(&self) -> usize {
+        self.apps.iter().filter(|a| a.selected).count()
+    }
+
+    /// Returns `(succeeded, failed, other)` counts for the last update batch.
+    pub fn result_counts(&self) -> (usize, usize, usize) {
+        tally_results(self.update_results.iter().map(String::as_str))
+    }
+
+    /// Returns indices of apps visible after filtering by the search query
+    /// (see [`parse_query`]), in display order.
+    pub fn visible_indices(&self) -> Vec<usize> {
+        self.visible_matches().into_iter().map(|(i, _)| i).collect()
+    }
+
+    /// Like [`visible_indices`](Self::visible_indices), but also returns the
+    /// fuzzy match for each row when fuzzy search is enabled.
+    ///
+    /// In fuzzy mode the free-text part of the query is matched as a
+    /// subsequence and rows are ranked by score; the active column sort only
+    /// breaks ties.
+    pub fn visible_matches(&self) -> Vec<VisibleRow> {
+        // An unparsable query falls back to plain substring matching so the
+        // list keeps responding while the user is still typing.
+        let query = parse_query(&self.search_query);
+        let mut rows: Vec<VisibleRow> = if self.settings.fuzzy_search {
+            let (pattern, rest) = match query {
+                Ok(query) => query.split_text(),
+                Err(_) => (self.search_query.clone(), Query::default()),
+            };
+            self.apps
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| rest.matches(item))
+                .filter_map(|(i, item)| match_app(&pattern, &item.app).map(|m| (i, Some(m))))
+                .collect()
+        } else {
+            self.apps
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| match &query {
+                    Ok(query) => query.matches(item),
+                    Err(_) => item.matches_search(&self.search_query),
+                })
+                .map(|(i, _)| (i, None))
+                .collect()
+        };
+
+        if let Some(order) = self.sort {
+            rows.sort_by(|&(a, _), &(b, _)| {
+                let ord = order.column.compare(&self.apps[a].app, &self.apps[b].app);
+                if order.ascending {
+                    ord
+                } else {
+                    ord.reverse()
+                }
+            });
+        }
+        // Stable, so rows with equal scores keep the column order.
+        rows.sort_by_key(|(_, m)| std::cmp::Reverse(m.as_ref().map_or(0, |m| m.score)));
+        rows
+    }
+
+    /// Splits the visible rows into sections per source, ordered by source
+    /// name. Rows keep their display order within a section.
+    pub fn source_groups(&self) -> Vec<(String, Vec<VisibleRow>)> {
+        let mut groups: Vec<(String, Vec<VisibleRow>)> = Vec::new();
+        for (idx, matched) in self.visible_matches() {
+            let source = &self.apps[idx].app.source;
+            match groups.iter_mut().find(|(s, _)| s == source) {
+                Some((_, rows)) => rows.push((idx, matched)),
+                None => groups.push((source.clone(), vec![(idx, matched)])),
+            }
+        }
+        groups.sort_by_key(|(source, _)| source.to_lowercase());
+        groups
+    }
+
+    /// Returns indices of apps in the order they are shown, skipping rows in
+    /// collapsed source sections.
+    pub fn display_order(&self) -> Vec<usize> {
+        if self.settings.group_by_source {
+            self.source_groups()
+                .into_iter()
+                .filter(|(source, _)| !self.collapsed_sources.contains(source))
+                .flat_map(|(_, rows)| rows.into_iter().map(|(i, _)| i))
+                .collect()
+        } else {
+            self.visible_indices()
+        }
+    }
+
+    /// Returns the index of the app under the row cursor, if it is loaded.
+    pub fn cursor_index(&self) -> Option<usize> {
+        let id = self.cursor.as_ref()?;
+        self.apps.iter().position(|item| &item.app.id == id)
+    }
+
+    /// Returns `true` if a batch is waiting for the user to confirm it.
+    pub fn confirmation_open(&self) -> bool {
+        self.show_confirmation
+            || self.show_uninstall_confirmation
+            || self.show_install_confirmation
+            || self.show_import_preview
+            || self.drift_report.is_some()
+    }
+
+    /// Returns `true` if any dialog is covering the main window.
+    pub fn overlay_open(&self) -> bool {
+        self.confirmation_open()
+            || self.show_results_dialog
+            || self.show_settings
+            || self.details.is_some()
+            || self.show_help
+    }
+
     /// Updates the application state based on the given message.
     pub fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::LoadApps => self.handle_load_apps(),
-            Message::AppsLoaded(result) => self.handle_apps_loaded(result),
+            Message::AppsLoaded(result) => {
+                let result = result.map(|(apps, errors)| {
+                    self.source_errors = errors;
+                    apps
+                });
+                self.handle_apps_loaded(result)
+            }
             Message::ToggleApp(index) => self.handle_toggle_app(index),
             Message::UpdateSelected => self.handle_update_selected(),
             Message::UpdateSingleComplete(result) => self.handle_update_single_complete(result),
@@ -88,9 +520,200 @@ impl AppState {
             Message::CancelUpdate => self.handle_cancel_update(),
             Message::CloseResultsDialog => self.handle_close_results_dialog(),
             Message::SearchChanged(query) => self.handle_search_changed(query),
+            Message::OpenSettings => self.handle_open_settings(),
+            Message::CloseSettings => self.handle_close_settings(),
+            Message::CooldownDaysChanged(input) => self.handle_cooldown_days_changed(input),
+            Message::SchedulesChanged(input) => self.handle_schedules_changed(input),
+            Message::IgnoredIdsChanged(input) => self.handle_ignored_ids_changed(input),
+            Message::SchedulerTick => self.handle_scheduler_tick(),
+            Message::MuteNotificationsToggled(muted) => self.handle_mute_notifications(muted),
+            Message::MutedIdsChanged(input) => self.handle_muted_ids_changed(input),
+            Message::BackgroundRefresh => self.handle_background_refresh(),
+            Message::RefreshIntervalChanged(input) => self.handle_refresh_interval_changed(input),
+            Message::ListScrolled(viewport) => self.handle_list_scrolled(viewport),
+            Message::WindowResized(height) => self.handle_window_resized(height),
+            Message::SortBy(column) => self.handle_sort_by(column),
+            Message::FuzzySearchToggled(enabled) => self.handle_fuzzy_search_toggled(enabled),
+            Message::GroupBySourceToggled(enabled) => self.handle_group_by_source_toggled(enabled),
+            Message::ToggleSourceCollapsed(source) => self.handle_toggle_source_collapsed(source),
+            Message::SelectSource(source, selected) => self.handle_select_source(source, selected),
+            Message::KeyPressed(key, modifiers) => self.handle_key_pressed(key, modifiers),
+            Message::FocusSearch => text_input::focus(search_input_id()),
+            Message::MoveCursor(delta) => self.handle_move_cursor(delta),
+            Message::ToggleCursor => self.handle_toggle_cursor(),
+            Message::OpenDetails => self.handle_open_details(),
+            Message::CloseDetails => self.handle_close_details(),
+            Message::ToggleHelp => self.handle_toggle_help(),
+            Message::CloseOverlay => self.handle_close_overlay(),
+            Message::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers;
+                Task::none()
+            }
+            Message::SelectRange(index) => self.handle_select_range(index),
+            Message::InvertSelection => self.handle_invert_selection(),
+            Message::UpdateNow(index) => self.handle_update_now(index),
+            Message::SkipRowUpdateConfirmationToggled(skip) => {
+                self.handle_skip_row_update_confirmation(skip)
+            }
+            Message::ThemeChanged(choice) => self.handle_theme_changed(choice),
+            Message::CheckSystemTheme => {
+                Task::perform(async { system_prefers_dark() }, Message::SystemThemeDetected)
+            }
+            Message::SystemThemeDetected(dark) => {
+                self.system_dark = dark;
+                Task::none()
+            }
+            Message::LanguageChanged(language) => self.handle_language_changed(language),
+            Message::TabSelected(tab) => self.handle_tab_selected(tab),
+            Message::LoadInventory => self.handle_load_inventory(),
+            Message::InventoryLoaded(result) => self.handle_inventory_loaded(result),
+            Message::UnmanagedOnlyToggled(enabled) => {
+                self.unmanaged_only = enabled;
+                Task::none()
+            }
+            Message::InventoryScrolled(viewport) => {
+                self.inventory_scroll = viewport.relative_offset().y;
+                self.inventory_viewport_height = viewport.bounds().height;
+                Task::none()
+            }
+            Message::ToggleInventoryApp(index) => self.handle_toggle_inventory_app(index),
+            Message::UninstallSelected => self.handle_uninstall_selected(),
+            Message::ConfirmUninstall => self.handle_confirm_uninstall(),
+            Message::CancelUninstall => self.handle_cancel_uninstall(),
+            Message::DiscoverQueryChanged(query) => {
+                self.discover_query = query;
+                Task::none()
+            }
+            Message::SearchPackages => self.handle_search_packages(),
+            Message::SearchResultsLoaded(result) => self.handle_search_results_loaded(result),
+            Message::ToggleSearchResult(index) => self.handle_toggle_search_result(index),
+            Message::InstallVersionChanged(id, version) => {
+                self.install_versions.insert(id, version);
+                Task::none()
+            }
+            Message::InstallScopeChanged(scope) => {
+                self.install_scope = scope;
+                Task::none()
+            }
+            Message::InstallSelected => self.handle_install_selected(),
+            Message::ConfirmInstall => self.handle_confirm_install(),
+            Message::CancelInstall => self.handle_cancel_install(),
+            Message::DiscoverScrolled(viewport) => {
+                self.discover_scroll = viewport.relative_offset().y;
+                self.discover_viewport_height = viewport.bounds().height;
+                Task::none()
+            }
+            Message::PackageFileChanged(path) => {
+                self.package_file = path;
+                Task::none()
+            }
+            Message::ExportPackages => self.handle_export_packages(),
+            Message::ImportPackages => self.handle_import_packages(),
+            Message::ImportLoaded(result) => self.handle_import_loaded(result),
+            Message::ConfirmImport => self.handle_confirm_import(),
+            Message::CancelImport => self.handle_cancel_import(),
+            Message::ManifestPathChanged(path) => {
+                self.settings.manifest_path = path;
+                self.persist(SETTINGS_FILE, |s| &s.settings);
+                Task::none()
+            }
+            Message::CheckDrift => self.handle_check_drift(),
+            Message::DriftChecked(result) => self.handle_drift_checked(result),
+            Message::ApplyDrift => self.handle_apply_drift(),
+            Message::CloseDrift => {
+                self.drift_report = None;
+                Task::none()
+            }
+            Message::LoadSources => self.handle_load_sources(),
+            Message::SourcesLoaded(result) => self.handle_sources_loaded(result),
+            Message::SourceNameChanged(name) => {
+                self.source_name_input = name;
+                Task::none()
+            }
+            Message::SourceArgumentChanged(argument) => {
+                self.source_argument_input = argument;
+                Task::none()
+            }
+            Message::SourceTypeChanged(kind) => {
+                self.source_type_input = kind;
+                Task::none()
+            }
+            Message::AcceptSourceAgreementsToggled(accept) => {
+                self.accept_source_agreements = accept;
+                Task::none()
+            }
+            Message::AddSource => self.handle_add_source(),
+            Message::RunSourceAction(action) => self.handle_run_source_action(action),
+            Message::SourceActionComplete(result) => self.handle_source_action_complete(result),
+            Message::SourceEnabledToggled(name, enabled) => {
+                self.handle_source_enabled_toggled(name, enabled)
+            }
+            Message::AcceptStoreAgreementsToggled(accept) => {
+                self.settings.accept_store_agreements = accept;
+                self.persist(SETTINGS_FILE, |s| &s.settings);
+                Task::none()
+            }
+            Message::PackageOptionsChanged(id, options) => {
+                self.handle_package_options_changed(id, options)
+            }
+            Message::OptionRulesChanged(input) => self.handle_option_rules_changed(input),
+            Message::OverrideArgsChanged(input) => {
+                self.override_args_input = input;
+                self.handle_args_profile_changed()
+            }
+            Message::CustomArgsChanged(input) => {
+                self.custom_args_input = input;
+                self.handle_args_profile_changed()
+            }
+        }
+    }
+
+    /// Returns the translated UI string `id`.
+    pub fn tr(&self, id: &str) -> String {
+        self.catalog.tr(id)
+    }
+
+    /// Returns the translated UI string `id` with its arguments filled in.
+    pub fn tr_args(&self, id: &str, args: &[(&str, FluentValue<'_>)]) -> String {
+        self.catalog.tr_args(id, args)
+    }
+
+    /// Returns the translated text of an input error.
+    pub fn tr_error(&self, error: &LocalizedError) -> String {
+        self.catalog.tr_error(error)
+    }
+
+    /// Describes the progress of the running batch, e.g. "Updating 1/3 apps...".
+    fn updating_status(&self) -> String {
+        let id = match self.batch_action {
+            BatchAction::Update => "status-updating",
+            BatchAction::Uninstall => "status-uninstalling",
+            BatchAction::Install => "status-installing",
+        };
+        self.tr_args(
+            id,
+            &[("done", self.update_completed.into()), ("total", self.update_total.into())],
+        )
+    }
+
+    /// Loads the catalog for the chosen language, or the system's.
+    fn reload_catalog(&mut self) {
+        let language = self.settings.language.unwrap_or(self.system_language);
+        if self.catalog.language() != language {
+            self.catalog = Catalog::new(language);
         }
     }
 
+    /// Returns the iced theme for the chosen theme setting.
+    pub fn theme(&self) -> Theme {
+        self.settings.theme.theme(self.system_dark)
+    }
+
+    /// Returns the colours of the active theme.
+    pub fn palette(&self) -> &'static Palette {
+        self.settings.theme.palette(self.system_dark)
+    }
+
     /// Renders the view.
     pub fn view(&self) -> Element<'_, Message> {
         build_view(self)
@@ -100,9 +723,61 @@ impl AppState {
 
     fn handle_load_apps(&mut self) -> Task<Message> {
         self.loading = true;
-        self.search_query.clear();
-        self.status_message = String::from("Loading updatable apps...");
-        Task::perform(async { get_updatable_apps() }, Message::AppsLoaded)
+        self.status_message = self.tr("status-loading");
+        self.load_apps_task()
+    }
+
+    fn handle_tab_selected(&mut self, tab: Tab) -> Task<Message> {
+        self.tab = tab;
+        if tab == Tab::Installed && self.inventory.is_empty() && !self.inventory_loading {
+            return self.handle_load_inventory();
+        }
+        if tab == Tab::Sources && self.sources.is_empty() && !self.sources_loading {
+            return self.handle_load_sources();
+        }
+        Task::none()
+    }
+
+    /// Lists the updatable apps of the enabled sources.
+    fn load_apps_task(&self) -> Task<Message> {
+        let disabled = self.settings.disabled_sources.clone();
+        Task::perform(
+            async move { get_updatable_apps_excluding(&disabled) },
+            Message::AppsLoaded,
+        )
+    }
+
+    fn handle_load_inventory(&mut self) -> Task<Message> {
+        self.inventory_loading = true;
+        self.status_message = self.tr("status-inventory-loading");
+        let disabled = self.settings.disabled_sources.clone();
+        Task::perform(
+            async move { get_installed_apps_excluding(&disabled) },
+            Message::InventoryLoaded,
+        )
+    }
+
+    fn handle_inventory_loaded(
+        &mut self,
+        result: Result<Vec<InstalledApp>, String>,
+    ) -> Task<Message> {
+        self.inventory_loading = false;
+        match result {
+            Ok(apps) => {
+                let unmanaged = apps.iter().filter(|app| !app.is_managed()).count();
+                self.status_message = self.tr_args(
+                    "status-inventory",
+                    &[("count", apps.len().into()), ("unmanaged", unmanaged.into())],
+                );
+                self.inventory_selected
+                    .retain(|id| apps.iter().any(|app| &app.id == id));
+                self.inventory = apps;
+            }
+            Err(e) => {
+                self.status_message = self.tr_args("status-error", &[("error", e.into())]);
+            }
+        }
+        Task::none()
     }
 
     fn handle_apps_loaded(&mut self, result: Result<Vec<UpdatableApp>, String>) -> Task<Message> {
@@ -110,33 +785,139 @@ impl AppState {
         match result {
             Ok(apps) => {
                 let count = apps.len();
-                self.apps = apps.into_iter().map(AppItem::from).collect();
-                self.status_message = format!("{count} app(s) available for update");
+                let now = unix_now();
+                let scheduled = self.scheduled_run.take();
+                if let Some(schedule) = &scheduled {
+                    self.scheduler.mark_run(schedule, now);
+                }
+                let notification = std::mem::take(&mut self.background_refresh)
+                    .then(|| self.new_updates_notification(&apps))
+                    .flatten();
+                // Packages of disabled or unreachable sources are missing from
+                // the listing but keep their first-seen times for when they
+                // return.
+                let complete = self.settings.disabled_sources.is_empty()
+                    && self.source_errors.is_empty();
+                let recorded = self.first_seen.record(&apps, now, complete);
+                let first_load = self.last_refreshed.is_none();
+                let (appeared, disappeared) = self.reconcile_apps(apps, !first_load);
+                // Drop the cursor and details of apps that are no longer offered.
+                let loaded = |id: &String| self.apps.iter().any(|item| &item.app.id == id);
+                if !self.cursor.as_ref().is_some_and(loaded) {
+                    self.cursor = None;
+                }
+                if !self.details.as_ref().is_some_and(loaded) {
+                    self.details = None;
+                }
+                self.last_refreshed = Some(now);
+                self.apply_cooldown(now);
+                self.status_message = self.tr_args("status-available", &[("count", count.into())]);
+                if !first_load {
+                    let changes = describe_changes(&self.catalog, &appeared, &disappeared);
+                    self.status_message.push_str(&changes);
+                }
+                if !self.source_errors.is_empty() {
+                    let errors = std::mem::take(&mut self.source_errors).join("; ");
+                    let failed = self.tr_args("status-sources-failed", &[("errors", errors.into())]);
+                    self.status_message.push_str(&format!(" ({failed})"));
+                }
+                // Sent and saved last so a failure replaces the status above.
+                if let Some(notification) = notification {
+                    self.send_notification(&notification);
+                }
+                if scheduled.is_some() {
+                    self.persist(SCHEDULER_FILE, |s| &s.scheduler);
+                }
+                if recorded {
+                    self.persist(FIRST_SEEN_FILE, |s| &s.first_seen);
+                }
+                if std::mem::take(&mut self.scheduled_update) {
+                    return self.start_scheduled_update();
+                }
+                return scrollable::snap_to(
+                    app_list_id(),
+                    scrollable::RelativeOffset {
+                        x: 0.0,
+                        y: self.list_scroll,
+                    },
+                );
             }
             Err(e) => {
-                self.status_message = format!("Error: {e}");
+                // A failed scheduled refresh is retried on the next tick.
+                self.scheduled_run = None;
+                self.scheduled_update = false;
+                self.background_refresh = false;
+                self.status_message = self.tr_args("status-error", &[("error", e.into())]);
             }
         }
         Task::none()
     }
 
     fn handle_toggle_app(&mut self, index: usize) -> Task<Message> {
+        let now = unix_now();
         if let Some(app) = self.apps.get_mut(index) {
-            app.selected = !app.selected;
+            if !app.is_held(now) {
+                app.selected = !app.selected;
+                self.selection_anchor = Some(app.app.id.clone());
+            }
+        }
+        Task::none()
+    }
+
+    fn handle_select_range(&mut self, index: usize) -> Task<Message> {
+        let order = self.display_order();
+        let anchor = self
+            .selection_anchor
+            .as_ref()
+            .and_then(|id| order.iter().position(|&i| &self.apps[i].app.id == id));
+        let clicked = order.iter().position(|&i| i == index);
+        let (Some(anchor), Some(clicked)) = (anchor, clicked) else {
+            return self.handle_toggle_app(index);
+        };
+
+        let now = unix_now();
+        let selected = !self.apps[index].selected;
+        for &i in &order[anchor.min(clicked)..=anchor.max(clicked)] {
+            if !self.apps[i].is_held(now) {
+                self.apps[i].selected = selected;
+            }
+        }
+        Task::none()
+    }
+
+    fn handle_invert_selection(&mut self) -> Task<Message> {
+        let now = unix_now();
+        for idx in self.visible_indices() {
+            let item = &mut self.apps[idx];
+            if !item.is_held(now) {
+                item.selected = !item.selected;
+            }
         }
         Task::none()
     }
 
+    /// Returns the ID of the app currently being updated, if any.
+    pub fn updating_id(&self) -> Option<&str> {
+        if !self.updating {
+            return None;
+        }
+        self.update_queue
+            .get(self.update_completed)
+            .map(String::as_str)
+    }
+
     fn handle_update_selected(&mut self) -> Task<Message> {
+        self.single_update = false;
+        let now = unix_now();
         let selected: Vec<(String, String)> = self
             .apps
             .iter()
-            .filter(|a| a.selected)
+            .filter(|a| a.selected && !a.is_held(now))
             .map(|a| (a.app.name.clone(), a.app.id.clone()))
             .collect();
 
         if selected.is_empty() {
-            self.status_message = String::from("No apps selected");
+            self.status_message = self.tr("status-no-selection");
             return Task::none();
         }
 
@@ -145,13 +926,44 @@ impl AppState {
         Task::none()
     }
 
+    /// Runs a one-app batch for the row's "Update" button, asking for
+    /// confirmation unless the user turned that off.
+    fn handle_update_now(&mut self, index: usize) -> Task<Message> {
+        if self.updating || self.loading {
+            return Task::none();
+        }
+        let Some(item) = self.apps.get(index).filter(|item| !item.is_held(unix_now())) else {
+            return Task::none();
+        };
+
+        self.single_update = true;
+        self.pending_updates = vec![(item.app.name.clone(), item.app.id.clone())];
+        if self.settings.skip_row_update_confirmation {
+            self.handle_confirm_update()
+        } else {
+            self.show_confirmation = true;
+            Task::none()
+        }
+    }
+
     fn handle_confirm_update(&mut self) -> Task<Message> {
         self.show_confirmation = false;
+        self.start_batch(BatchAction::Update)
+    }
+
+    /// Queues every pending package and runs `action` on the first one.
+    fn start_batch(&mut self, action: BatchAction) -> Task<Message> {
+        self.batch_action = action;
         self.update_queue = self
             .pending_updates
             .iter()
             .map(|(_, id)| id.clone())
             .collect();
+        if action == BatchAction::Update {
+            for id in &self.update_queue {
+                self.row_results.remove(id);
+            }
+        }
         self.update_total = self.update_queue.len();
         self.update_completed = 0;
         self.update_results.clear();
@@ -161,11 +973,102 @@ impl AppState {
             return Task::none();
         }
 
-        let id = self.update_queue[0].clone();
-        self.status_message = format!("Updating 0/{} app(s)...", self.update_total);
+        self.status_message = self.updating_status();
+        self.run_batch_step()
+    }
+
+    /// Builds the `winget upgrade` request for `id`: its source, whether to
+    /// accept the source's agreements, its upgrade options and the expanded
+    /// arguments profile.
+    ///
+    /// Fails with a `FAILURE:` result if the stored profile does not validate
+    /// (e.g. a hand-edited settings file) or needs a `{version}` that is not
+    /// known because the package is not in the list.
+    pub fn upgrade_request(&self, id: &str) -> Result<UpgradeRequest, String> {
+        let source = self
+            .apps
+            .iter()
+            .find(|item| item.app.id == id)
+            .map(|item| item.app.source.clone())
+            .unwrap_or_default();
+        Ok(UpgradeRequest {
+            id: id.to_string(),
+            accept_source_agreements: source != STORE_SOURCE
+                || self.settings.accept_store_agreements,
+            source,
+            options: self.settings.package_options_for(id),
+            extra_args: self.profile_args(id, None)?,
+        })
+    }
+
+    /// Completes a queued install request for running: agreements follow the
+    /// current setting, and upgrades get the package's arguments profile.
+    pub fn batch_install_request(
+        &self,
+        request: &InstallRequest,
+    ) -> Result<InstallRequest, String> {
+        let extra_args = if request.upgrade {
+            self.profile_args(&request.id, request.version.as_deref())?
+        } else {
+            Vec::new()
+        };
+        Ok(InstallRequest {
+            accept_source_agreements: request.source != STORE_SOURCE
+                || self.settings.accept_store_agreements,
+            extra_args,
+            ..request.clone()
+        })
+    }
+
+    /// Returns the validated switches of the package's arguments profile,
+    /// filling `{version}` from `version` or else the listed update.
+    fn profile_args(&self, id: &str, version: Option<&str>) -> Result<Vec<String>, String> {
+        let Some(profile) = self.settings.args_profile_for(id) else {
+            return Ok(Vec::new());
+        };
+        profile
+            .validate()
+            .map_err(|e| format!("FAILURE:{id} - invalid arguments profile: {e}"))?;
+        let listed = self
+            .apps
+            .iter()
+            .find(|item| item.app.id == id)
+            .map(|item| item.app.available.as_str());
+        match version.or(listed) {
+            Some(version) => Ok(profile.args(id, version)),
+            None if profile.needs_version() => Err(format!(
+                "FAILURE:{id} - the arguments profile needs {{version}}, but no update is listed"
+            )),
+            None => Ok(profile.args(id, "")),
+        }
+    }
+
+    /// Runs the batch action on the package at the queue cursor.
+    fn run_batch_step(&self) -> Task<Message> {
+        let id = self.update_queue[self.update_completed].clone();
+        let action = self.batch_action;
+        let request = self
+            .install_requests
+            .iter()
+            .find(|r| r.id == id)
+            .map(|r| self.batch_install_request(r));
+        let upgrade = self.upgrade_request(&id);
+        let source = self.inventory.iter().find(|app| app.id == id).map(|app| app.source.as_str());
+        let accept_agreements =
+            source != Some(STORE_SOURCE) || self.settings.accept_store_agreements;
         Task::perform(
             async move {
-                match update_single_app(&id) {
+                let result = match (action, request) {
+                    (BatchAction::Update, _) => upgrade.and_then(|r| update_single_app(&r)),
+                    (BatchAction::Uninstall, _) => uninstall_single_app(&id, accept_agreements),
+                    (BatchAction::Install, Some(request)) => {
+                        request.and_then(|r| install_single_app(&r))
+                    }
+                    (BatchAction::Install, None) => {
+                        Err(format!("FAILURE:{id} - not queued for install"))
+                    }
+                };
+                match result {
                     Ok(msg) | Err(msg) => msg,
                 }
             },
@@ -175,374 +1078,2888 @@ impl AppState {
 
     fn handle_cancel_update(&mut self) -> Task<Message> {
         self.show_confirmation = false;
+        self.single_update = false;
         self.pending_updates.clear();
-        self.status_message = String::from("Update cancelled");
+        self.status_message = self.tr("status-update-cancelled");
         Task::none()
     }
 
     fn handle_update_single_complete(&mut self, result: String) -> Task<Message> {
+        let update = self.batch_action == BatchAction::Update;
+        if let Some(id) = self.update_queue.get(self.update_completed).filter(|_| update) {
+            self.row_results.insert(id.clone(), result.clone());
+        }
         self.update_results.push(result);
         self.update_completed += 1;
 
         if self.update_completed < self.update_total {
-            let id = self.update_queue[self.update_completed].clone();
-            self.status_message = format!(
-                "Updating {}/{} app(s)...",
-                self.update_completed, self.update_total
-            );
-            return Task::perform(
-                async move {
-                    match update_single_app(&id) {
-                        Ok(msg) | Err(msg) => msg,
-                    }
-                },
-                Message::UpdateSingleComplete,
-            );
+            self.status_message = self.updating_status();
+            return self.run_batch_step();
         }
 
-        // All updates finished.
+        // All packages finished.
         self.updating = false;
-        self.show_results_dialog = true;
-        self.status_message = String::from("Update complete");
-
-        for item in &mut self.apps {
-            item.selected = false;
+        if !update {
+            return self.finish_package_batch();
+        }
+        if std::mem::take(&mut self.single_update) {
+            // The row badge shows the outcome; keep the user's selection.
+            let id = match self.result_counts() {
+                (1, _, _) => "status-updated-app",
+                (_, 1, _) => "status-update-app-failed",
+                _ => "status-update-app-finished",
+            };
+            let name = self.pending_updates[0].0.as_str();
+            self.status_message = self.tr_args(id, &[("name", name.into())]);
+        } else {
+            self.show_results_dialog = true;
+            self.status_message = self.tr("status-update-complete");
+            for item in &mut self.apps {
+                item.selected = false;
+            }
         }
+        self.notify_batch_finished();
 
         // Auto-refresh the list
         self.loading = true;
-        Task::perform(async { get_updatable_apps() }, Message::AppsLoaded)
+        self.load_apps_task()
     }
 
-    fn handle_select_all(&mut self) -> Task<Message> {
-        let visible = self.visible_indices();
-        for idx in visible {
-            self.apps[idx].selected = true;
+    /// Shows the results of an uninstall or install batch and reloads both
+    /// lists, since the changed packages also affect the updates.
+    fn finish_package_batch(&mut self) -> Task<Message> {
+        let reload_inventory = self.handle_load_inventory();
+        self.show_results_dialog = true;
+        if self.batch_action == BatchAction::Install {
+            self.status_message = self.tr("status-install-complete");
+            self.discover_selected.clear();
+            self.install_requests.clear();
+        } else {
+            self.status_message = self.tr("status-uninstall-complete");
+            self.inventory_selected.clear();
+        }
+        self.loading = true;
+        Task::batch([
+            reload_inventory,
+            self.load_apps_task(),
+        ])
+    }
+
+    fn handle_toggle_inventory_app(&mut self, index: usize) -> Task<Message> {
+        if self.updating {
+            return Task::none();
+        }
+        if let Some(app) = self.inventory.get(index) {
+            if !self.inventory_selected.remove(&app.id) {
+                self.inventory_selected.insert(app.id.clone());
+            }
         }
         Task::none()
     }
 
-    fn handle_deselect_all(&mut self) -> Task<Message> {
-        let visible = self.visible_indices();
-        for idx in visible {
-            self.apps[idx].selected = false;
+    fn handle_uninstall_selected(&mut self) -> Task<Message> {
+        if self.updating {
+            return Task::none();
+        }
+        let selected: Vec<(String, String)> = self
+            .inventory
+            .iter()
+            .filter(|app| self.inventory_selected.contains(&app.id))
+            .map(|app| (app.name.clone(), app.id.clone()))
+            .collect();
+
+        if selected.is_empty() {
+            self.status_message = self.tr("status-no-selection");
+            return Task::none();
         }
+
+        self.pending_updates = selected;
+        self.show_uninstall_confirmation = true;
         Task::none()
     }
 
-    fn handle_close_results_dialog(&mut self) -> Task<Message> {
-        self.show_results_dialog = false;
+    fn handle_confirm_uninstall(&mut self) -> Task<Message> {
+        self.show_uninstall_confirmation = false;
+        self.single_update = false;
+        self.start_batch(BatchAction::Uninstall)
+    }
+
+    fn handle_cancel_uninstall(&mut self) -> Task<Message> {
+        self.show_uninstall_confirmation = false;
+        self.pending_updates.clear();
+        self.status_message = self.tr("status-uninstall-cancelled");
         Task::none()
     }
 
-    fn handle_search_changed(&mut self, query: String) -> Task<Message> {
-        self.search_query = query;
+    fn handle_search_packages(&mut self) -> Task<Message> {
+        let query = self.discover_query.trim().to_string();
+        if query.is_empty() || self.discover_loading {
+            return Task::none();
+        }
+        self.discover_loading = true;
+        self.status_message = self.tr_args("status-searching", &[("query", query.as_str().into())]);
+        Task::perform(async move { search_packages(&query) }, Message::SearchResultsLoaded)
+    }
+
+    fn handle_search_results_loaded(
+        &mut self,
+        result: Result<Vec<SearchResult>, String>,
+    ) -> Task<Message> {
+        self.discover_loading = false;
+        match result {
+            Ok(results) => {
+                self.status_message = self.tr_args(
+                    "status-search-results",
+                    &[
+                        ("count", results.len().into()),
+                        ("query", self.discover_query.trim().into()),
+                    ],
+                );
+                self.discover_selected
+                    .retain(|id| results.iter().any(|r| &r.id == id));
+                self.discover_results = results;
+            }
+            Err(e) => {
+                self.status_message = self.tr_args("status-error", &[("error", e.into())]);
+            }
+        }
         Task::none()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn handle_toggle_search_result(&mut self, index: usize) -> Task<Message> {
+        if self.updating {
+            return Task::none();
+        }
+        if let Some(result) = self.discover_results.get(index) {
+            if !self.discover_selected.remove(&result.id) {
+                self.discover_selected.insert(result.id.clone());
+            }
+        }
+        Task::none()
+    }
 
-    fn sample_app(name: &str, id: &str) -> UpdatableApp {
-        UpdatableApp::new(
-            name.into(),
-            id.into(),
-            "1.0".into(),
-            "2.0".into(),
-            "winget".into(),
-        )
+    /// Queues the selected search results with their typed version and the
+    /// chosen scope, then asks for confirmation.
+    fn handle_install_selected(&mut self) -> Task<Message> {
+        if self.updating {
+            return Task::none();
+        }
+        self.install_requests = self
+            .discover_results
+            .iter()
+            .filter(|r| self.discover_selected.contains(&r.id))
+            .map(|r| {
+                let mut request =
+                    InstallRequest::new(r.name.clone(), r.id.clone(), r.source.clone());
+                request.version = self
+                    .install_versions
+                    .get(&r.id)
+                    .map(|v| v.trim().to_string())
+                    .filter(|v| !v.is_empty());
+                request.scope = self.install_scope;
+                request
+            })
+            .collect();
+
+        if self.install_requests.is_empty() {
+            self.status_message = self.tr("status-no-selection");
+            return Task::none();
+        }
+
+        self.pending_updates = self
+            .install_requests
+            .iter()
+            .map(|r| (r.name.clone(), r.id.clone()))
+            .collect();
+        self.show_install_confirmation = true;
+        Task::none()
     }
 
-    #[test]
-    fn test_app_state_default() {
-        let state = AppState::default();
-        assert!(state.apps.is_empty());
-        assert!(state.loading);
-        assert!(!state.updating);
-        assert!(!state.show_confirmation);
-        assert!(!state.show_results_dialog);
-        assert!(state.search_query.is_empty());
+    fn handle_confirm_install(&mut self) -> Task<Message> {
+        self.show_install_confirmation = false;
+        self.single_update = false;
+        self.start_batch(BatchAction::Install)
     }
 
-    #[test]
-    fn test_selected_count() {
-        let mut state = AppState::default();
-        state.apps.push(AppItem::new(sample_app("A", "A.A"), true));
-        state
-            .apps
-            .push(AppItem::new(sample_app("B", "B.B"), false));
-        state.apps.push(AppItem::new(sample_app("C", "C.C"), true));
-        assert_eq!(state.selected_count(), 2);
+    fn handle_cancel_install(&mut self) -> Task<Message> {
+        self.show_install_confirmation = false;
+        self.pending_updates.clear();
+        self.install_requests.clear();
+        self.status_message = self.tr("status-install-cancelled");
+        Task::none()
     }
 
-    #[test]
+    /// Writes the selected inventory packages, with their installed
+    /// versions, to the package set file.
+    fn handle_export_packages(&mut self) -> Task<Message> {
+        let selected = self
+            .inventory
+            .iter()
+            .filter(|app| self.inventory_selected.contains(&app.id));
+        let set = PackageSet::from_installed(selected, chrono::Local::now().to_rfc3339());
+        if set.is_empty() {
+            self.status_message = self.tr("status-export-nothing");
+            return Task::none();
+        }
+
+        self.status_message = match save_json(self.package_file.as_ref(), &set) {
+            Ok(()) => self.tr_args(
+                "status-exported",
+                &[("count", set.len().into()), ("path", self.package_file.as_str().into())],
+            ),
+            Err(e) => self.tr_args("status-error", &[("error", e.into())]),
+        };
+        Task::none()
+    }
+
+    /// Reads the package set together with a fresh inventory to diff against.
+    fn handle_import_packages(&mut self) -> Task<Message> {
+        if self.updating || self.inventory_loading {
+            return Task::none();
+        }
+        self.inventory_loading = true;
+        self.status_message = self.tr("status-import-loading");
+        let path = PathBuf::from(&self.package_file);
+        Task::perform(
+            async move {
+                let set = PackageSet::load(&path)?;
+                Ok((set, get_installed_apps()?))
+            },
+            Message::ImportLoaded,
+        )
+    }
+
+    fn handle_import_loaded(
+        &mut self,
+        result: Result<(PackageSet, Vec<InstalledApp>), String>,
+    ) -> Task<Message> {
+        let (set, inventory) = match result {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                self.inventory_loading = false;
+                self.status_message = self.tr_args("status-error", &[("error", e.into())]);
+                return Task::none();
+            }
+        };
+        let _ = self.handle_inventory_loaded(Ok(inventory));
+        self.import_plan = plan_import(&set, &self.inventory);
+        self.show_import_preview = true;
+        Task::none()
+    }
+
+    /// Queues every package the import would install or upgrade as one
+    /// install batch.
+    fn handle_confirm_import(&mut self) -> Task<Message> {
+        self.show_import_preview = false;
+        self.install_requests = std::mem::take(&mut self.import_plan)
+            .into_iter()
+            .filter_map(|item| {
+                let upgrade = match item.action {
+                    ImportAction::Install => false,
+                    ImportAction::Upgrade { .. } => true,
+                    ImportAction::Skip { .. } => return None,
+                };
+                let name = self
+                    .inventory
+                    .iter()
+                    .find(|app| app.id.eq_ignore_ascii_case(&item.id))
+                    .map_or_else(|| item.id.clone(), |app| app.name.clone());
+                let mut request = InstallRequest::new(name, item.id, item.source);
+                request.version = item.version;
+                request.upgrade = upgrade;
+                if upgrade {
+                    request.options = self.settings.package_options_for(&request.id);
+                }
+                Some(request)
+            })
+            .collect();
+
+        if self.install_requests.is_empty() {
+            self.status_message = self.tr("status-import-nothing");
+            return Task::none();
+        }
+
+        self.pending_updates = self
+            .install_requests
+            .iter()
+            .map(|r| (r.name.clone(), r.id.clone()))
+            .collect();
+        self.single_update = false;
+        self.start_batch(BatchAction::Install)
+    }
+
+    fn handle_cancel_import(&mut self) -> Task<Message> {
+        self.show_import_preview = false;
+        self.import_plan.clear();
+        self.status_message = self.tr("status-import-cancelled");
+        Task::none()
+    }
+
+    /// Reconciles the manifest against a fresh inventory and update list.
+    fn handle_check_drift(&mut self) -> Task<Message> {
+        if self.updating || self.inventory_loading {
+            return Task::none();
+        }
+        let path = self.settings.manifest_path.trim();
+        if path.is_empty() {
+            self.status_message = self.tr("status-drift-no-manifest");
+            return Task::none();
+        }
+        self.inventory_loading = true;
+        self.status_message = self.tr("status-drift-checking");
+        let path = PathBuf::from(path);
+        Task::perform(async move { check_manifest(&path) }, Message::DriftChecked)
+    }
+
+    fn handle_drift_checked(
+        &mut self,
+        result: Result<(Vec<DriftItem>, Vec<InstalledApp>), String>,
+    ) -> Task<Message> {
+        let (report, inventory) = match result {
+            Ok(checked) => checked,
+            Err(e) => {
+                self.inventory_loading = false;
+                self.status_message = self.tr_args("status-error", &[("error", e.into())]);
+                return Task::none();
+            }
+        };
+        let _ = self.handle_inventory_loaded(Ok(inventory));
+        self.status_message = self.tr_args("status-drift", &[("count", report.len().into())]);
+        self.drift_report = Some(report);
+        Task::none()
+    }
+
+    /// Installs missing packages and upgrades outdated ones as one install
+    /// batch. Forbidden packages are selected on the Installed tab so the
+    /// regular uninstall flow can remove them; over-constrained ones need a
+    /// manifest change and are left alone.
+    fn handle_apply_drift(&mut self) -> Task<Message> {
+        let report = self.drift_report.take().unwrap_or_default();
+        let mut forbidden = HashSet::new();
+        let mut requests = Vec::new();
+        for item in &report {
+            let upgrade = match item.drift {
+                Drift::Missing => false,
+                Drift::Outdated { .. } => true,
+                Drift::Forbidden { .. } => {
+                    forbidden.insert(item.id.clone());
+                    continue;
+                }
+                Drift::OverConstrained { .. } => continue,
+            };
+            let installed = self
+                .inventory
+                .iter()
+                .find(|app| app.id.eq_ignore_ascii_case(&item.id));
+            let mut request = InstallRequest::new(
+                installed.map_or_else(|| item.id.clone(), |app| app.name.clone()),
+                item.id.clone(),
+                installed.map(|app| app.source.clone()).unwrap_or_default(),
+            );
+            request.version = item.pinned_version();
+            request.upgrade = upgrade;
+            if upgrade {
+                request.options = self.settings.package_options_for(&item.id);
+            }
+            requests.push(request);
+        }
+
+        if !forbidden.is_empty() {
+            self.inventory_selected = self
+                .inventory
+                .iter()
+                .filter(|app| forbidden.iter().any(|id| app.id.eq_ignore_ascii_case(id)))
+                .map(|app| app.id.clone())
+                .collect();
+        }
+        if requests.is_empty() {
+            if self.inventory_selected.is_empty() || forbidden.is_empty() {
+                self.status_message = self.tr("status-drift-nothing");
+                return Task::none();
+            }
+            return self.handle_uninstall_selected();
+        }
+
+        self.install_requests = requests;
+        self.pending_updates = self
+            .install_requests
+            .iter()
+            .map(|r| (r.name.clone(), r.id.clone()))
+            .collect();
+        self.single_update = false;
+        self.start_batch(BatchAction::Install)
+    }
+
+    fn handle_load_sources(&mut self) -> Task<Message> {
+        self.sources_loading = true;
+        self.status_message = self.tr("status-sources-loading");
+        Task::perform(async { list_sources() }, Message::SourcesLoaded)
+    }
+
+    fn handle_sources_loaded(&mut self, result: Result<Vec<SourceInfo>, String>) -> Task<Message> {
+        self.sources_loading = false;
+        match result {
+            Ok(sources) => {
+                self.status_message =
+                    self.tr_args("status-sources", &[("count", sources.len().into())]);
+                self.sources = sources;
+            }
+            Err(e) => {
+                self.status_message = self.tr_args("status-error", &[("error", e.into())]);
+            }
+        }
+        Task::none()
+    }
+
+    fn handle_add_source(&mut self) -> Task<Message> {
+        let name = self.source_name_input.trim();
+        let argument = self.source_argument_input.trim();
+        if name.is_empty() || argument.is_empty() {
+            self.status_message = self.tr("status-source-incomplete");
+            return Task::none();
+        }
+        let action = SourceAction::Add {
+            name: name.to_string(),
+            argument: argument.to_string(),
+            kind: self.source_type_input.trim().to_string(),
+        };
+        self.handle_run_source_action(action)
+    }
+
+    fn handle_run_source_action(&mut self, action: SourceAction) -> Task<Message> {
+        if self.source_busy || self.updating {
+            return Task::none();
+        }
+        self.source_busy = true;
+        self.source_result = None;
+        self.status_message = self.tr("status-source-running");
+        let accept = self.accept_source_agreements;
+        Task::perform(
+            async move {
+                match run_source_action(&action, accept) {
+                    Ok(msg) | Err(msg) => msg,
+                }
+            },
+            Message::SourceActionComplete,
+        )
+    }
+
+    /// Shows the outcome and relists the sources, since adding, removing and
+    /// resetting all change them.
+    fn handle_source_action_complete(&mut self, result: String) -> Task<Message> {
+        self.source_busy = false;
+        if result.starts_with("SUCCESS:") {
+            self.source_name_input.clear();
+            self.source_argument_input.clear();
+            self.source_type_input.clear();
+        }
+        self.source_result = Some(result);
+        self.handle_load_sources()
+    }
+
+    /// Persists the toggle and reloads the lists it filters.
+    fn handle_source_enabled_toggled(&mut self, name: String, enabled: bool) -> Task<Message> {
+        self.settings.disabled_sources.retain(|source| *source != name);
+        if !enabled {
+            self.settings.disabled_sources.push(name);
+        }
+        self.persist(SETTINGS_FILE, |s| &s.settings);
+
+        let mut reloads = Vec::new();
+        if !self.loading && !self.updating {
+            self.loading = true;
+            reloads.push(self.load_apps_task());
+        }
+        if !self.inventory.is_empty() && !self.inventory_loading {
+            reloads.push(self.handle_load_inventory());
+        }
+        Task::batch(reloads)
+    }
+
+    fn handle_select_all(&mut self) -> Task<Message> {
+        let now = unix_now();
+        let visible = self.visible_indices();
+        for idx in visible {
+            if !self.apps[idx].is_held(now) {
+                self.apps[idx].selected = true;
+            }
+        }
+        Task::none()
+    }
+
+    fn handle_deselect_all(&mut self) -> Task<Message> {
+        let visible = self.visible_indices();
+        for idx in visible {
+            self.apps[idx].selected = false;
+        }
+        Task::none()
+    }
+
+    fn handle_select_source(&mut self, source: String, selected: bool) -> Task<Message> {
+        let now = unix_now();
+        for idx in self.visible_indices() {
+            let item = &mut self.apps[idx];
+            if item.app.source == source && !(selected && item.is_held(now)) {
+                item.selected = selected;
+            }
+        }
+        Task::none()
+    }
+
+    fn handle_toggle_source_collapsed(&mut self, source: String) -> Task<Message> {
+        if !self.collapsed_sources.remove(&source) {
+            self.collapsed_sources.insert(source);
+        }
+        Task::none()
+    }
+
+    /// Runs the shortcut bound to a key, unless a dialog is open or the
+    /// matching toolbar action is currently disabled.
+    fn handle_key_pressed(&mut self, key: Key, modifiers: Modifiers) -> Task<Message> {
+        let Some(message) = shortcut(&key, modifiers) else {
+            return Task::none();
+        };
+        let busy = self.loading || self.updating;
+        let allowed = match message {
+            Message::CloseOverlay | Message::ToggleHelp => true,
+            _ if self.overlay_open() => false,
+            // The other tabs have no row cursor; F5 reloads their list instead.
+            Message::LoadApps if self.tab == Tab::Installed => {
+                return if self.inventory_loading {
+                    Task::none()
+                } else {
+                    self.handle_load_inventory()
+                };
+            }
+            Message::LoadApps if self.tab == Tab::Discover => {
+                return self.handle_search_packages();
+            }
+            Message::LoadApps if self.tab == Tab::Sources => {
+                return if self.sources_loading {
+                    Task::none()
+                } else {
+                    self.handle_load_sources()
+                };
+            }
+            Message::FocusSearch => true,
+            _ if self.tab != Tab::Updates => false,
+            Message::LoadApps
+            | Message::SelectAll
+            | Message::DeselectAll
+            | Message::UpdateSelected
+            | Message::ToggleCursor => !busy,
+            _ => true,
+        };
+        if allowed {
+            self.update(message)
+        } else {
+            Task::none()
+        }
+    }
+
+    fn handle_move_cursor(&mut self, delta: i32) -> Task<Message> {
+        let order = self.display_order();
+        if order.is_empty() {
+            self.cursor = None;
+            return Task::none();
+        }
+
+        let last = order.len() - 1;
+        let current = self
+            .cursor_index()
+            .and_then(|idx| order.iter().position(|&i| i == idx));
+        let pos = match current {
+            Some(pos) => pos.saturating_add_signed(delta as isize).min(last),
+            None if delta < 0 => last,
+            None => 0,
+        };
+        self.cursor = Some(self.apps[order[pos]].app.id.clone());
+
+        // Scrolling to the same relative position keeps the row in view.
+        self.list_scroll = if last == 0 { 0.0 } else { pos as f32 / last as f32 };
+        scrollable::snap_to(
+            app_list_id(),
+            scrollable::RelativeOffset {
+                x: 0.0,
+                y: self.list_scroll,
+            },
+        )
+    }
+
+    fn handle_toggle_cursor(&mut self) -> Task<Message> {
+        match self.cursor_index() {
+            Some(index) => self.handle_toggle_app(index),
+            None => Task::none(),
+        }
+    }
+
+    fn handle_open_details(&mut self) -> Task<Message> {
+        self.details = self.cursor_index().map(|i| self.apps[i].app.id.clone());
+        let profile = self
+            .details
+            .as_deref()
+            .and_then(|id| self.settings.args_profile_for(id))
+            .cloned()
+            .unwrap_or_default();
+        self.override_args_input = profile.override_args;
+        self.custom_args_input = profile.custom_args;
+        self.args_profile_error = None;
+        Task::none()
+    }
+
+    /// Stores the arguments profile typed for the package in the details
+    /// dialog once it validates; an empty profile is forgotten.
+    fn handle_args_profile_changed(&mut self) -> Task<Message> {
+        let Some(id) = self.details.clone() else {
+            return Task::none();
+        };
+        let profile = ArgsProfile {
+            override_args: self.override_args_input.trim().to_string(),
+            custom_args: self.custom_args_input.trim().to_string(),
+        };
+        if let Err(e) = profile.validate() {
+            self.args_profile_error = Some(e);
+            return Task::none();
+        }
+        self.args_profile_error = None;
+        self.settings
+            .args_profiles
+            .retain(|key, _| !key.eq_ignore_ascii_case(&id));
+        if !profile.is_empty() {
+            self.settings.args_profiles.insert(id, profile);
+        }
+        self.persist(SETTINGS_FILE, |s| &s.settings);
+        Task::none()
+    }
+
+    fn handle_close_details(&mut self) -> Task<Message> {
+        self.details = None;
+        Task::none()
+    }
+
+    fn handle_toggle_help(&mut self) -> Task<Message> {
+        self.show_help = !self.show_help;
+        Task::none()
+    }
+
+    /// Closes the topmost dialog; a pending confirmation is cancelled.
+    fn handle_close_overlay(&mut self) -> Task<Message> {
+        if self.show_help {
+            self.handle_toggle_help()
+        } else if self.details.is_some() {
+            self.handle_close_details()
+        } else if self.show_settings {
+            self.handle_close_settings()
+        } else if self.show_results_dialog {
+            self.handle_close_results_dialog()
+        } else if self.show_confirmation {
+            self.handle_cancel_update()
+        } else if self.show_uninstall_confirmation {
+            self.handle_cancel_uninstall()
+        } else if self.show_install_confirmation {
+            self.handle_cancel_install()
+        } else if self.show_import_preview {
+            self.handle_cancel_import()
+        } else if self.drift_report.is_some() {
+            self.drift_report = None;
+            Task::none()
+        } else {
+            Task::none()
+        }
+    }
+
+    fn handle_close_results_dialog(&mut self) -> Task<Message> {
+        self.show_results_dialog = false;
+        Task::none()
+    }
+
+    fn handle_search_changed(&mut self, query: String) -> Task<Message> {
+        self.search_query = query;
+        Task::none()
+    }
+
+    fn handle_open_settings(&mut self) -> Task<Message> {
+        self.reset_settings_inputs();
+        self.show_settings = true;
+        Task::none()
+    }
+
+    fn handle_close_settings(&mut self) -> Task<Message> {
+        self.show_settings = false;
+        Task::none()
+    }
+
+    fn handle_cooldown_days_changed(&mut self, input: String) -> Task<Message> {
+        let trimmed = input.trim();
+        let days = if trimmed.is_empty() {
+            Some(0)
+        } else {
+            trimmed.parse::<u32>().ok()
+        };
+        self.cooldown_input = input;
+
+        if let Some(days) = days {
+            if days != self.settings.cooldown_days {
+                self.settings.cooldown_days = days;
+                self.persist(SETTINGS_FILE, |s| &s.settings);
+                self.apply_cooldown(unix_now());
+            }
+        }
+        Task::none()
+    }
+
+    fn handle_schedules_changed(&mut self, input: String) -> Task<Message> {
+        match parse_schedules(&input) {
+            Ok(schedules) => {
+                self.schedules_error = None;
+                if schedules != self.settings.schedules {
+                    self.settings.schedules = schedules;
+                    self.persist(SETTINGS_FILE, |s| &s.settings);
+                }
+            }
+            Err(e) => self.schedules_error = Some(e),
+        }
+        self.schedules_input = input;
+        Task::none()
+    }
+
+    /// Remembers the upgrade options chosen for `id`; choosing the defaults
+    /// forgets them so that option rules apply again.
+    fn handle_package_options_changed(
+        &mut self,
+        id: String,
+        options: PackageOptions,
+    ) -> Task<Message> {
+        let locale_ok = options
+            .locale
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !locale_ok {
+            let error = LocalizedError::new("options-invalid-locale").arg("value", options.locale);
+            self.status_message = self.tr_error(&error);
+            return Task::none();
+        }
+        self.settings
+            .package_options
+            .retain(|key, _| !key.eq_ignore_ascii_case(&id));
+        if !options.is_default() {
+            self.settings.package_options.insert(id, options);
+        }
+        self.persist(SETTINGS_FILE, |s| &s.settings);
+        Task::none()
+    }
+
+    fn handle_option_rules_changed(&mut self, input: String) -> Task<Message> {
+        match parse_option_rules(&input) {
+            Ok(rules) => {
+                self.option_rules_error = None;
+                if rules != self.settings.option_rules {
+                    self.settings.option_rules = rules;
+                    self.persist(SETTINGS_FILE, |s| &s.settings);
+                }
+            }
+            Err(e) => self.option_rules_error = Some(e),
+        }
+        self.option_rules_input = input;
+        Task::none()
+    }
+
+    fn handle_ignored_ids_changed(&mut self, input: String) -> Task<Message> {
+        let ids = parse_id_list(&input);
+        self.ignored_input = input;
+        if ids != self.settings.ignored_ids {
+            self.settings.ignored_ids = ids;
+            self.persist(SETTINGS_FILE, |s| &s.settings);
+        }
+        Task::none()
+    }
+
+    fn handle_scheduler_tick(&mut self) -> Task<Message> {
+        if self.loading || self.updating || self.confirmation_open() {
+            return Task::none();
+        }
+        let Some(schedule) = self
+            .scheduler
+            .due(&self.settings.schedules, local_now())
+            .cloned()
+        else {
+            return Task::none();
+        };
+
+        self.scheduled_update = schedule.action == ScheduledAction::Update;
+        self.background_refresh = true;
+        self.loading = true;
+        self.status_message = self.tr_args(
+            "status-scheduled-running",
+            &[("action", schedule.action.to_string().into())],
+        );
+        self.scheduled_run = Some(schedule);
+        self.load_apps_task()
+    }
+
+    fn handle_background_refresh(&mut self) -> Task<Message> {
+        if self.loading || self.updating || self.background_refresh || self.confirmation_open() {
+            return Task::none();
+        }
+        self.background_refresh = true;
+        self.load_apps_task()
+    }
+
+    fn handle_refresh_interval_changed(&mut self, input: String) -> Task<Message> {
+        let trimmed = input.trim();
+        let mins = if trimmed.is_empty() {
+            Some(0)
+        } else {
+            trimmed.parse::<u32>().ok()
+        };
+        self.refresh_interval_input = input;
+
+        if let Some(mins) = mins {
+            if mins != self.settings.refresh_interval_mins {
+                self.settings.refresh_interval_mins = mins;
+                self.persist(SETTINGS_FILE, |s| &s.settings);
+            }
+        }
+        Task::none()
+    }
+
+    fn handle_list_scrolled(&mut self, viewport: scrollable::Viewport) -> Task<Message> {
+        self.list_scroll = viewport.relative_offset().y;
+        self.list_viewport_height = viewport.bounds().height;
+        Task::none()
+    }
+
+    /// Grows or shrinks the known list viewports by the change in window
+    /// height, so the virtual lists render the right rows before the next
+    /// scroll event reports the new bounds.
+    fn handle_window_resized(&mut self, height: f32) -> Task<Message> {
+        if self.window_height > 0.0 {
+            let delta = height - self.window_height;
+            for viewport in [
+                &mut self.list_viewport_height,
+                &mut self.inventory_viewport_height,
+                &mut self.discover_viewport_height,
+            ] {
+                if *viewport > 0.0 {
+                    *viewport = (*viewport + delta).max(0.0);
+                }
+            }
+        }
+        self.window_height = height;
+        Task::none()
+    }
+
+    fn handle_sort_by(&mut self, column: SortColumn) -> Task<Message> {
+        self.sort = Some(match self.sort {
+            Some(order) if order.column == column => SortOrder {
+                column,
+                ascending: !order.ascending,
+            },
+            _ => SortOrder {
+                column,
+                ascending: true,
+            },
+        });
+        Task::none()
+    }
+
+    fn handle_mute_notifications(&mut self, muted: bool) -> Task<Message> {
+        self.settings.mute_notifications = muted;
+        self.persist(SETTINGS_FILE, |s| &s.settings);
+        Task::none()
+    }
+
+    fn handle_fuzzy_search_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.settings.fuzzy_search = enabled;
+        self.persist(SETTINGS_FILE, |s| &s.settings);
+        Task::none()
+    }
+
+    fn handle_group_by_source_toggled(&mut self, enabled: bool) -> Task<Message> {
+        self.settings.group_by_source = enabled;
+        self.persist(SETTINGS_FILE, |s| &s.settings);
+        Task::none()
+    }
+
+    fn handle_skip_row_update_confirmation(&mut self, skip: bool) -> Task<Message> {
+        self.settings.skip_row_update_confirmation = skip;
+        self.persist(SETTINGS_FILE, |s| &s.settings);
+        Task::none()
+    }
+
+    fn handle_theme_changed(&mut self, choice: ThemeChoice) -> Task<Message> {
+        self.settings.theme = choice;
+        self.persist(SETTINGS_FILE, |s| &s.settings);
+        if choice == ThemeChoice::System {
+            Task::perform(async { system_prefers_dark() }, Message::SystemThemeDetected)
+        } else {
+            Task::none()
+        }
+    }
+
+    fn handle_language_changed(&mut self, language: Option<Language>) -> Task<Message> {
+        self.settings.language = language;
+        self.persist(SETTINGS_FILE, |s| &s.settings);
+        self.reload_catalog();
+        Task::none()
+    }
+
+    fn handle_muted_ids_changed(&mut self, input: String) -> Task<Message> {
+        let ids = parse_id_list(&input);
+        self.muted_input = input;
+        if ids != self.settings.muted_ids {
+            self.settings.muted_ids = ids;
+            self.persist(SETTINGS_FILE, |s| &s.settings);
+        }
+        Task::none()
+    }
+
+    // ── Helpers ──────────────────────────────────────────────────────
+
+    /// Replaces the list with `apps`, keeping selections for IDs that still
+    /// have updates. When `highlight` is set, releases that were not offered
+    /// by the previous refresh are flagged as new.
+    ///
+    /// Returns the names of apps that appeared and disappeared.
+    fn reconcile_apps(
+        &mut self,
+        apps: Vec<UpdatableApp>,
+        highlight: bool,
+    ) -> (Vec<String>, Vec<String>) {
+        let mut previous: HashMap<String, AppItem> = self
+            .apps
+            .drain(..)
+            .map(|item| (item.app.id.clone(), item))
+            .collect();
+
+        let mut appeared = Vec::new();
+        self.apps = apps
+            .into_iter()
+            .map(|app| {
+                let (selected, is_new) = match previous.remove(&app.id) {
+                    Some(old) => (old.selected, old.app.available != app.available),
+                    None => {
+                        appeared.push(app.name.clone());
+                        (false, true)
+                    }
+                };
+                let mut item = AppItem::new(app, selected);
+                item.is_new = highlight && is_new;
+                item
+            })
+            .collect();
+
+        let mut disappeared: Vec<String> = previous.into_values().map(|i| i.app.name).collect();
+        disappeared.sort();
+        (appeared, disappeared)
+    }
+
+    /// Builds the notification about releases in `apps` that were not
+    /// offered before, if there are any.
+    fn new_updates_notification(&self, apps: &[UpdatableApp]) -> Option<Notification> {
+        let known: HashSet<(&str, &str)> = self
+            .apps
+            .iter()
+            .map(|a| (a.app.id.as_str(), a.app.available.as_str()))
+            .collect();
+        let names: Vec<String> = apps
+            .iter()
+            .filter(|a| !known.contains(&(a.id.as_str(), a.available.as_str())))
+            .filter(|a| !self.settings.muted_ids.iter().any(|m| m.eq_ignore_ascii_case(&a.id)))
+            .map(|a| a.name.clone())
+            .collect();
+        notify::updates_available(&self.catalog, &names)
+    }
+
+    /// Notifies about the outcome of the batch that just finished, leaving
+    /// out muted packages.
+    fn notify_batch_finished(&mut self) {
+        let results: Vec<&str> = self
+            .update_queue
+            .iter()
+            .zip(&self.update_results)
+            .filter(|(id, _)| !self.settings.muted_ids.iter().any(|m| m.eq_ignore_ascii_case(id)))
+            .map(|(_, r)| r.as_str())
+            .collect();
+        if results.is_empty() {
+            return;
+        }
+        let (ok, failed, other) = tally_results(results.into_iter());
+        self.send_notification(&notify::batch_finished(&self.catalog, ok, failed, other));
+    }
+
+    /// Shows a notification unless they are muted, reporting a failure in
+    /// the status bar.
+    fn send_notification(&mut self, notification: &Notification) {
+        if self.settings.mute_notifications {
+            return;
+        }
+        if let Err(e) = self.notifier.notify(notification) {
+            self.status_message = self.tr_args("status-error", &[("error", e.into())]);
+        }
+    }
+
+    /// Queues every eligible app for an unattended update.
+    ///
+    /// Apps in their cooling-off period or on the ignore list are skipped.
+    fn start_scheduled_update(&mut self) -> Task<Message> {
+        let now = unix_now();
+        let ignored = &self.settings.ignored_ids;
+        self.pending_updates = self
+            .apps
+            .iter()
+            .filter(|a| {
+                !a.is_held(now) && !ignored.iter().any(|i| i.eq_ignore_ascii_case(&a.app.id))
+            })
+            .map(|a| (a.app.name.clone(), a.app.id.clone()))
+            .collect();
+
+        if self.pending_updates.is_empty() {
+            self.status_message = self.tr("status-scheduled-nothing");
+            return Task::none();
+        }
+        self.handle_confirm_update()
+    }
+
+    /// Re-populates the settings inputs from the current settings.
+    fn reset_settings_inputs(&mut self) {
+        self.cooldown_input = self.settings.cooldown_days.to_string();
+        self.schedules_input = format_schedules(&self.settings.schedules);
+        self.schedules_error = None;
+        self.option_rules_input = format_option_rules(&self.settings.option_rules);
+        self.option_rules_error = None;
+        self.ignored_input = self.settings.ignored_ids.join(", ");
+        self.muted_input = self.settings.muted_ids.join(", ");
+        self.refresh_interval_input = self.settings.refresh_interval_mins.to_string();
+    }
+
+    /// Recomputes which releases are held back and deselects them.
+    fn apply_cooldown(&mut self, now: u64) {
+        let period = self.settings.cooldown_secs();
+        for item in &mut self.apps {
+            item.held_until =
+                self.first_seen
+                    .held_until(&item.app.id, &item.app.available, period, now);
+            if item.is_held(now) {
+                item.selected = false;
+            }
+        }
+    }
+
+    /// Writes the state `value` picks to `file` in the data directory, if
+    /// persistence is enabled, and reports a failed write in the status bar.
+    fn persist<T: serde::Serialize>(&mut self, file: &str, value: fn(&Self) -> &T) {
+        let Some(dir) = &self.data_dir else {
+            return;
+        };
+        if let Err(e) = settings::save_json(&dir.join(file), value(self)) {
+            self.status_message = self.tr_args("status-error", &[("error", e.into())]);
+        }
+    }
+}
+
+/// Describes rows that appeared or disappeared since the previous refresh,
+/// e.g. ` (new: B, C; gone: A)`, or an empty string if nothing changed.
+fn describe_changes(catalog: &Catalog, appeared: &[String], disappeared: &[String]) -> String {
+    let mut parts = Vec::new();
+    for (id, names) in [("status-changes-new", appeared), ("status-changes-gone", disappeared)] {
+        if !names.is_empty() {
+            let names = notify::summarize_names(catalog, names, 3);
+            parts.push(catalog.tr_args(id, &[("names", names.into())]));
+        }
+    }
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", parts.join("; "))
+    }
+}
+
+/// Counts `(succeeded, failed, other)` among update result strings.
+fn tally_results<'a>(results: impl Iterator<Item = &'a str>) -> (usize, usize, usize) {
+    let mut counts = (0, 0, 0);
+    for r in results {
+        if r.starts_with("SUCCESS:") {
+            counts.0 += 1;
+        } else if r.starts_with("FAILURE:") {
+            counts.1 += 1;
+        } else {
+            counts.2 += 1;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::{reconcile, Manifest};
+
+    fn sample_app(name: &str, id: &str) -> UpdatableApp {
+        UpdatableApp::new(
+            name.into(),
+            id.into(),
+            "1.0".into(),
+            "2.0".into(),
+            "winget".into(),
+        )
+    }
+
+    #[test]
+    fn test_app_state_default() {
+        let state = AppState::default();
+        assert!(state.apps.is_empty());
+        assert!(state.loading);
+        assert!(!state.updating);
+        assert!(!state.show_confirmation);
+        assert!(!state.show_results_dialog);
+        assert!(state.search_query.is_empty());
+    }
+
+    #[test]
+    fn test_selected_count() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::new(sample_app("A", "A.A"), true));
+        state
+            .apps
+            .push(AppItem::new(sample_app("B", "B.B"), false));
+        state.apps.push(AppItem::new(sample_app("C", "C.C"), true));
+        assert_eq!(state.selected_count(), 2);
+    }
+
+    #[test]
     fn test_visible_indices_with_search() {
         let mut state = AppState::default();
         state
-            .apps
-            .push(AppItem::from_app(sample_app("Firefox", "Mozilla.Firefox")));
-        state
-            .apps
-            .push(AppItem::from_app(sample_app("Chrome", "Google.Chrome")));
-        state
-            .apps
-            .push(AppItem::from_app(sample_app("Edge", "Microsoft.Edge")));
+            .apps
+            .push(AppItem::from_app(sample_app("Firefox", "Mozilla.Firefox")));
+        state
+            .apps
+            .push(AppItem::from_app(sample_app("Chrome", "Google.Chrome")));
+        state
+            .apps
+            .push(AppItem::from_app(sample_app("Edge", "Microsoft.Edge")));
+
+        state.search_query = "fire".into();
+        assert_eq!(state.visible_indices(), vec![0]);
+
+        state.search_query.clear();
+        assert_eq!(state.visible_indices(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_handle_select_all() {
+        let mut state = AppState::default();
+        state
+            .apps
+            .push(AppItem::from_app(sample_app("A", "A.A")));
+        state
+            .apps
+            .push(AppItem::from_app(sample_app("B", "B.B")));
+
+        let _ = state.handle_select_all();
+        assert!(state.apps.iter().all(|a| a.selected));
+    }
+
+    #[test]
+    fn test_handle_select_all_respects_filter() {
+        let mut state = AppState::default();
+        state
+            .apps
+            .push(AppItem::from_app(sample_app("Firefox", "Mozilla.Firefox")));
+        state
+            .apps
+            .push(AppItem::from_app(sample_app("Chrome", "Google.Chrome")));
+
+        state.search_query = "fire".into();
+        let _ = state.handle_select_all();
+        assert!(state.apps[0].selected);
+        assert!(!state.apps[1].selected); // Chrome not visible
+    }
+
+    #[test]
+    fn test_handle_deselect_all() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::new(sample_app("A", "A.A"), true));
+
+        let _ = state.handle_deselect_all();
+        assert!(!state.apps[0].selected);
+    }
+
+    #[test]
+    fn test_handle_toggle_app() {
+        let mut state = AppState::default();
+        state
+            .apps
+            .push(AppItem::from_app(sample_app("A", "A.A")));
+
+        let _ = state.handle_toggle_app(0);
+        assert!(state.apps[0].selected);
+
+        let _ = state.handle_toggle_app(0);
+        assert!(!state.apps[0].selected);
+    }
+
+    #[test]
+    fn test_handle_toggle_app_out_of_bounds() {
+        let mut state = AppState::default();
+        // Should not panic
+        let _ = state.handle_toggle_app(99);
+    }
+
+    #[test]
+    fn test_handle_update_selected_empty() {
+        let mut state = AppState {
+            loading: false,
+            ..Default::default()
+        };
+        let _ = state.handle_update_selected();
+        assert_eq!(state.status_message, "No apps selected");
+        assert!(!state.show_confirmation);
+    }
+
+    #[test]
+    fn test_handle_update_selected_shows_confirmation() {
+        let mut state = AppState::default();
+        state
+            .apps
+            .push(AppItem::new(sample_app("My App", "My.App"), true));
+
+        let _ = state.handle_update_selected();
+        assert!(state.show_confirmation);
+        assert_eq!(state.pending_updates.len(), 1);
+        assert_eq!(
+            state.pending_updates[0],
+            ("My App".into(), "My.App".into())
+        );
+    }
+
+    #[test]
+    fn test_handle_cancel_update() {
+        let mut state = AppState {
+            show_confirmation: true,
+            ..Default::default()
+        };
+        state
+            .pending_updates
+            .push(("A".into(), "A.Id".into()));
+
+        let _ = state.handle_cancel_update();
+        assert!(!state.show_confirmation);
+        assert!(state.pending_updates.is_empty());
+        assert_eq!(state.status_message, "Update cancelled");
+    }
+
+    #[test]
+    fn test_handle_close_results_dialog() {
+        let mut state = AppState {
+            show_results_dialog: true,
+            ..Default::default()
+        };
+        let _ = state.handle_close_results_dialog();
+        assert!(!state.show_results_dialog);
+    }
+
+    #[test]
+    fn test_handle_search_changed() {
+        let mut state = AppState::default();
+        let _ = state.handle_search_changed("firefox".into());
+        assert_eq!(state.search_query, "firefox");
+    }
+
+    #[test]
+    fn test_handle_load_apps_keeps_filter() {
+        let mut state = AppState {
+            loading: false,
+            search_query: "test".into(),
+            ..Default::default()
+        };
+        let _ = state.handle_load_apps();
+        assert!(state.loading);
+        assert_eq!(state.search_query, "test");
+        assert_eq!(state.status_message, "Loading updatable apps...");
+    }
+
+    #[test]
+    fn test_handle_apps_loaded_ok() {
+        let mut state = AppState::default();
+        let apps = vec![
+            sample_app("Firefox", "Mozilla.Firefox"),
+            sample_app("Chrome", "Google.Chrome"),
+        ];
+        let _ = state.handle_apps_loaded(Ok(apps));
+        assert!(!state.loading);
+        assert_eq!(state.apps.len(), 2);
+        assert!(state.status_message.contains("2"));
+    }
+
+    #[test]
+    fn test_handle_apps_loaded_err() {
+        let mut state = AppState::default();
+        let _ = state.handle_apps_loaded(Err("Connection failed".into()));
+        assert!(!state.loading);
+        assert!(state.apps.is_empty());
+        assert!(state.status_message.contains("Error"));
+        assert!(state.status_message.contains("Connection failed"));
+    }
+
+    #[test]
+    fn test_handle_apps_loaded_empty() {
+        let mut state = AppState::default();
+        let _ = state.handle_apps_loaded(Ok(vec![]));
+        assert!(!state.loading);
+        assert!(state.apps.is_empty());
+        assert!(state.status_message.contains("0"));
+    }
+
+    #[test]
+    fn test_handle_confirm_update_sets_progress() {
+        let mut state = AppState::default();
+        state
+            .pending_updates
+            .push(("App A".into(), "A.App".into()));
+        state
+            .pending_updates
+            .push(("App B".into(), "B.App".into()));
+
+        let _ = state.handle_confirm_update();
+        assert!(!state.show_confirmation);
+        assert!(state.updating);
+        assert_eq!(state.update_total, 2);
+        assert_eq!(state.update_completed, 0);
+        assert_eq!(state.update_queue, vec!["A.App", "B.App"]);
+        assert!(state.status_message.contains("0/2"));
+    }
+
+    #[test]
+    fn test_handle_confirm_update_empty_queue() {
+        let mut state = AppState::default();
+        // pending_updates is empty
+        let _ = state.handle_confirm_update();
+        assert!(!state.show_confirmation);
+        assert_eq!(state.update_total, 0);
+    }
+
+    #[test]
+    fn test_handle_update_single_complete_continues() {
+        let mut state = AppState {
+            updating: true,
+            update_total: 3,
+            update_completed: 0,
+            update_queue: vec!["A.App".into(), "B.App".into(), "C.App".into()],
+            ..Default::default()
+        };
+
+        let _ = state.handle_update_single_complete("SUCCESS:A.App - done".into());
+
+        assert_eq!(state.update_completed, 1);
+        assert!(state.updating);
+        assert!(!state.show_results_dialog);
+        assert_eq!(state.update_results.len(), 1);
+        assert!(state.status_message.contains("1/3"));
+    }
+
+    #[test]
+    fn test_handle_update_single_complete_finishes() {
+        let mut state = AppState {
+            updating: true,
+            update_total: 1,
+            update_completed: 0,
+            update_queue: vec!["A.App".into()],
+            ..Default::default()
+        };
+        state.apps.push(AppItem::new(sample_app("A", "A.App"), true));
+
+        let _ = state.handle_update_single_complete("SUCCESS:A.App - done".into());
+
+        assert_eq!(state.update_completed, 1);
+        assert!(!state.updating);
+        assert!(state.show_results_dialog);
+        assert!(!state.apps[0].selected);
+        assert_eq!(state.update_results, vec!["SUCCESS:A.App - done"]);
+        assert_eq!(state.status_message, "Update complete");
+    }
+
+    #[test]
+    fn test_handle_update_single_complete_second_of_two() {
+        let mut state = AppState {
+            updating: true,
+            update_total: 2,
+            update_completed: 1, // first already done
+            update_results: vec!["SUCCESS:A - done".into()],
+            update_queue: vec!["A.App".into(), "B.App".into()],
+            ..Default::default()
+        };
+        state.apps.push(AppItem::new(sample_app("A", "A.App"), true));
+        state.apps.push(AppItem::new(sample_app("B", "B.App"), true));
+
+        let _ = state.handle_update_single_complete("FAILURE:B - err".into());
+
+        assert_eq!(state.update_completed, 2);
+        assert!(!state.updating);
+        assert!(state.show_results_dialog);
+        assert_eq!(state.update_results.len(), 2);
+    }
+
+    #[test]
+    fn test_progress_defaults() {
+        let state = AppState::default();
+        assert_eq!(state.update_total, 0);
+        assert_eq!(state.update_completed, 0);
+        assert!(state.update_queue.is_empty());
+    }
+
+    #[test]
+    fn test_cooldown_holds_new_release() {
+        let mut state = AppState::default();
+        state.settings.cooldown_days = 7;
+        let _ = state.handle_apps_loaded(Ok(vec![sample_app("A", "A.App")]));
+
+        let now = unix_now();
+        assert!(state.apps[0].is_held(now));
+        assert!(state.first_seen.first_seen("A.App", "2.0").is_some());
+
+        let _ = state.handle_toggle_app(0);
+        assert!(!state.apps[0].selected);
+
+        let _ = state.handle_select_all();
+        assert!(!state.apps[0].selected);
+    }
+
+    #[test]
+    fn test_cooldown_releases_after_period() {
+        let mut state = AppState::default();
+        state.settings.cooldown_days = 7;
+        let long_ago = unix_now() - 8 * 86_400;
+        state.first_seen.record(&[sample_app("A", "A.App")], long_ago, true);
+
+        let _ = state.handle_apps_loaded(Ok(vec![sample_app("A", "A.App")]));
+        assert!(state.apps[0].held_until.is_none());
+
+        let _ = state.handle_toggle_app(0);
+        assert!(state.apps[0].selected);
+    }
+
+    #[test]
+    fn test_disabled_source_keeps_cooldown_history() {
+        let mut state = AppState::default();
+        let long_ago = unix_now() - 8 * 86_400;
+        state.first_seen.record(&[sample_app("A", "A.App")], long_ago, true);
+        state.settings.disabled_sources = vec!["winget".into()];
+
+        let _ = state.handle_apps_loaded(Ok(Vec::new()));
+        assert_eq!(state.first_seen.first_seen("A.App", "2.0"), Some(long_ago));
+    }
+
+    #[test]
+    fn test_failed_source_is_reported_and_keeps_history() {
+        let mut state = AppState::default();
+        let long_ago = unix_now() - 8 * 86_400;
+        state.first_seen.record(&[sample_app("B", "B.App")], long_ago, true);
+
+        let listing = (vec![sample_app("A", "A.App")], vec!["contoso: unreachable".to_string()]);
+        let _ = state.update(Message::AppsLoaded(Ok(listing)));
+        assert_eq!(state.apps.len(), 1);
+        assert!(state.status_message.contains("contoso: unreachable"));
+        assert!(state.source_errors.is_empty());
+        assert_eq!(state.first_seen.first_seen("B.App", "2.0"), Some(long_ago));
+    }
+
+    #[test]
+    fn test_cooldown_days_changed_applies_and_deselects() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::new(sample_app("A", "A.App"), true));
+
+        let _ = state.handle_cooldown_days_changed("3".into());
+        assert_eq!(state.settings.cooldown_days, 3);
+        assert!(state.apps[0].held_until.is_some());
+        assert!(!state.apps[0].selected);
+
+        let _ = state.handle_cooldown_days_changed("".into());
+        assert_eq!(state.settings.cooldown_days, 0);
+        assert!(state.apps[0].held_until.is_none());
+    }
+
+    #[test]
+    fn test_cooldown_days_changed_invalid_input_keeps_setting() {
+        let mut state = AppState::default();
+        state.settings.cooldown_days = 5;
+        let _ = state.handle_cooldown_days_changed("abc".into());
+        assert_eq!(state.settings.cooldown_days, 5);
+        assert_eq!(state.cooldown_input, "abc");
+    }
+
+    #[test]
+    fn test_open_and_close_settings() {
+        let mut state = AppState::default();
+        state.settings.cooldown_days = 2;
+        let _ = state.handle_open_settings();
+        assert!(state.show_settings);
+        assert_eq!(state.cooldown_input, "2");
+
+        let _ = state.handle_close_settings();
+        assert!(!state.show_settings);
+    }
+
+    #[test]
+    fn test_schedules_changed_valid() {
+        let mut state = AppState::default();
+        let _ = state.handle_schedules_changed("update weekdays 12:00-13:00".into());
+        assert_eq!(state.settings.schedules.len(), 1);
+        assert!(state.schedules_error.is_none());
+        assert!(state.next_scheduled_run().is_some());
+    }
+
+    #[test]
+    fn test_schedules_changed_invalid_keeps_previous() {
+        let mut state = AppState::default();
+        let _ = state.handle_schedules_changed("refresh daily 08:00-09:00".into());
+        let _ = state.handle_schedules_changed("refresh daily 08:00".into());
+        assert_eq!(state.settings.schedules.len(), 1);
+        assert!(state.schedules_error.is_some());
+        assert_eq!(state.schedules_input, "refresh daily 08:00");
+    }
+
+    #[test]
+    fn test_option_rules_changed() {
+        let mut state = AppState::default();
+        let _ = state.handle_option_rules_changed("Contoso.* scope=machine".into());
+        assert_eq!(state.settings.option_rules.len(), 1);
+        assert!(state.option_rules_error.is_none());
+        let _ = state.handle_option_rules_changed("Contoso.* scope=global".into());
+        assert_eq!(state.settings.option_rules.len(), 1);
+        assert!(state.option_rules_error.is_some());
+    }
+
+    #[test]
+    fn test_package_options_changed_remembers_and_forgets() {
+        let mut state = AppState::default();
+        let options: PackageOptions = "arch=x86 locale=fr-FR".parse().unwrap();
+        let _ = state.handle_package_options_changed("Git.Git".into(), options.clone());
+        assert_eq!(state.settings.package_options_for("git.git"), options);
+
+        // A locale with shell characters is reported and not stored.
+        let bad = PackageOptions {
+            locale: "fr;rm".into(),
+            ..options.clone()
+        };
+        let _ = state.handle_package_options_changed("Git.Git".into(), bad);
+        assert_eq!(state.settings.package_options_for("Git.Git"), options);
+        assert_eq!(state.status_message, "Invalid locale 'fr;rm'");
+
+        let _ = state.handle_package_options_changed("Git.Git".into(), PackageOptions::default());
+        assert!(state.settings.package_options.is_empty());
+    }
+
+    #[test]
+    fn test_ignored_ids_changed() {
+        let mut state = AppState::default();
+        let _ = state.handle_ignored_ids_changed(" A.App, ,B.App ".into());
+        assert_eq!(state.settings.ignored_ids, vec!["A.App", "B.App"]);
+    }
+
+    #[test]
+    fn test_scheduler_tick_without_schedules_does_nothing() {
+        let mut state = AppState {
+            loading: false,
+            ..Default::default()
+        };
+        let _ = state.handle_scheduler_tick();
+        assert!(!state.loading);
+        assert!(!state.scheduled_update);
+    }
+
+    #[test]
+    fn test_scheduler_tick_runs_due_schedule_once() {
+        let mut state = AppState {
+            loading: false,
+            ..Default::default()
+        };
+        state.settings.schedules = parse_schedules("update daily 00:00-00:00").unwrap();
+
+        let _ = state.handle_scheduler_tick();
+        assert!(state.loading);
+        assert!(state.scheduled_update);
+
+        state.scheduled_update = false;
+        let _ = state.handle_apps_loaded(Ok(Vec::new()));
+        let _ = state.handle_scheduler_tick();
+        assert!(!state.loading, "schedule must not run twice in one window");
+    }
+
+    #[test]
+    fn test_failed_scheduled_refresh_is_retried() {
+        let mut state = AppState {
+            loading: false,
+            ..Default::default()
+        };
+        state.settings.schedules = parse_schedules("refresh daily 00:00-00:00").unwrap();
+
+        let _ = state.handle_scheduler_tick();
+        assert!(state.scheduled_run.is_some());
+        let _ = state.handle_apps_loaded(Err("winget not found".into()));
+        assert!(state.scheduled_run.is_none());
+
+        let _ = state.handle_scheduler_tick();
+        assert!(state.loading, "a failed refresh must not count as run");
+    }
+
+    #[test]
+    fn test_scheduler_tick_skipped_while_updating() {
+        let mut state = AppState {
+            loading: false,
+            updating: true,
+            ..Default::default()
+        };
+        state.settings.schedules = parse_schedules("refresh daily 00:00-00:00").unwrap();
+        let _ = state.handle_scheduler_tick();
+        assert!(!state.loading);
+    }
+
+    #[test]
+    fn test_scheduled_update_honours_ignore_list_and_cooldown() {
+        let mut state = AppState::default();
+        state.settings.ignored_ids = vec!["B.App".into()];
+        state.settings.cooldown_days = 7;
+        let long_ago = unix_now() - 30 * 86_400;
+        state
+            .first_seen
+            .record(&[sample_app("A", "A.App"), sample_app("B", "B.App")], long_ago, true);
+        state.scheduled_update = true;
 
-        state.search_query = "fire".into();
+        let _ = state.handle_apps_loaded(Ok(vec![
+            sample_app("A", "A.App"),
+            sample_app("B", "B.App"),
+            sample_app("C", "C.App"),
+        ]));
+
+        assert!(!state.scheduled_update);
+        assert!(state.updating);
+        assert!(!state.show_confirmation);
+        assert_eq!(state.update_queue, vec!["A.App"]);
+    }
+
+    #[test]
+    fn test_scheduled_update_nothing_eligible() {
+        let mut state = AppState::default();
+        state.settings.ignored_ids = vec!["a.app".into()];
+        state.scheduled_update = true;
+        let _ = state.handle_apps_loaded(Ok(vec![sample_app("A", "A.App")]));
+        assert!(!state.updating);
+        assert_eq!(state.status_message, "Scheduled update: nothing to update");
+    }
+
+    fn with_memory_notifier(state: &mut AppState) -> Arc<MemoryNotifier> {
+        let notifier = Arc::new(MemoryNotifier::default());
+        state.notifier = notifier.clone();
+        notifier
+    }
+
+    #[test]
+    fn test_background_refresh_notifies_new_updates() {
+        let mut state = AppState::default();
+        let notifier = with_memory_notifier(&mut state);
+        state.apps.push(AppItem::from_app(sample_app("A", "A.App")));
+        state.background_refresh = true;
+
+        let _ = state.handle_apps_loaded(Ok(vec![
+            sample_app("A", "A.App"),
+            sample_app("B", "B.App"),
+        ]));
+
+        let sent = notifier.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].title, "1 new update available");
+        assert_eq!(sent[0].body, "B");
+        assert!(!state.background_refresh);
+    }
+
+    struct FailingNotifier;
+
+    impl Notifier for FailingNotifier {
+        fn notify(&self, _: &Notification) -> Result<(), String> {
+            Err("Failed to execute powershell: not found".into())
+        }
+    }
+
+    #[test]
+    fn test_failed_notification_is_reported() {
+        let mut state = AppState {
+            notifier: Arc::new(FailingNotifier),
+            background_refresh: true,
+            ..Default::default()
+        };
+        let _ = state.handle_apps_loaded(Ok(vec![sample_app("A", "A.App")]));
+        assert_eq!(state.status_message, "Error: Failed to execute powershell: not found");
+    }
+
+    #[test]
+    fn test_manual_refresh_does_not_notify() {
+        let mut state = AppState::default();
+        let notifier = with_memory_notifier(&mut state);
+        let _ = state.handle_apps_loaded(Ok(vec![sample_app("A", "A.App")]));
+        assert!(notifier.sent().is_empty());
+    }
+
+    #[test]
+    fn test_notifications_muted_globally() {
+        let mut state = AppState::default();
+        let notifier = with_memory_notifier(&mut state);
+        let _ = state.handle_mute_notifications(true);
+        state.background_refresh = true;
+        let _ = state.handle_apps_loaded(Ok(vec![sample_app("A", "A.App")]));
+        assert!(notifier.sent().is_empty());
+    }
+
+    #[test]
+    fn test_notifications_muted_per_app() {
+        let mut state = AppState::default();
+        let notifier = with_memory_notifier(&mut state);
+        let _ = state.handle_muted_ids_changed("a.app".into());
+        state.background_refresh = true;
+        let _ = state.handle_apps_loaded(Ok(vec![sample_app("A", "A.App")]));
+        assert!(notifier.sent().is_empty());
+    }
+
+    #[test]
+    fn test_batch_finished_notifies_counts() {
+        let mut state = AppState::default();
+        let notifier = with_memory_notifier(&mut state);
+        state.updating = true;
+        state.update_total = 2;
+        state.update_completed = 1;
+        state.update_results = vec!["SUCCESS:A.App - done".into()];
+        state.update_queue = vec!["A.App".into(), "B.App".into()];
+
+        let _ = state.handle_update_single_complete("FAILURE:B.App - err".into());
+
+        let sent = notifier.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].body, "1 succeeded, 1 failed, 0 other");
+    }
+
+    #[test]
+    fn test_batch_finished_skips_muted_apps() {
+        let mut state = AppState::default();
+        let notifier = with_memory_notifier(&mut state);
+        state.settings.muted_ids = vec!["a.app".into()];
+        state.updating = true;
+        state.update_total = 1;
+        state.update_queue = vec!["A.App".into()];
+
+        let _ = state.handle_update_single_complete("SUCCESS:A.App - done".into());
+        assert!(notifier.sent().is_empty());
+    }
+
+    #[test]
+    fn test_result_counts() {
+        let state = AppState {
+            update_results: vec![
+                "SUCCESS:A - ok".into(),
+                "FAILURE:B - err".into(),
+                "[!] C - close".into(),
+                "[i] D - up to date".into(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(state.result_counts(), (1, 1, 2));
+    }
+
+    #[test]
+    fn test_background_refresh_starts_without_loading_indicator() {
+        let mut state = AppState {
+            loading: false,
+            ..Default::default()
+        };
+        let _ = state.handle_background_refresh();
+        assert!(state.background_refresh);
+        assert!(!state.loading);
+
+        // A second tick while the first refresh is in flight is ignored.
+        let _ = state.handle_background_refresh();
+        assert!(state.background_refresh);
+    }
+
+    #[test]
+    fn test_background_refresh_skipped_while_busy() {
+        let mut state = AppState {
+            loading: false,
+            updating: true,
+            ..Default::default()
+        };
+        let _ = state.handle_background_refresh();
+        assert!(!state.background_refresh);
+    }
+
+    #[test]
+    fn test_background_refresh_merges_and_preserves_selection() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::new(sample_app("A", "A.App"), true));
+        state.apps.push(AppItem::new(sample_app("B", "B.App"), true));
+        state.last_refreshed = Some(0);
+        state.background_refresh = true;
+
+        let mut b_newer = sample_app("B", "B.App");
+        b_newer.available = "3.0".into();
+        let _ = state.handle_apps_loaded(Ok(vec![
+            sample_app("C", "C.App"),
+            sample_app("A", "A.App"),
+            b_newer,
+        ]));
+
+        let by_id = |id: &str| state.apps.iter().find(|a| a.app.id == id).unwrap();
+        assert!(by_id("A.App").selected);
+        assert!(!by_id("A.App").is_new);
+        assert!(by_id("B.App").selected);
+        assert!(by_id("B.App").is_new);
+        assert!(!by_id("C.App").selected);
+        assert!(by_id("C.App").is_new);
+    }
+
+    #[test]
+    fn test_refresh_interval_changed() {
+        let mut state = AppState::default();
+        let _ = state.handle_refresh_interval_changed("15".into());
+        assert_eq!(state.settings.refresh_interval_mins, 15);
+
+        let _ = state.handle_refresh_interval_changed("x".into());
+        assert_eq!(state.settings.refresh_interval_mins, 15);
+        assert_eq!(state.refresh_interval_input, "x");
+    }
+
+    #[test]
+    fn test_refresh_preserves_selection_and_reports_changes() {
+        let mut state = AppState::default();
+        let _ = state.handle_apps_loaded(Ok(vec![
+            sample_app("Firefox", "Mozilla.Firefox"),
+            sample_app("Chrome", "Google.Chrome"),
+        ]));
+        assert_eq!(state.status_message, "2 apps available for update");
+        assert!(state.apps.iter().all(|a| !a.is_new));
+        state.apps[0].selected = true;
+        state.apps[1].selected = true;
+
+        let _ = state.handle_load_apps();
+        let _ = state.handle_apps_loaded(Ok(vec![
+            sample_app("Edge", "Microsoft.Edge"),
+            sample_app("Firefox", "Mozilla.Firefox"),
+        ]));
+
+        assert_eq!(state.apps.len(), 2);
+        assert!(!state.apps[0].selected);
+        assert!(state.apps[0].is_new);
+        assert!(state.apps[1].selected);
+        assert!(!state.apps[1].is_new);
+        assert_eq!(
+            state.status_message,
+            "2 apps available for update (new: Edge; gone: Chrome)"
+        );
+    }
+
+    #[test]
+    fn test_refresh_without_changes_has_plain_status() {
+        let mut state = AppState::default();
+        let _ = state.handle_apps_loaded(Ok(vec![sample_app("A", "A.App")]));
+        let _ = state.handle_apps_loaded(Ok(vec![sample_app("A", "A.App")]));
+        assert_eq!(state.status_message, "1 app available for update");
+    }
+
+    #[test]
+    fn test_describe_changes() {
+        let en = Catalog::default();
+        assert_eq!(describe_changes(&en, &[], &[]), "");
+        assert_eq!(describe_changes(&en, &["A".into()], &[]), " (new: A)");
+        assert_eq!(describe_changes(&en, &[], &["B".into()]), " (gone: B)");
+    }
+
+    #[test]
+    fn test_sort_by_toggles_direction() {
+        let mut state = AppState::default();
+        let _ = state.handle_sort_by(SortColumn::Name);
+        assert_eq!(
+            state.sort,
+            Some(SortOrder {
+                column: SortColumn::Name,
+                ascending: true
+            })
+        );
+        let _ = state.handle_sort_by(SortColumn::Name);
+        assert!(!state.sort.unwrap().ascending);
+        let _ = state.handle_sort_by(SortColumn::Id);
+        assert_eq!(state.sort.unwrap().column, SortColumn::Id);
+        assert!(state.sort.unwrap().ascending);
+    }
+
+    #[test]
+    fn test_visible_indices_sorted_by_available_version() {
+        let mut state = AppState::default();
+        for (id, available) in [("A.App", "10.0"), ("B.App", "9.1"), ("C.App", "9.10")] {
+            let mut app = sample_app(id, id);
+            app.available = available.into();
+            state.apps.push(AppItem::from_app(app));
+        }
+
+        let _ = state.handle_sort_by(SortColumn::Available);
+        assert_eq!(state.visible_indices(), vec![1, 2, 0]);
+
+        let _ = state.handle_sort_by(SortColumn::Available);
+        assert_eq!(state.visible_indices(), vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn test_sorted_view_respects_filter_and_select_all() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::from_app(sample_app("Zed", "Zed.Editor")));
+        state.apps.push(AppItem::from_app(sample_app("Chrome", "Google.Chrome")));
+        state.apps.push(AppItem::from_app(sample_app("Atom", "GitHub.Atom")));
+        let _ = state.handle_sort_by(SortColumn::Name);
+        state.search_query = "e".into(); // matches Zed and Chrome, not Atom
+
+        assert_eq!(state.visible_indices(), vec![1, 0]);
+        let _ = state.handle_select_all();
+        assert!(state.apps[0].selected);
+        assert!(state.apps[1].selected);
+        assert!(!state.apps[2].selected);
+    }
+
+    #[test]
+    fn test_visible_indices_with_filter_language() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::from_app(sample_app("Edge", "Microsoft.Edge")));
+        state.apps.push(AppItem::new(sample_app("Chrome", "Google.Chrome"), true));
+        state.apps.push(AppItem::from_app(sample_app("Teams", "Microsoft.Teams")));
+
+        state.search_query = "id:Microsoft.* -teams".into();
+        assert_eq!(state.visible_indices(), vec![0]);
+
+        state.search_query = "selected:yes".into();
+        assert_eq!(state.visible_indices(), vec![1]);
+    }
+
+    #[test]
+    fn test_visible_indices_invalid_query_falls_back_to_substring() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::from_app(sample_app("Edge", "Microsoft.Edge")));
+        state.search_query = "\"edge".into();
+        assert!(state.visible_indices().is_empty());
+        state.search_query = "edg".into();
         assert_eq!(state.visible_indices(), vec![0]);
+    }
+
+    #[test]
+    fn test_fuzzy_search_ranks_by_score() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::from_app(sample_app("Vivaldi Sync Client", "Vivaldi.Vivaldi")));
+        state.apps.push(AppItem::from_app(sample_app("Chrome", "Google.Chrome")));
+        state.apps.push(AppItem::from_app(sample_app(
+            "Microsoft Visual Studio Code",
+            "Microsoft.VisualStudioCode",
+        )));
+        state.apps.push(AppItem::from_app(sample_app("vscode", "Other.VsCode")));
+        state.search_query = "vsc".into();
+
+        assert_eq!(state.visible_indices(), vec![3]);
+
+        let _ = state.update(Message::FuzzySearchToggled(true));
+        let visible = state.visible_indices();
+        assert_eq!(visible[0], 3);
+        assert!(!visible.contains(&1));
+        assert_eq!(visible.len(), 3);
+    }
+
+    #[test]
+    fn test_fuzzy_search_keeps_field_filters() {
+        let mut state = AppState::default();
+        state.settings.fuzzy_search = true;
+        state.apps.push(AppItem::from_app(sample_app("Edge", "Microsoft.Edge")));
+        state.apps.push(AppItem::from_app(sample_app("Edge Dev", "Microsoft.Edge.Dev")));
+        state.search_query = "edg -id:*.Dev".into();
+        let rows = state.visible_matches();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].1.as_ref().unwrap().name, vec![0, 1, 2]);
+    }
+
+    fn sourced_app(name: &str, id: &str, source: &str) -> UpdatableApp {
+        UpdatableApp {
+            source: source.into(),
+            ..sample_app(name, id)
+        }
+    }
+
+    #[test]
+    fn test_source_groups() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::from_app(sourced_app("Edge", "Microsoft.Edge", "winget")));
+        state.apps.push(AppItem::from_app(sourced_app("Paint", "9PCFS5B6T72H", "msstore")));
+        state.apps.push(AppItem::from_app(sourced_app("Chrome", "Google.Chrome", "winget")));
+
+        let groups = state.source_groups();
+        let summary: Vec<(&str, Vec<usize>)> = groups
+            .iter()
+            .map(|(s, rows)| (s.as_str(), rows.iter().map(|(i, _)| *i).collect()))
+            .collect();
+        assert_eq!(summary, vec![("msstore", vec![1]), ("winget", vec![0, 2])]);
+
+        state.search_query = "chrome".into();
+        assert_eq!(state.source_groups().len(), 1);
+    }
+
+    #[test]
+    fn test_select_source() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::from_app(sourced_app("Edge", "Microsoft.Edge", "winget")));
+        state.apps.push(AppItem::from_app(sourced_app("Paint", "9PCFS5B6T72H", "msstore")));
+        state.apps.push(AppItem::from_app(sourced_app("Chrome", "Google.Chrome", "winget")));
+        state.apps[2].held_until = Some(u64::MAX);
+
+        let _ = state.update(Message::SelectSource("winget".into(), true));
+        let selected: Vec<bool> = state.apps.iter().map(|a| a.selected).collect();
+        assert_eq!(selected, vec![true, false, false]);
+
+        state.apps[1].selected = true;
+        let _ = state.update(Message::SelectSource("winget".into(), false));
+        let selected: Vec<bool> = state.apps.iter().map(|a| a.selected).collect();
+        assert_eq!(selected, vec![false, true, false]);
+    }
+
+    #[test]
+    fn test_toggle_source_collapsed() {
+        let mut state = AppState::default();
+        let _ = state.update(Message::ToggleSourceCollapsed("winget".into()));
+        assert!(state.collapsed_sources.contains("winget"));
+        let _ = state.update(Message::ToggleSourceCollapsed("winget".into()));
+        assert!(state.collapsed_sources.is_empty());
+    }
+
+    fn press(state: &mut AppState, key: Key, modifiers: Modifiers) {
+        let _ = state.update(Message::KeyPressed(key, modifiers));
+    }
+
+    fn arrow_down() -> Key {
+        Key::Named(keyboard::key::Named::ArrowDown)
+    }
+
+    #[test]
+    fn test_cursor_moves_through_visible_rows() {
+        let mut state = AppState {
+            loading: false,
+            ..Default::default()
+        };
+        for (name, id) in [("A", "A.A"), ("B", "B.B"), ("C", "C.C")] {
+            state.apps.push(AppItem::from_app(sample_app(name, id)));
+        }
+        state.search_query = "-B.B".into();
+
+        press(&mut state, arrow_down(), Modifiers::empty());
+        assert_eq!(state.cursor.as_deref(), Some("A.A"));
+        press(&mut state, arrow_down(), Modifiers::empty());
+        assert_eq!(state.cursor.as_deref(), Some("C.C"));
+        press(&mut state, arrow_down(), Modifiers::empty());
+        assert_eq!(state.cursor.as_deref(), Some("C.C"));
+        assert_eq!(state.list_scroll, 1.0);
+
+        let _ = state.update(Message::MoveCursor(-5));
+        assert_eq!(state.cursor.as_deref(), Some("A.A"));
+    }
+
+    #[test]
+    fn test_space_toggles_cursor_row() {
+        let mut state = AppState {
+            loading: false,
+            ..Default::default()
+        };
+        state.apps.push(AppItem::from_app(sample_app("A", "A.A")));
+        let _ = state.update(Message::MoveCursor(1));
+
+        press(&mut state, Key::Named(keyboard::key::Named::Space), Modifiers::empty());
+        assert!(state.apps[0].selected);
+
+        state.updating = true;
+        press(&mut state, Key::Named(keyboard::key::Named::Space), Modifiers::empty());
+        assert!(state.apps[0].selected);
+    }
 
-        state.search_query.clear();
-        assert_eq!(state.visible_indices(), vec![0, 1, 2]);
+    #[test]
+    fn test_ctrl_a_selects_visible() {
+        let mut state = AppState {
+            loading: false,
+            ..Default::default()
+        };
+        state.apps.push(AppItem::from_app(sample_app("A", "A.A")));
+        state.apps.push(AppItem::from_app(sample_app("B", "B.B")));
+        state.search_query = "A.A".into();
+
+        press(&mut state, Key::Character("a".into()), Modifiers::CTRL);
+        assert_eq!(state.selected_count(), 1);
+        press(
+            &mut state,
+            Key::Character("A".into()),
+            Modifiers::CTRL | Modifiers::SHIFT,
+        );
+        assert_eq!(state.selected_count(), 0);
     }
 
     #[test]
-    fn test_handle_select_all() {
-        let mut state = AppState::default();
-        state
-            .apps
-            .push(AppItem::from_app(sample_app("A", "A.A")));
-        state
-            .apps
-            .push(AppItem::from_app(sample_app("B", "B.B")));
+    fn test_shortcuts_blocked_behind_dialogs() {
+        let mut state = AppState {
+            loading: false,
+            ..Default::default()
+        };
+        state.apps.push(AppItem::from_app(sample_app("A", "A.A")));
+        state.show_settings = true;
+
+        press(&mut state, arrow_down(), Modifiers::empty());
+        assert!(state.cursor.is_none());
+
+        press(&mut state, Key::Named(keyboard::key::Named::Escape), Modifiers::empty());
+        assert!(!state.show_settings);
+    }
 
-        let _ = state.handle_select_all();
-        assert!(state.apps.iter().all(|a| a.selected));
+    #[test]
+    fn test_escape_closes_topmost_dialog() {
+        let mut state = AppState {
+            show_confirmation: true,
+            pending_updates: vec![("A".into(), "A.A".into())],
+            ..Default::default()
+        };
+        let _ = state.update(Message::ToggleHelp);
+
+        let _ = state.update(Message::CloseOverlay);
+        assert!(!state.show_help);
+        assert!(state.show_confirmation);
+
+        let _ = state.update(Message::CloseOverlay);
+        assert!(!state.show_confirmation);
+        assert!(state.pending_updates.is_empty());
     }
 
     #[test]
-    fn test_handle_select_all_respects_filter() {
+    fn test_open_details_for_cursor() {
         let mut state = AppState::default();
-        state
-            .apps
-            .push(AppItem::from_app(sample_app("Firefox", "Mozilla.Firefox")));
-        state
-            .apps
-            .push(AppItem::from_app(sample_app("Chrome", "Google.Chrome")));
-
-        state.search_query = "fire".into();
-        let _ = state.handle_select_all();
-        assert!(state.apps[0].selected);
-        assert!(!state.apps[1].selected); // Chrome not visible
+        state.apps.push(AppItem::from_app(sample_app("A", "A.A")));
+        let _ = state.update(Message::OpenDetails);
+        assert!(state.details.is_none());
+
+        let _ = state.update(Message::MoveCursor(1));
+        let _ = state.update(Message::OpenDetails);
+        assert_eq!(state.details.as_deref(), Some("A.A"));
+        assert!(state.overlay_open());
     }
 
     #[test]
-    fn test_handle_deselect_all() {
+    fn test_args_profile_edited_in_details() {
         let mut state = AppState::default();
-        state.apps.push(AppItem::new(sample_app("A", "A.A"), true));
+        state.apps.push(AppItem::from_app(sample_app("A", "A.A")));
+        let _ = state.update(Message::MoveCursor(1));
+        let _ = state.update(Message::OpenDetails);
+
+        let _ = state.update(Message::OverrideArgsChanged("/S /LICENSE={version}".into()));
+        assert!(state.args_profile_error.is_none());
+        let request = state.upgrade_request("A.A").unwrap();
+        assert_eq!(request.extra_args, ["--override", "/S /LICENSE=2.0"]);
+        assert_eq!(request.source, "winget");
+        assert!(request.accept_source_agreements);
+
+        // Invalid input is reported and leaves the stored profile alone.
+        let _ = state.update(Message::CustomArgsChanged("/Q & calc".into()));
+        assert!(state.args_profile_error.is_some());
+        assert!(state.settings.args_profile_for("A.A").unwrap().custom_args.is_empty());
+
+        let _ = state.update(Message::CustomArgsChanged(String::new()));
+        let _ = state.update(Message::OverrideArgsChanged(String::new()));
+        assert!(state.settings.args_profiles.is_empty());
+        assert!(state.upgrade_request("A.A").unwrap().extra_args.is_empty());
+    }
 
-        let _ = state.handle_deselect_all();
-        assert!(!state.apps[0].selected);
+    #[test]
+    fn test_upgrade_request_revalidates_stored_profile() {
+        let mut state = AppState::default();
+        state.apps.push(AppItem::from_app(sample_app("A", "A.A")));
+        state.settings.args_profiles.insert(
+            "A.A".into(),
+            ArgsProfile {
+                override_args: "/S & calc".into(),
+                custom_args: String::new(),
+            },
+        );
+        let err = state.upgrade_request("A.A").unwrap_err();
+        assert!(err.starts_with("FAILURE:A.A - invalid arguments profile"));
+
+        state.settings.args_profiles.insert(
+            "B.B".into(),
+            ArgsProfile {
+                override_args: "/LICENSE={version}".into(),
+                custom_args: String::new(),
+            },
+        );
+        let err = state.upgrade_request("B.B").unwrap_err();
+        assert!(err.contains("{version}"));
     }
 
     #[test]
-    fn test_handle_toggle_app() {
+    fn test_open_details_loads_args_profile() {
         let mut state = AppState::default();
+        state.apps.push(AppItem::from_app(sample_app("A", "A.A")));
+        state.settings.args_profiles.insert(
+            "A.A".into(),
+            ArgsProfile {
+                override_args: String::new(),
+                custom_args: "/NoShortcut".into(),
+            },
+        );
+        let _ = state.update(Message::MoveCursor(1));
+        let _ = state.update(Message::OpenDetails);
+        assert_eq!(state.custom_args_input, "/NoShortcut");
+        assert!(state.override_args_input.is_empty());
+        assert!(state.tr("details-args-hint").contains("{version}"));
+    }
+
+    fn five_apps() -> AppState {
+        let mut state = AppState {
+            loading: false,
+            ..Default::default()
+        };
+        for (name, id) in [("A", "A.A"), ("B", "B.B"), ("C", "C.C"), ("D", "D.D"), ("E", "E.E")] {
+            state.apps.push(AppItem::from_app(sample_app(name, id)));
+        }
         state
-            .apps
-            .push(AppItem::from_app(sample_app("A", "A.A")));
+    }
 
-        let _ = state.handle_toggle_app(0);
-        assert!(state.apps[0].selected);
+    #[test]
+    fn test_select_range_from_anchor() {
+        let mut state = five_apps();
+        let _ = state.update(Message::ToggleApp(1));
+        let _ = state.update(Message::SelectRange(3));
+        let selected: Vec<bool> = state.apps.iter().map(|a| a.selected).collect();
+        assert_eq!(selected, vec![false, true, true, true, false]);
+
+        // Shift-clicking a selected row clears the range again.
+        let _ = state.update(Message::SelectRange(2));
+        let selected: Vec<bool> = state.apps.iter().map(|a| a.selected).collect();
+        assert_eq!(selected, vec![false, false, false, true, false]);
+    }
 
-        let _ = state.handle_toggle_app(0);
-        assert!(!state.apps[0].selected);
+    #[test]
+    fn test_select_range_follows_display_order_and_skips_hidden() {
+        let mut state = five_apps();
+        state.sort = Some(SortOrder {
+            column: SortColumn::Name,
+            ascending: false,
+        });
+        state.search_query = "-C.C".into();
+        state.apps[3].held_until = Some(u64::MAX);
+
+        let _ = state.update(Message::ToggleApp(4));
+        let _ = state.update(Message::SelectRange(1));
+        let selected: Vec<bool> = state.apps.iter().map(|a| a.selected).collect();
+        assert_eq!(selected, vec![false, true, false, false, true]);
     }
 
     #[test]
-    fn test_handle_toggle_app_out_of_bounds() {
-        let mut state = AppState::default();
-        // Should not panic
-        let _ = state.handle_toggle_app(99);
+    fn test_select_range_without_anchor_toggles() {
+        let mut state = five_apps();
+        let _ = state.update(Message::SelectRange(2));
+        assert_eq!(state.selected_count(), 1);
+        assert_eq!(state.selection_anchor.as_deref(), Some("C.C"));
     }
 
     #[test]
-    fn test_handle_update_selected_empty() {
-        let mut state = AppState::default();
-        state.loading = false;
-        let _ = state.handle_update_selected();
-        assert_eq!(state.status_message, "No apps selected");
-        assert!(!state.show_confirmation);
+    fn test_invert_selection() {
+        let mut state = five_apps();
+        state.apps[0].selected = true;
+        state.apps[4].held_until = Some(u64::MAX);
+        state.search_query = "-B.B".into();
+        let _ = state.update(Message::InvertSelection);
+        let selected: Vec<bool> = state.apps.iter().map(|a| a.selected).collect();
+        assert_eq!(selected, vec![false, false, true, true, false]);
     }
 
     #[test]
-    fn test_handle_update_selected_shows_confirmation() {
-        let mut state = AppState::default();
-        state
-            .apps
-            .push(AppItem::new(sample_app("My App", "My.App"), true));
+    fn test_update_now_keeps_selection() {
+        let mut state = five_apps();
+        state.settings.skip_row_update_confirmation = true;
+        state.apps[0].selected = true;
 
-        let _ = state.handle_update_selected();
-        assert!(state.show_confirmation);
-        assert_eq!(state.pending_updates.len(), 1);
-        assert_eq!(
-            state.pending_updates[0],
-            ("My App".into(), "My.App".into())
-        );
+        let _ = state.update(Message::UpdateNow(2));
+        assert!(state.updating);
+        assert!(!state.show_confirmation);
+        assert_eq!(state.update_queue, vec!["C.C".to_string()]);
+        assert_eq!(state.updating_id(), Some("C.C"));
+
+        let _ = state.update(Message::UpdateSingleComplete("SUCCESS: C updated".into()));
+        assert!(!state.updating);
+        assert!(!state.show_results_dialog);
+        assert!(state.apps[0].selected);
+        assert_eq!(state.status_message, "Updated C");
+        assert_eq!(state.row_results["C.C"], "SUCCESS: C updated");
     }
 
     #[test]
-    fn test_handle_cancel_update() {
-        let mut state = AppState::default();
-        state.show_confirmation = true;
-        state
-            .pending_updates
-            .push(("A".into(), "A.Id".into()));
+    fn test_update_now_asks_for_confirmation_by_default() {
+        let mut state = five_apps();
+        let _ = state.update(Message::UpdateNow(1));
+        assert!(state.show_confirmation);
+        assert!(!state.updating);
+        assert_eq!(state.pending_updates, vec![("B".to_string(), "B.B".to_string())]);
 
-        let _ = state.handle_cancel_update();
-        assert!(!state.show_confirmation);
-        assert!(state.pending_updates.is_empty());
-        assert_eq!(state.status_message, "Update cancelled");
+        let _ = state.update(Message::CancelUpdate);
+        assert!(!state.single_update);
     }
 
     #[test]
-    fn test_handle_close_results_dialog() {
-        let mut state = AppState::default();
-        state.show_results_dialog = true;
-        let _ = state.handle_close_results_dialog();
-        assert!(!state.show_results_dialog);
+    fn test_update_now_ignores_held_apps() {
+        let mut state = five_apps();
+        state.settings.skip_row_update_confirmation = true;
+        state.apps[1].held_until = Some(u64::MAX);
+        let _ = state.update(Message::UpdateNow(1));
+        assert!(!state.updating);
     }
 
     #[test]
-    fn test_handle_search_changed() {
+    fn test_theme_follows_setting_and_system() {
         let mut state = AppState::default();
-        let _ = state.handle_search_changed("firefox".into());
-        assert_eq!(state.search_query, "firefox");
+        assert_eq!(state.theme(), Theme::Dark);
+
+        // The default keeps the dark look whatever the desktop uses.
+        let _ = state.update(Message::SystemThemeDetected(false));
+        assert_eq!(state.theme(), Theme::Dark);
+
+        let _ = state.update(Message::ThemeChanged(ThemeChoice::System));
+        assert_eq!(state.settings.theme, ThemeChoice::System);
+        assert_eq!(state.theme(), Theme::Light);
+        assert_eq!(state.palette(), ThemeChoice::Light.palette(true));
+
+        let _ = state.update(Message::ThemeChanged(ThemeChoice::Dark));
+        assert_eq!(state.settings.theme, ThemeChoice::Dark);
+        assert_eq!(state.theme(), Theme::Dark);
     }
 
     #[test]
-    fn test_handle_load_apps_resets_state() {
+    fn test_window_resize_adjusts_list_viewports() {
         let mut state = AppState::default();
-        state.loading = false;
-        state.search_query = "test".into();
-        let _ = state.handle_load_apps();
-        assert!(state.loading);
-        assert!(state.search_query.is_empty());
-        assert_eq!(state.status_message, "Loading updatable apps...");
+        let _ = state.update(Message::WindowResized(700.0));
+        assert_eq!(state.list_viewport_height, 0.0);
+
+        state.list_viewport_height = 500.0;
+        state.inventory_viewport_height = 450.0;
+        let _ = state.update(Message::WindowResized(900.0));
+        assert_eq!(state.list_viewport_height, 700.0);
+        assert_eq!(state.inventory_viewport_height, 650.0);
+        // Not reported yet: left for the first scroll event.
+        assert_eq!(state.discover_viewport_height, 0.0);
+
+        let _ = state.update(Message::WindowResized(100.0));
+        assert_eq!(state.list_viewport_height, 0.0);
+        assert_eq!(state.window_height, 100.0);
     }
 
     #[test]
-    fn test_handle_apps_loaded_ok() {
+    fn test_language_setting_overrides_system() {
+        let mut state = AppState {
+            system_language: Language::German,
+            ..Default::default()
+        };
+        state.reload_catalog();
+        assert_eq!(state.tr("toolbar-refresh"), "Aktualisieren");
+
+        let _ = state.update(Message::LanguageChanged(Some(Language::French)));
+        assert_eq!(state.settings.language, Some(Language::French));
+        let _ = state.handle_apps_loaded(Ok(vec![sample_app("A", "A.App")]));
+        assert_eq!(state.status_message, "1 application à mettre à jour");
+
+        let _ = state.update(Message::LanguageChanged(None));
+        assert_eq!(state.catalog.language(), Language::German);
+    }
+
+    fn installed(name: &str, id: &str, source: &str) -> InstalledApp {
+        InstalledApp::new(name.into(), id.into(), "1.0".into(), String::new(), source.into())
+    }
+
+    #[test]
+    fn test_installed_tab_loads_inventory_once() {
         let mut state = AppState::default();
-        let apps = vec![
-            sample_app("Firefox", "Mozilla.Firefox"),
-            sample_app("Chrome", "Google.Chrome"),
+        let _ = state.update(Message::TabSelected(Tab::Installed));
+        assert_eq!(state.tab, Tab::Installed);
+        assert!(state.inventory_loading);
+        assert_eq!(state.status_message, "Loading installed apps...");
+
+        let inventory = vec![
+            installed("Git", "Git.Git", "winget"),
+            installed("Tool", "ARP\\Tool", ""),
         ];
-        let _ = state.handle_apps_loaded(Ok(apps));
-        assert!(!state.loading);
-        assert_eq!(state.apps.len(), 2);
-        assert!(state.status_message.contains("2"));
+        let _ = state.update(Message::InventoryLoaded(Ok(inventory)));
+        assert!(!state.inventory_loading);
+        assert_eq!(state.inventory.len(), 2);
+        assert_eq!(state.status_message, "2 packages installed, 1 not managed by winget");
+
+        let _ = state.update(Message::TabSelected(Tab::Updates));
+        let _ = state.update(Message::TabSelected(Tab::Installed));
+        assert!(!state.inventory_loading);
     }
 
     #[test]
-    fn test_handle_apps_loaded_err() {
-        let mut state = AppState::default();
-        let _ = state.handle_apps_loaded(Err("Connection failed".into()));
-        assert!(!state.loading);
-        assert!(state.apps.is_empty());
-        assert!(state.status_message.contains("Error"));
-        assert!(state.status_message.contains("Connection failed"));
+    fn test_visible_inventory_filters() {
+        let mut state = AppState {
+            inventory: vec![
+                installed("Git", "Git.Git", "winget"),
+                installed("Tool", "ARP\\Tool", ""),
+                installed("Game", "MSIX\\Game", ""),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(state.visible_inventory(), vec![0, 1, 2]);
+
+        let _ = state.update(Message::UnmanagedOnlyToggled(true));
+        assert_eq!(state.visible_inventory(), vec![1, 2]);
+
+        state.search_query = "game".into();
+        assert_eq!(state.visible_inventory(), vec![2]);
+    }
+
+    fn inventory_state() -> AppState {
+        AppState {
+            tab: Tab::Installed,
+            inventory: vec![
+                installed("Git", "Git.Git", "winget"),
+                installed("Tool", "ARP\\Tool", ""),
+            ],
+            ..Default::default()
+        }
     }
 
     #[test]
-    fn test_handle_apps_loaded_empty() {
-        let mut state = AppState::default();
-        let _ = state.handle_apps_loaded(Ok(vec![]));
-        assert!(!state.loading);
-        assert!(state.apps.is_empty());
-        assert!(state.status_message.contains("0"));
+    fn test_uninstall_selected_asks_for_confirmation() {
+        let mut state = inventory_state();
+        let _ = state.update(Message::UninstallSelected);
+        assert!(!state.show_uninstall_confirmation);
+        assert_eq!(state.status_message, "No apps selected");
+
+        let _ = state.update(Message::ToggleInventoryApp(1));
+        let _ = state.update(Message::UninstallSelected);
+        assert!(state.show_uninstall_confirmation);
+        assert_eq!(state.pending_updates, vec![("Tool".into(), "ARP\\Tool".into())]);
+
+        let _ = state.update(Message::CloseOverlay);
+        assert!(!state.show_uninstall_confirmation);
+        assert!(!state.updating);
+        assert_eq!(state.status_message, "Uninstall cancelled");
     }
 
     #[test]
-    fn test_handle_confirm_update_sets_progress() {
+    fn test_uninstall_batch_runs_and_reloads_inventory() {
+        let mut state = inventory_state();
+        let _ = state.update(Message::ToggleInventoryApp(0));
+        let _ = state.update(Message::ToggleInventoryApp(1));
+        let _ = state.update(Message::UninstallSelected);
+        let _ = state.update(Message::ConfirmUninstall);
+        assert!(state.updating);
+        assert_eq!(state.batch_action, BatchAction::Uninstall);
+        assert_eq!(state.status_message, "Uninstalling 0/2 packages...");
+
+        // The selection is frozen while the batch runs.
+        let _ = state.update(Message::ToggleInventoryApp(0));
+        assert_eq!(state.inventory_selected.len(), 2);
+
+        let _ = state.update(Message::UpdateSingleComplete(
+            "SUCCESS:Git.Git - uninstalled successfully".into(),
+        ));
+        assert_eq!(state.status_message, "Uninstalling 1/2 packages...");
+        let _ = state.update(Message::UpdateSingleComplete(
+            "[!] ARP\\Tool - requires interactive uninstall".into(),
+        ));
+        assert!(!state.updating);
+        assert!(state.show_results_dialog);
+        assert_eq!(state.result_counts(), (1, 0, 1));
+        assert!(state.inventory_selected.is_empty());
+        assert!(state.inventory_loading);
+        assert!(state.row_results.is_empty());
+        assert_eq!(state.status_message, "Uninstall complete");
+    }
+
+    fn search_result(name: &str, id: &str) -> SearchResult {
+        SearchResult::new(name.into(), id.into(), "2.0".into(), String::new(), "winget".into())
+    }
+
+    #[test]
+    fn test_search_packages_reports_results() {
         let mut state = AppState::default();
-        state
-            .pending_updates
-            .push(("App A".into(), "A.App".into()));
-        state
-            .pending_updates
-            .push(("App B".into(), "B.App".into()));
+        let _ = state.update(Message::SearchPackages);
+        assert!(!state.discover_loading);
+
+        let _ = state.update(Message::DiscoverQueryChanged("  git ".into()));
+        let _ = state.update(Message::SearchPackages);
+        assert!(state.discover_loading);
+        assert_eq!(state.status_message, "Searching for \"git\"...");
+
+        let _ = state.update(Message::SearchResultsLoaded(Ok(vec![
+            search_result("Git", "Git.Git"),
+            search_result("GitHub CLI", "GitHub.cli"),
+        ])));
+        assert!(!state.discover_loading);
+        assert_eq!(state.discover_results.len(), 2);
+        assert_eq!(state.status_message, "2 packages found for \"git\"");
+    }
 
-        let _ = state.handle_confirm_update();
-        assert!(!state.show_confirmation);
+    #[test]
+    fn test_install_selected_queues_version_and_scope() {
+        let mut state = AppState {
+            tab: Tab::Discover,
+            discover_results: vec![
+                search_result("Git", "Git.Git"),
+                search_result("GitHub CLI", "GitHub.cli"),
+            ],
+            ..Default::default()
+        };
+        let _ = state.update(Message::ToggleSearchResult(0));
+        let _ = state.update(Message::ToggleSearchResult(1));
+        let _ = state.update(Message::InstallVersionChanged("Git.Git".into(), " 2.44.0 ".into()));
+        let _ = state.update(Message::InstallScopeChanged(InstallScope::Machine));
+        let _ = state.update(Message::InstallSelected);
+
+        assert!(state.show_install_confirmation);
+        assert_eq!(state.install_requests.len(), 2);
+        assert_eq!(state.install_requests[0].version.as_deref(), Some("2.44.0"));
+        assert_eq!(state.install_requests[0].source, "winget");
+        assert_eq!(state.install_requests[1].version, None);
+        assert!(state.install_requests.iter().all(|r| r.scope == InstallScope::Machine));
+
+        let _ = state.update(Message::ConfirmInstall);
         assert!(state.updating);
-        assert_eq!(state.update_total, 2);
-        assert_eq!(state.update_completed, 0);
-        assert_eq!(state.update_queue, vec!["A.App", "B.App"]);
-        assert!(state.status_message.contains("0/2"));
+        assert_eq!(state.batch_action, BatchAction::Install);
+        assert_eq!(state.status_message, "Installing 0/2 packages...");
+
+        let _ = state.update(Message::UpdateSingleComplete(
+            "SUCCESS:Git.Git - installed successfully".into(),
+        ));
+        let _ = state.update(Message::UpdateSingleComplete(
+            "[i] GitHub.cli - already installed".into(),
+        ));
+        assert!(!state.updating);
+        assert!(state.show_results_dialog);
+        assert!(state.discover_selected.is_empty());
+        assert!(state.inventory_loading);
+        assert_eq!(state.status_message, "Install complete");
     }
 
     #[test]
-    fn test_handle_confirm_update_empty_queue() {
-        let mut state = AppState::default();
-        // pending_updates is empty
-        let _ = state.handle_confirm_update();
-        assert!(!state.show_confirmation);
-        assert_eq!(state.update_total, 0);
+    fn test_cancel_install_clears_queue() {
+        let mut state = AppState {
+            discover_results: vec![search_result("Git", "Git.Git")],
+            ..Default::default()
+        };
+        let _ = state.update(Message::ToggleSearchResult(0));
+        let _ = state.update(Message::InstallSelected);
+        let _ = state.update(Message::CloseOverlay);
+        assert!(!state.show_install_confirmation);
+        assert!(state.install_requests.is_empty());
+        assert_eq!(state.status_message, "Install cancelled");
+        assert!(state.discover_selected.contains("Git.Git"));
     }
 
     #[test]
-    fn test_handle_update_single_complete_continues() {
-        let mut state = AppState::default();
-        state.updating = true;
-        state.update_total = 3;
-        state.update_completed = 0;
-        state.update_queue = vec!["A.App".into(), "B.App".into(), "C.App".into()];
+    fn test_export_selected_packages() {
+        let mut state = inventory_state();
+        let path = std::env::temp_dir().join(format!("wau_export_{}.json", std::process::id()));
+        state.package_file = path.display().to_string();
+
+        let _ = state.update(Message::ExportPackages);
+        assert_eq!(state.status_message, "Select packages managed by winget to export");
+
+        let _ = state.update(Message::ToggleInventoryApp(0));
+        let _ = state.update(Message::ToggleInventoryApp(1));
+        let _ = state.update(Message::ExportPackages);
+        assert!(state.status_message.starts_with("Exported 1 package to "));
+
+        let set = PackageSet::load(&path).unwrap();
+        assert_eq!(set.sources[0].packages[0].package_identifier, "Git.Git");
+        assert_eq!(set.sources[0].packages[0].version.as_deref(), Some("1.0"));
+        let _ = std::fs::remove_file(path);
+    }
 
-        let _ = state.handle_update_single_complete("SUCCESS:A.App - done".into());
+    #[test]
+    fn test_import_upgrade_carries_remembered_options() {
+        let mut state = inventory_state();
+        state.settings.package_options.insert(
+            "Git.Git".into(),
+            "scope=machine arch=x64".parse().unwrap(),
+        );
+        let set = PackageSet::from_json(
+            r#"{"Sources": [{
+                "Packages": [
+                    {"PackageIdentifier": "Git.Git", "Version": "2.0"},
+                    {"PackageIdentifier": "Mozilla.Firefox"}
+                ],
+                "SourceDetails": {"Name": "winget"}
+            }]}"#,
+        )
+        .unwrap();
+        let inventory = state.inventory.clone();
+        let _ = state.update(Message::ImportLoaded(Ok((set, inventory))));
+        let _ = state.update(Message::ConfirmImport);
+
+        let upgrade = &state.install_requests[0];
+        assert!(upgrade.upgrade);
+        assert_eq!(upgrade.options.scope, InstallScope::Machine);
+        assert_eq!(upgrade.options.architecture.arg(), Some("x64"));
+        // Fresh installs keep winget's defaults.
+        assert!(state.install_requests[1].options.is_default());
+    }
 
-        assert_eq!(state.update_completed, 1);
+    #[test]
+    fn test_import_upgrade_carries_args_profile() {
+        let mut state = inventory_state();
+        for id in ["Git.Git", "Mozilla.Firefox"] {
+            state.settings.args_profiles.insert(
+                id.into(),
+                ArgsProfile {
+                    override_args: String::new(),
+                    custom_args: "/LICENSE={version}".into(),
+                },
+            );
+        }
+        let set = PackageSet::from_json(
+            r#"{"Sources": [{
+                "Packages": [
+                    {"PackageIdentifier": "Git.Git", "Version": "2.0"},
+                    {"PackageIdentifier": "Mozilla.Firefox"}
+                ],
+                "SourceDetails": {"Name": "winget"}
+            }]}"#,
+        )
+        .unwrap();
+        let inventory = state.inventory.clone();
+        let _ = state.update(Message::ImportLoaded(Ok((set, inventory))));
+        let _ = state.update(Message::ConfirmImport);
+
+        let upgrade = state.batch_install_request(&state.install_requests[0]).unwrap();
+        assert!(upgrade.upgrade);
+        assert_eq!(upgrade.extra_args, vec!["--custom", "/LICENSE=2.0"]);
+        // Fresh installs keep the installer's defaults.
+        let install = state.batch_install_request(&state.install_requests[1]).unwrap();
+        assert!(install.extra_args.is_empty());
+
+        state.settings.args_profiles.get_mut("Git.Git").unwrap().custom_args = "/S & calc".into();
+        let err = state.batch_install_request(&state.install_requests[0]).unwrap_err();
+        assert!(err.starts_with("FAILURE:Git.Git - invalid arguments profile"));
+    }
+
+    #[test]
+    fn test_import_previews_and_queues_installs_and_upgrades() {
+        let mut state = inventory_state();
+        let set = PackageSet::from_json(
+            r#"{"Sources": [{
+                "Packages": [
+                    {"PackageIdentifier": "Git.Git", "Version": "2.0"},
+                    {"PackageIdentifier": "Mozilla.Firefox"},
+                    {"PackageIdentifier": "ARP\\Tool", "Version": "1.0"}
+                ],
+                "SourceDetails": {"Name": "winget"}
+            }]}"#,
+        )
+        .unwrap();
+        let inventory = state.inventory.clone();
+        let _ = state.update(Message::ImportLoaded(Ok((set, inventory))));
+        assert!(state.show_import_preview);
+        assert!(state.overlay_open());
+        assert_eq!(state.import_plan.len(), 3);
+
+        let _ = state.update(Message::ConfirmImport);
+        assert!(!state.show_import_preview);
         assert!(state.updating);
-        assert!(!state.show_results_dialog);
-        assert_eq!(state.update_results.len(), 1);
-        assert!(state.status_message.contains("1/3"));
+        assert_eq!(state.batch_action, BatchAction::Install);
+        assert_eq!(state.update_queue, vec!["Git.Git", "Mozilla.Firefox"]);
+        assert!(state.install_requests[0].upgrade);
+        assert_eq!(state.install_requests[0].name, "Git");
+        assert_eq!(state.install_requests[0].version.as_deref(), Some("2.0"));
+        assert!(!state.install_requests[1].upgrade);
+        assert_eq!(state.install_requests[1].source, "winget");
     }
 
     #[test]
-    fn test_handle_update_single_complete_finishes() {
-        let mut state = AppState::default();
-        state.updating = true;
-        state.update_total = 1;
-        state.update_completed = 0;
-        state.update_queue = vec!["A.App".into()];
-        state.apps.push(AppItem::new(sample_app("A", "A.App"), true));
+    fn test_import_error_and_cancel() {
+        let mut state = inventory_state();
+        state.inventory_loading = true;
+        let _ = state.update(Message::ImportLoaded(Err("Failed to read x".into())));
+        assert!(!state.inventory_loading);
+        assert_eq!(state.status_message, "Error: Failed to read x");
+
+        state.import_plan = vec![ImportItem {
+            id: "Git.Git".into(),
+            source: "winget".into(),
+            version: None,
+            action: ImportAction::Install,
+        }];
+        state.show_import_preview = true;
+        let _ = state.update(Message::CloseOverlay);
+        assert!(!state.show_import_preview);
+        assert!(state.import_plan.is_empty());
+        assert_eq!(state.status_message, "Import cancelled");
+    }
 
-        let _ = state.handle_update_single_complete("SUCCESS:A.App - done".into());
+    #[test]
+    fn test_check_drift_requires_manifest() {
+        let mut state = inventory_state();
+        let _ = state.update(Message::CheckDrift);
+        assert!(!state.inventory_loading);
+        assert_eq!(
+            state.status_message,
+            "Set a desired-state manifest in Settings first"
+        );
 
-        assert_eq!(state.update_completed, 1);
+        state.settings.manifest_path = "desired.toml".into();
+        let _ = state.update(Message::CheckDrift);
+        assert!(state.inventory_loading);
+        let _ = state.update(Message::DriftChecked(Err("Invalid manifest: x".into())));
+        assert!(!state.inventory_loading);
+        assert!(state.drift_report.is_none());
+        assert_eq!(state.status_message, "Error: Invalid manifest: x");
+    }
+
+    #[test]
+    fn test_apply_drift_queues_installs_and_selects_forbidden() {
+        let mut state = inventory_state();
+        state.settings.manifest_path = "desired.toml".into();
+        let manifest = Manifest::from_toml(
+            r#"
+            [packages]
+            "Git.Git" = "=2.0"
+            "Mozilla.Firefox" = "latest"
+            'ARP\Tool' = "absent"
+            "#,
+        )
+        .unwrap();
+        let inventory = state.inventory.clone();
+        let report = reconcile(&manifest, &inventory, &[]);
+        let _ = state.update(Message::DriftChecked(Ok((report, inventory))));
+        assert!(state.overlay_open());
+        assert_eq!(state.drift_report.as_ref().map(Vec::len), Some(3));
+        assert_eq!(state.status_message, "3 packages drift from the manifest");
+
+        let _ = state.update(Message::ApplyDrift);
+        assert!(state.drift_report.is_none());
+        assert!(state.updating);
+        assert_eq!(state.batch_action, BatchAction::Install);
+        assert_eq!(state.update_queue, vec!["Git.Git", "Mozilla.Firefox"]);
+        assert!(state.install_requests[0].upgrade);
+        assert_eq!(state.install_requests[0].name, "Git");
+        assert_eq!(state.install_requests[0].source, "winget");
+        assert_eq!(state.install_requests[0].version.as_deref(), Some("2.0"));
+        assert!(!state.install_requests[1].upgrade);
+        assert_eq!(state.install_requests[1].version, None);
+        assert_eq!(state.inventory_selected, HashSet::from(["ARP\\Tool".to_string()]));
+    }
+
+    #[test]
+    fn test_apply_drift_with_only_forbidden_asks_to_uninstall() {
+        let mut state = inventory_state();
+        let manifest = Manifest::from_toml("[packages]\n'ARP\\Tool' = \"absent\"\n").unwrap();
+        let inventory = state.inventory.clone();
+        state.drift_report = Some(reconcile(&manifest, &inventory, &[]));
+        let _ = state.update(Message::ApplyDrift);
         assert!(!state.updating);
-        assert!(state.show_results_dialog);
-        assert!(!state.apps[0].selected);
-        assert_eq!(state.update_results, vec!["SUCCESS:A.App - done"]);
-        assert_eq!(state.status_message, "Update complete");
+        assert!(state.show_uninstall_confirmation);
+        assert_eq!(state.pending_updates, vec![("Tool".into(), "ARP\\Tool".into())]);
+
+        state.show_uninstall_confirmation = false;
+        state.drift_report = Some(Vec::new());
+        let _ = state.update(Message::CloseOverlay);
+        assert!(state.drift_report.is_none());
+    }
+
+    fn source(name: &str) -> SourceInfo {
+        SourceInfo {
+            name: name.into(),
+            argument: format!("https://{name}.example/api"),
+            explicit: false,
+        }
     }
 
     #[test]
-    fn test_handle_update_single_complete_second_of_two() {
+    fn test_sources_tab_loads_sources_once() {
         let mut state = AppState::default();
-        state.updating = true;
-        state.update_total = 2;
-        state.update_completed = 1; // first already done
-        state.update_results = vec!["SUCCESS:A - done".into()];
-        state.update_queue = vec!["A.App".into(), "B.App".into()];
-        state.apps.push(AppItem::new(sample_app("A", "A.App"), true));
-        state.apps.push(AppItem::new(sample_app("B", "B.App"), true));
+        let _ = state.update(Message::TabSelected(Tab::Sources));
+        assert!(state.sources_loading);
+        assert_eq!(state.status_message, "Loading sources...");
+
+        let _ = state.update(Message::SourcesLoaded(Ok(vec![source("winget"), source("contoso")])));
+        assert!(!state.sources_loading);
+        assert_eq!(state.sources.len(), 2);
+        assert_eq!(state.status_message, "2 sources configured");
+
+        let _ = state.update(Message::TabSelected(Tab::Updates));
+        let _ = state.update(Message::TabSelected(Tab::Sources));
+        assert!(!state.sources_loading);
+    }
 
-        let _ = state.handle_update_single_complete("FAILURE:B - err".into());
+    #[test]
+    fn test_add_source_runs_and_reloads() {
+        let mut state = AppState {
+            tab: Tab::Sources,
+            ..Default::default()
+        };
+        let _ = state.update(Message::AddSource);
+        assert!(!state.source_busy);
+        assert_eq!(state.status_message, "Enter a name and URL for the new source");
+
+        let _ = state.update(Message::SourceNameChanged("contoso".into()));
+        let _ = state.update(Message::SourceArgumentChanged("https://contoso.example/api".into()));
+        let _ = state.update(Message::AddSource);
+        assert!(state.source_busy);
+        // Only one source command runs at a time.
+        let _ = state.update(Message::RunSourceAction(SourceAction::Update(None)));
+        assert!(state.source_busy);
+
+        let _ = state.update(Message::SourceActionComplete(
+            "SUCCESS:contoso - source added".into(),
+        ));
+        assert!(!state.source_busy);
+        assert!(state.sources_loading);
+        assert!(state.source_name_input.is_empty());
+        assert_eq!(state.source_result.as_deref(), Some("SUCCESS:contoso - source added"));
+    }
 
-        assert_eq!(state.update_completed, 2);
-        assert!(!state.updating);
-        assert!(state.show_results_dialog);
-        assert_eq!(state.update_results.len(), 2);
+    #[test]
+    fn test_failed_source_action_keeps_inputs() {
+        let mut state = AppState {
+            source_name_input: "msstore".into(),
+            source_busy: true,
+            ..Default::default()
+        };
+        let _ = state.update(Message::SourceActionComplete(
+            "[!] msstore - requires accepting the source agreements: Terms".into(),
+        ));
+        assert_eq!(state.source_name_input, "msstore");
+        assert!(state.source_result.as_deref().unwrap().starts_with("[!]"));
     }
 
     #[test]
-    fn test_progress_defaults() {
-        let state = AppState::default();
-        assert_eq!(state.update_total, 0);
-        assert_eq!(state.update_completed, 0);
-        assert!(state.update_queue.is_empty());
+    fn test_source_toggle_persists_and_reloads() {
+        let dir = std::env::temp_dir().join(format!("wau_sources_{}", std::process::id()));
+        let mut state = AppState::with_data_dir(Some(dir.clone()));
+        state.loading = false;
+        state.inventory = vec![installed("Git", "Git.Git", "winget")];
+
+        let _ = state.update(Message::SourceEnabledToggled("msstore".into(), false));
+        assert_eq!(state.settings.disabled_sources, vec!["msstore"]);
+        assert!(state.loading);
+        assert!(state.inventory_loading);
+        let saved: Settings = settings::load_json(&dir.join(SETTINGS_FILE));
+        assert_eq!(saved.disabled_sources, vec!["msstore"]);
+
+        let _ = state.update(Message::SourceEnabledToggled("msstore".into(), true));
+        assert!(state.settings.disabled_sources.is_empty());
+        let _ = std::fs::remove_dir_all(dir);
     }
-}
 
+    #[test]
+    fn test_failed_settings_write_is_reported() {
+        // A file where the data directory should be makes every write fail.
+        let dir = std::env::temp_dir().join(format!("wau_unwritable_{}", std::process::id()));
+        std::fs::write(&dir, "").unwrap();
+        let mut state = AppState::with_data_dir(Some(dir.clone()));
+
+        let _ = state.update(Message::ThemeChanged(ThemeChoice::Light));
+        assert!(state.status_message.starts_with("Error: Failed to"));
+        let _ = std::fs::remove_file(dir);
+    }
+}
@@ -0,0 +1,32 @@
+//! Headless drift check: prints the drift report for a desired-state
+//! manifest and exits with 0 (no drift), 1 (drift) or 2 (error).
+//!
+//! ```text
+//! check-manifest desired.toml
+//! ```
+//!
+//! A console-subsystem binary of its own, so the report and the exit code
+//! reach the calling shell on Windows.
+
+use std::path::Path;
+use std::process::ExitCode;
+use win_apps_updater::manifest::{check_manifest, format_report};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [path] = args.as_slice() else {
+        eprintln!("Usage: check-manifest <manifest.toml>");
+        return ExitCode::from(2);
+    };
+
+    match check_manifest(Path::new(path)) {
+        Ok((report, _)) => {
+            print!("{}", format_report(&report));
+            ExitCode::from(u8::from(!report.is_empty()))
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::from(2)
+        }
+    }
+}
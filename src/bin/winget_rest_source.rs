@@ -0,0 +1,42 @@
+//! Debug server: serves a directory of package manifests as a winget REST
+//! source.
+//!
+//! ```text
+//! winget-rest-source <manifest-dir> [address]
+//! winget source add --name local --arg http://127.0.0.1:8765 --type Microsoft.Rest
+//! ```
+
+use std::path::Path;
+use std::process::ExitCode;
+use win_apps_updater::rest_source::{RestServer, RestSource};
+
+/// Address used when none is given.
+const DEFAULT_ADDR: &str = "127.0.0.1:8765";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (dir, addr) = match args.as_slice() {
+        [dir] => (dir, DEFAULT_ADDR),
+        [dir, addr] => (dir, addr.as_str()),
+        _ => {
+            eprintln!("Usage: winget-rest-source <manifest-dir> [address]");
+            return ExitCode::from(2);
+        }
+    };
+
+    let server = RestSource::load(Path::new(dir)).and_then(|source| {
+        let count = source.len();
+        RestServer::start(source, addr).map(|server| (server, count))
+    });
+    match server {
+        Ok((server, count)) => {
+            println!("Serving {count} packages from {dir} at {}", server.url());
+            server.wait();
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
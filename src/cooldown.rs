@@ -0,0 +1,175 @@
+use crate::i18n::Catalog;
+use crate::models::UpdatableApp;
+use serde::{Deserialize, Serialize};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// File name of the persisted first-seen history inside the data directory.
+pub const FIRST_SEEN_FILE: &str = "first_seen.json";
+
+/// Returns the current time as seconds since the Unix epoch.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Records when each `(ID, available version)` pair was first observed.
+///
+/// This drives the cooling-off period: a release only becomes selectable
+/// once it has been visible in `winget upgrade` for long enough.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FirstSeen {
+    /// Unix timestamps keyed by `"<id>@<version>"`.
+    entries: HashMap<String, u64>,
+}
+
+impl FirstSeen {
+    fn key(id: &str, version: &str) -> String {
+        format!("{id}@{version}")
+    }
+
+    /// Records every app in `apps` that has not been seen before. With
+    /// `prune`, also drops entries for releases that are no longer offered;
+    /// pass `false` when `apps` is a partial listing (e.g. some sources were
+    /// left out) so their packages keep their cooling-off clocks.
+    ///
+    /// Returns `true` if the history changed and should be persisted.
+    pub fn record(&mut self, apps: &[UpdatableApp], now: u64, prune: bool) -> bool {
+        let current: HashSet<String> = apps
+            .iter()
+            .map(|a| Self::key(&a.id, &a.available))
+            .collect();
+
+        let before = self.entries.len();
+        if prune {
+            self.entries.retain(|k, _| current.contains(k));
+        }
+        let mut changed = self.entries.len() != before;
+
+        for key in current {
+            if let Entry::Vacant(e) = self.entries.entry(key) {
+                e.insert(now);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Returns when the given release was first observed, if known.
+    pub fn first_seen(&self, id: &str, version: &str) -> Option<u64> {
+        self.entries.get(&Self::key(id, version)).copied()
+    }
+
+    /// Returns the Unix time at which the release leaves its cooling-off
+    /// period, or `None` if it is not held back.
+    pub fn held_until(&self, id: &str, version: &str, period_secs: u64, now: u64) -> Option<u64> {
+        if period_secs == 0 {
+            return None;
+        }
+        let until = self.first_seen(id, version).unwrap_or(now) + period_secs;
+        (until > now).then_some(until)
+    }
+}
+
+/// Formats the remaining cooling-off time as a short countdown (e.g. `3d 4h`).
+pub fn format_countdown(catalog: &Catalog, secs: u64) -> String {
+    let days = secs / 86_400;
+    let hours = (secs % 86_400) / 3_600;
+    let minutes = (secs % 3_600) / 60;
+    if days > 0 {
+        catalog.tr_args("countdown-days", &[("days", days.into()), ("hours", hours.into())])
+    } else if hours > 0 {
+        catalog.tr_args(
+            "countdown-hours",
+            &[("hours", hours.into()), ("minutes", minutes.into())],
+        )
+    } else {
+        catalog.tr_args("countdown-minutes", &[("minutes", minutes.max(1).into())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(id: &str, available: &str) -> UpdatableApp {
+        UpdatableApp::new(
+            id.into(),
+            id.into(),
+            "1.0".into(),
+            available.into(),
+            "winget".into(),
+        )
+    }
+
+    #[test]
+    fn test_record_new_release() {
+        let mut seen = FirstSeen::default();
+        assert!(seen.record(&[app("A.A", "2.0")], 100, true));
+        assert_eq!(seen.first_seen("A.A", "2.0"), Some(100));
+    }
+
+    #[test]
+    fn test_record_keeps_original_timestamp() {
+        let mut seen = FirstSeen::default();
+        seen.record(&[app("A.A", "2.0")], 100, true);
+        assert!(!seen.record(&[app("A.A", "2.0")], 500, true));
+        assert_eq!(seen.first_seen("A.A", "2.0"), Some(100));
+    }
+
+    #[test]
+    fn test_record_new_version_resets_clock() {
+        let mut seen = FirstSeen::default();
+        seen.record(&[app("A.A", "2.0")], 100, true);
+        seen.record(&[app("A.A", "3.0")], 500, true);
+        assert_eq!(seen.first_seen("A.A", "3.0"), Some(500));
+        assert_eq!(seen.first_seen("A.A", "2.0"), None);
+    }
+
+    #[test]
+    fn test_record_without_prune_keeps_unlisted_entries() {
+        let mut seen = FirstSeen::default();
+        seen.record(&[app("A.A", "2.0"), app("B.B", "1.1")], 100, true);
+        seen.record(&[app("A.A", "2.0")], 500, false);
+        assert_eq!(seen.first_seen("B.B", "1.1"), Some(100));
+    }
+
+    #[test]
+    fn test_held_until_disabled() {
+        let mut seen = FirstSeen::default();
+        seen.record(&[app("A.A", "2.0")], 100, true);
+        assert_eq!(seen.held_until("A.A", "2.0", 0, 100), None);
+    }
+
+    #[test]
+    fn test_held_until_within_period() {
+        let mut seen = FirstSeen::default();
+        seen.record(&[app("A.A", "2.0")], 100, true);
+        assert_eq!(seen.held_until("A.A", "2.0", 50, 120), Some(150));
+    }
+
+    #[test]
+    fn test_held_until_elapsed() {
+        let mut seen = FirstSeen::default();
+        seen.record(&[app("A.A", "2.0")], 100, true);
+        assert_eq!(seen.held_until("A.A", "2.0", 50, 150), None);
+    }
+
+    #[test]
+    fn test_held_until_unknown_release_starts_now() {
+        let seen = FirstSeen::default();
+        assert_eq!(seen.held_until("A.A", "2.0", 50, 100), Some(150));
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        let catalog = Catalog::default();
+        assert_eq!(format_countdown(&catalog, 3 * 86_400 + 4 * 3_600), "3d 4h");
+        assert_eq!(format_countdown(&catalog, 2 * 3_600 + 5 * 60), "2h 5m");
+        assert_eq!(format_countdown(&catalog, 10), "1m");
+        let catalog = Catalog::new(crate::i18n::Language::French);
+        assert_eq!(format_countdown(&catalog, 3 * 86_400 + 4 * 3_600), "3 j 4 h");
+    }
+}
@@ -0,0 +1,350 @@
+use crate::i18n::LocalizedError;
+use crate::models::AppItem;
+use crate::version::major;
+
+/// A single condition of a search query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Predicate {
+    /// Free text matched against name and ID (case-insensitive substring).
+    Text(String),
+    /// `name:<pattern>`
+    Name(String),
+    /// `id:<pattern>`
+    Id(String),
+    /// `source:<name>` (exact, case-insensitive)
+    Source(String),
+    /// `major:<bool>`: whether the update changes the major version.
+    Major(bool),
+    /// `selected:<bool>`
+    Selected(bool),
+}
+
+/// A predicate, optionally negated with a leading `-`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clause {
+    pub negated: bool,
+    pub predicate: Predicate,
+}
+
+/// A parsed search query: every clause must match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    pub clauses: Vec<Clause>,
+}
+
+impl Query {
+    /// Returns `true` if `item` satisfies every clause.
+    pub fn matches(&self, item: &AppItem) -> bool {
+        self.clauses
+            .iter()
+            .all(|c| c.predicate.matches(item) != c.negated)
+    }
+
+    /// Separates the free-text terms from the rest of the query, for fuzzy
+    /// matching. Negated text terms stay in the returned query.
+    pub fn split_text(self) -> (String, Query) {
+        let (text, clauses): (Vec<_>, Vec<_>) = self
+            .clauses
+            .into_iter()
+            .partition(|c| !c.negated && matches!(c.predicate, Predicate::Text(_)));
+        let text = text
+            .into_iter()
+            .filter_map(|c| match c.predicate {
+                Predicate::Text(t) => Some(t),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        (text, Query { clauses })
+    }
+}
+
+impl Predicate {
+    fn matches(&self, item: &AppItem) -> bool {
+        let app = &item.app;
+        match self {
+            Self::Text(text) => item.matches_search(text),
+            Self::Name(pattern) => pattern_matches(pattern, &app.name),
+            Self::Id(pattern) => pattern_matches(pattern, &app.id),
+            Self::Source(source) => app.source.eq_ignore_ascii_case(source),
+            Self::Major(expected) => {
+                let bump = matches!(
+                    (major(&app.version), major(&app.available)),
+                    (Some(from), Some(to)) if to > from
+                );
+                bump == *expected
+            }
+            Self::Selected(expected) => item.selected == *expected,
+        }
+    }
+}
+
+/// Parses a search query such as `source:winget -id:Microsoft.* "visual studio"`.
+///
+/// Supported syntax:
+/// - bare words and `"quoted phrases"` match name or ID
+/// - `name:`, `id:` take a substring, or a glob when it contains `*` or `?`
+/// - `source:` matches the source exactly
+/// - `major:` and `selected:` take `true`/`false` (or `yes`/`no`)
+/// - a leading `-` negates a term
+pub fn parse_query(input: &str) -> Result<Query, LocalizedError> {
+    let clauses = tokenize(input)?
+        .into_iter()
+        .map(parse_clause)
+        .collect::<Result<_, _>>()?;
+    Ok(Query { clauses })
+}
+
+/// A raw token: optional negation, optional `field:` prefix and value.
+struct Token {
+    negated: bool,
+    field: Option<String>,
+    value: String,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, LocalizedError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let negated = chars.next_if_eq(&'-').is_some();
+        let mut field = None;
+        let mut value = String::new();
+        let mut quoted = false;
+
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            chars.next();
+            match c {
+                '"' => {
+                    quoted = true;
+                    loop {
+                        match chars.next() {
+                            Some('"') => break,
+                            Some(c) => value.push(c),
+                            None => return Err(LocalizedError::new("query-unterminated-quote")),
+                        }
+                    }
+                }
+                ':' if field.is_none() && !quoted && !value.is_empty() => {
+                    field = Some(std::mem::take(&mut value).to_lowercase());
+                }
+                _ => value.push(c),
+            }
+        }
+
+        if value.is_empty() && !quoted {
+            match (&field, negated) {
+                (Some(f), _) => {
+                    return Err(LocalizedError::new("query-missing-value").arg("field", f))
+                }
+                (None, true) => return Err(LocalizedError::new("query-nothing-to-negate")),
+                (None, false) => continue,
+            }
+        }
+
+        tokens.push(Token {
+            negated,
+            field,
+            value,
+        });
+    }
+    Ok(tokens)
+}
+
+fn parse_clause(token: Token) -> Result<Clause, LocalizedError> {
+    let Token {
+        negated,
+        field,
+        value,
+    } = token;
+
+    let predicate = match field.as_deref() {
+        None => Predicate::Text(value),
+        Some("name") => Predicate::Name(value),
+        Some("id") => Predicate::Id(value),
+        Some("source") => Predicate::Source(value),
+        Some("major") => Predicate::Major(parse_bool("major", &value)?),
+        Some("selected") => Predicate::Selected(parse_bool("selected", &value)?),
+        Some(other) => return Err(LocalizedError::new("query-unknown-filter").arg("field", other)),
+    };
+    Ok(Clause { negated, predicate })
+}
+
+fn parse_bool(field: &str, value: &str) -> Result<bool, LocalizedError> {
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "y" | "1" => Ok(true),
+        "false" | "no" | "n" | "0" => Ok(false),
+        _ => Err(LocalizedError::new("query-expects-bool")
+            .arg("field", field)
+            .arg("value", value)),
+    }
+}
+
+/// Case-insensitive substring match, or anchored glob match when the
+/// pattern contains `*` or `?`.
+fn pattern_matches(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let text = text.to_lowercase();
+    if pattern.contains(['*', '?']) {
+        glob_matches(pattern.as_bytes(), text.as_bytes())
+    } else {
+        text.contains(&pattern)
+    }
+}
+
+/// Case-insensitive exact match of a package ID, or anchored glob match
+/// when the pattern contains `*` or `?`.
+pub(crate) fn id_pattern_matches(pattern: &str, id: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let id = id.to_lowercase();
+    if pattern.contains(['*', '?']) {
+        glob_matches(pattern.as_bytes(), id.as_bytes())
+    } else {
+        pattern == id
+    }
+}
+
+/// Matches `*` (any run) and `?` (any single byte) against `text`.
+fn glob_matches(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((sp, st)) => {
+                    p = sp + 1;
+                    t = st + 1;
+                    star = Some((sp, st + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::UpdatableApp;
+
+    fn item(name: &str, id: &str, version: &str, available: &str, source: &str) -> AppItem {
+        AppItem::from_app(UpdatableApp::new(
+            name.into(),
+            id.into(),
+            version.into(),
+            available.into(),
+            source.into(),
+        ))
+    }
+
+    fn vscode() -> AppItem {
+        item(
+            "Microsoft Visual Studio Code",
+            "Microsoft.VisualStudioCode",
+            "1.85.0",
+            "1.85.1",
+            "winget",
+        )
+    }
+
+    fn matches(query: &str, item: &AppItem) -> bool {
+        parse_query(query).unwrap().matches(item)
+    }
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        assert!(parse_query("").unwrap().clauses.is_empty());
+        assert!(matches("   ", &vscode()));
+    }
+
+    #[test]
+    fn test_free_text_and_phrases() {
+        assert!(matches("visual code", &vscode()));
+        assert!(matches("\"visual studio\"", &vscode()));
+        assert!(!matches("\"studio visual\"", &vscode()));
+    }
+
+    #[test]
+    fn test_field_filters() {
+        assert!(matches("source:winget", &vscode()));
+        assert!(!matches("source:msstore", &vscode()));
+        assert!(matches("id:Microsoft.*", &vscode()));
+        assert!(!matches("id:Google.*", &vscode()));
+        assert!(matches("name:\"studio code\"", &vscode()));
+    }
+
+    #[test]
+    fn test_negation() {
+        assert!(!matches("-id:Microsoft.*", &vscode()));
+        assert!(matches("-source:msstore", &vscode()));
+        assert!(matches("-\"google chrome\"", &vscode()));
+    }
+
+    #[test]
+    fn test_major_bump() {
+        let chrome = item("Chrome", "Google.Chrome", "119.0", "120.0", "winget");
+        assert!(matches("major:true", &chrome));
+        assert!(!matches("major:true", &vscode()));
+        assert!(matches("major:no", &vscode()));
+    }
+
+    #[test]
+    fn test_selected() {
+        let mut app = vscode();
+        assert!(matches("selected:no", &app));
+        app.selected = true;
+        assert!(matches("selected:yes", &app));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_query("\"unterminated").is_err());
+        assert!(parse_query("colour:red").is_err());
+        assert!(parse_query("major:maybe").is_err());
+        assert!(parse_query("id:").is_err());
+        assert!(parse_query("-").is_err());
+
+        let err = parse_query("major:maybe").unwrap_err();
+        assert_eq!(err.id, "query-expects-bool");
+        assert_eq!(
+            err.to_string(),
+            "'major:' expects true or false, got 'maybe'"
+        );
+    }
+
+    #[test]
+    fn test_split_text() {
+        let (text, rest) = parse_query("vs -beta code source:winget")
+            .unwrap()
+            .split_text();
+        assert_eq!(text, "vs code");
+        assert_eq!(rest.clauses.len(), 2);
+        assert!(rest.matches(&vscode()));
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches(b"a*c", b"abbbc"));
+        assert!(glob_matches(b"a?c", b"abc"));
+        assert!(glob_matches(b"*", b""));
+        assert!(!glob_matches(b"a*d", b"abc"));
+        assert!(glob_matches(b"*.code", b"microsoft.code"));
+    }
+}
@@ -0,0 +1,220 @@
+use crate::models::UpdatableApp;
+use std::cmp::Reverse;
+
+/// Points for every matched character.
+const SCORE_MATCH: i32 = 16;
+/// Bonus for a match at the start of a word (`"vsc"` → **V**isual **S**tudio **C**ode).
+const BONUS_BOUNDARY: i32 = 8;
+/// Bonus for a match directly following the previous one.
+const BONUS_CONSECUTIVE: i32 = 8;
+/// Penalty per skipped character between two matches.
+const PENALTY_GAP: i32 = 1;
+
+/// The best fuzzy match of a pattern against an app's name or ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher is better.
+    pub score: i32,
+    /// Matched character positions in the name (empty if the ID matched better).
+    pub name: Vec<usize>,
+    /// Matched character positions in the ID (empty if the name matched better).
+    pub id: Vec<usize>,
+}
+
+/// Matches `pattern` against an app's name and ID, keeping whichever scores
+/// higher (the name wins ties). Returns `None` if neither contains the
+/// pattern as a subsequence.
+pub fn match_app(pattern: &str, app: &UpdatableApp) -> Option<FuzzyMatch> {
+    let name = fuzzy_match(pattern, &app.name);
+    let id = fuzzy_match(pattern, &app.id);
+    match (name, id) {
+        (Some((n, _)), Some((i, positions))) if i > n => Some(FuzzyMatch {
+            score: i,
+            name: Vec::new(),
+            id: positions,
+        }),
+        (Some((score, positions)), _) => Some(FuzzyMatch {
+            score,
+            name: positions,
+            id: Vec::new(),
+        }),
+        (None, Some((score, positions))) => Some(FuzzyMatch {
+            score,
+            name: Vec::new(),
+            id: positions,
+        }),
+        (None, None) => None,
+    }
+}
+
+/// Scores `text` against `pattern` as a case-insensitive subsequence,
+/// ignoring whitespace in the pattern.
+///
+/// Returns the score and the matched character positions of the best
+/// alignment, preferring matches at word starts and runs of consecutive
+/// characters. An empty pattern matches everything with a score of zero.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(fold)
+        .collect();
+    if pattern.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    // Cheap rejection before allocating the score matrix.
+    let mut remaining = pattern.iter().peekable();
+    for c in text.chars() {
+        remaining.next_if(|&&p| p == fold(c));
+    }
+    if remaining.peek().is_some() {
+        return None;
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let (m, n) = (pattern.len(), chars.len());
+    let folded: Vec<char> = chars.iter().copied().map(fold).collect();
+
+    // scores[i * n + j]: best score with pattern[i] matched at text[j]
+    // (`NONE` if impossible); back[i * n + j]: where pattern[i - 1] was
+    // matched in that alignment.
+    const NONE: i32 = i32::MIN;
+    let mut scores = vec![NONE; m * n];
+    let mut back = vec![0usize; m * n];
+
+    for i in 0..m {
+        // Best `score + PENALTY_GAP * k` over positions k < j - 1 of the
+        // previous row, so the gap penalty can be applied in O(1).
+        let mut best_gapped: Option<(i32, usize)> = None;
+        for j in 0..n {
+            if i > 0 && j >= 2 {
+                let s = scores[(i - 1) * n + j - 2];
+                if s != NONE {
+                    let v = s + PENALTY_GAP * (j - 2) as i32;
+                    if best_gapped.is_none_or(|(b, _)| v > b) {
+                        best_gapped = Some((v, j - 2));
+                    }
+                }
+            }
+            if folded[j] != pattern[i] {
+                continue;
+            }
+
+            let base = SCORE_MATCH + boundary_bonus(&chars, j);
+            let cell = if i == 0 {
+                Some((base, 0))
+            } else {
+                let consecutive = j
+                    .checked_sub(1)
+                    .map(|k| (scores[(i - 1) * n + k], k))
+                    .filter(|&(s, _)| s != NONE)
+                    .map(|(s, k)| (s + base + BONUS_CONSECUTIVE, k));
+                let gapped = best_gapped.map(|(b, k)| (b - PENALTY_GAP * (j as i32 - 1) + base, k));
+                match (consecutive, gapped) {
+                    (Some(c), Some(g)) => Some(if c.0 >= g.0 { c } else { g }),
+                    (c, g) => c.or(g),
+                }
+            };
+            if let Some((score, prev)) = cell {
+                scores[i * n + j] = score;
+                back[i * n + j] = prev;
+            }
+        }
+    }
+
+    let last = &scores[(m - 1) * n..];
+    let (mut j, score) = (0..n)
+        .filter(|&j| last[j] != NONE)
+        .map(|j| (j, last[j]))
+        .max_by_key(|&(j, s)| (s, Reverse(j)))?;
+    let mut positions = vec![0; m];
+    for i in (0..m).rev() {
+        positions[i] = j;
+        j = back[i * n + j];
+    }
+    Some((score, positions))
+}
+
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Bonus for matching at `chars[j]`: start of text, after a separator, or at
+/// a camel-case hump.
+fn boundary_bonus(chars: &[char], j: usize) -> i32 {
+    let Some(&prev) = j.checked_sub(1).and_then(|k| chars.get(k)) else {
+        return BONUS_BOUNDARY;
+    };
+    let cur = chars[j];
+    if !prev.is_alphanumeric() || (prev.is_lowercase() && cur.is_uppercase()) {
+        BONUS_BOUNDARY
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(name: &str, id: &str) -> UpdatableApp {
+        UpdatableApp::new(
+            name.into(),
+            id.into(),
+            "1.0".into(),
+            "2.0".into(),
+            "winget".into(),
+        )
+    }
+
+    #[test]
+    fn test_subsequence_required() {
+        assert!(fuzzy_match("vsc", "Microsoft Visual Studio Code").is_some());
+        assert!(fuzzy_match("vsx", "Microsoft Visual Studio Code").is_none());
+        assert!(fuzzy_match("longer than text", "short").is_none());
+    }
+
+    #[test]
+    fn test_prefers_word_starts() {
+        let (_, positions) = fuzzy_match("vsc", "Microsoft Visual Studio Code").unwrap();
+        assert_eq!(positions, vec![10, 17, 24]);
+    }
+
+    #[test]
+    fn test_case_and_whitespace_insensitive() {
+        assert!(fuzzy_match("VS code", "visualstudiocode").is_some());
+    }
+
+    #[test]
+    fn test_empty_pattern_matches() {
+        assert_eq!(fuzzy_match("", "anything"), Some((0, Vec::new())));
+        assert_eq!(fuzzy_match("  ", ""), Some((0, Vec::new())));
+    }
+
+    #[test]
+    fn test_consecutive_beats_scattered() {
+        let (tight, _) = fuzzy_match("fire", "Firefox").unwrap();
+        let (loose, _) = fuzzy_match("fire", "Fast Image Resizer Easy").unwrap();
+        assert!(tight > loose);
+    }
+
+    #[test]
+    fn test_camel_case_boundary() {
+        let (_, positions) = fuzzy_match("vsc", "Microsoft.VisualStudioCode").unwrap();
+        assert_eq!(positions, vec![10, 16, 22]);
+    }
+
+    #[test]
+    fn test_match_app_picks_better_field() {
+        let m = match_app("vsc", &app("Code", "Microsoft.VisualStudioCode")).unwrap();
+        assert!(m.name.is_empty());
+        assert_eq!(m.id.len(), 3);
+
+        let m = match_app("code", &app("Code", "Microsoft.VisualStudioCode")).unwrap();
+        assert_eq!(m.name, vec![0, 1, 2, 3]);
+        assert!(m.id.is_empty());
+
+        assert!(match_app("xyz", &app("Code", "Microsoft.VSCode")).is_none());
+    }
+}
@@ -0,0 +1,314 @@
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use unic_langid::LanguageIdentifier;
+
+#[cfg(windows)]
+use std::os::windows::process::CommandExt;
+
+pub use fluent_bundle::FluentValue;
+
+/// A language the user interface is translated into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Language {
+    /// The reference catalog; other languages fall back to it per message.
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "de")]
+    German,
+    #[serde(rename = "fr")]
+    French,
+}
+
+impl Language {
+    /// Every language, in the order shown in the settings dialog.
+    pub const ALL: [Language; 3] = [Self::English, Self::German, Self::French];
+
+    /// Returns the ISO 639-1 code, which is also the catalog file name.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::German => "de",
+            Self::French => "fr",
+        }
+    }
+
+    /// Returns the language's name in that language.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::German => "Deutsch",
+            Self::French => "Français",
+        }
+    }
+
+    /// Picks the language of a locale such as `de_DE.UTF-8` or `fr-CA`.
+    pub fn from_locale(locale: &str) -> Option<Language> {
+        let primary = locale.split(['-', '_', '.', '@']).next()?.to_ascii_lowercase();
+        Self::ALL.into_iter().find(|lang| lang.code() == primary)
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Self::English => include_str!("../locales/en.ftl"),
+            Self::German => include_str!("../locales/de.ftl"),
+            Self::French => include_str!("../locales/fr.ftl"),
+        }
+    }
+}
+
+/// Translated UI strings for one language.
+pub struct Catalog {
+    language: Language,
+    bundle: FluentBundle<FluentResource>,
+    /// English catalog for messages the language lacks (`None` for English).
+    reference: Option<FluentBundle<FluentResource>>,
+}
+
+impl Catalog {
+    /// Loads the catalog for `language`.
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            bundle: bundle(language),
+            reference: (language != Language::English).then(|| bundle(Language::English)),
+        }
+    }
+
+    /// Returns the catalog's language.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Returns the message `id`, or `id` itself if no catalog has it.
+    pub fn tr(&self, id: &str) -> String {
+        self.tr_args(id, &[])
+    }
+
+    /// Returns the message `id` with its `{ $name }` placeables filled in from
+    /// `args`. Numbers select plural variants using the language's rules.
+    pub fn tr_args(&self, id: &str, args: &[(&str, FluentValue<'_>)]) -> String {
+        let args: FluentArgs = args.iter().map(|(k, v)| (*k, v.clone())).collect();
+        format(&self.bundle, id, &args)
+            .or_else(|| self.reference.as_ref().and_then(|b| format(b, id, &args)))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    /// Returns the text of a [`LocalizedError`] in the catalog's language.
+    pub fn tr_error(&self, error: &LocalizedError) -> String {
+        let args: Vec<(&str, FluentValue)> = error
+            .args
+            .iter()
+            .map(|(name, value)| (*name, FluentValue::from(value.as_str())))
+            .collect();
+        self.tr_args(error.id, &args)
+    }
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Self::new(Language::default())
+    }
+}
+
+/// An error in user input, kept as a message ID and its arguments so the UI
+/// can show it in the current language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalizedError {
+    pub id: &'static str,
+    pub args: Vec<(&'static str, String)>,
+}
+
+impl LocalizedError {
+    /// Creates an error showing the message `id`.
+    pub fn new(id: &'static str) -> Self {
+        Self {
+            id,
+            args: Vec::new(),
+        }
+    }
+
+    /// Adds the `{ $name }` argument of the message.
+    pub fn arg(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.args.push((name, value.into()));
+        self
+    }
+}
+
+/// Formats the error in English, for logs and serde.
+impl fmt::Display for LocalizedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&Catalog::new(Language::English).tr_error(self))
+    }
+}
+
+fn bundle(language: Language) -> FluentBundle<FluentResource> {
+    let resource =
+        FluentResource::try_new(language.source().to_string()).unwrap_or_else(|(res, _)| res);
+    let locale: LanguageIdentifier = language.code().parse().unwrap_or_default();
+    let mut bundle = FluentBundle::new_concurrent(vec![locale]);
+    // Iced draws the Unicode isolation marks Fluent wraps arguments in.
+    bundle.set_use_isolating(false);
+    // Every message ID is unique within a file, so this cannot fail.
+    let _ = bundle.add_resource(resource);
+    bundle
+}
+
+fn format(bundle: &FluentBundle<FluentResource>, id: &str, args: &FluentArgs) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut errors = Vec::new();
+    Some(bundle.format_pattern(pattern, Some(args), &mut errors).into_owned())
+}
+
+/// Returns the language of the desktop session, falling back to English.
+///
+/// Reads `LocaleName` from the registry on Windows and the `LC_ALL`,
+/// `LC_MESSAGES` and `LANG` environment variables elsewhere.
+pub fn system_language() -> Language {
+    #[cfg(windows)]
+    let locale = {
+        let mut cmd = std::process::Command::new("reg");
+        cmd.args(["query", r"HKCU\Control Panel\International", "/v", "LocaleName"]);
+        cmd.creation_flags(crate::winget::CREATE_NO_WINDOW);
+        cmd.output()
+            .ok()
+            .and_then(|o| parse_locale_name(&String::from_utf8_lossy(&o.stdout)))
+    };
+    #[cfg(not(windows))]
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty());
+    locale
+        .as_deref()
+        .and_then(Language::from_locale)
+        .unwrap_or_default()
+}
+
+/// Parses `reg query ... /v LocaleName` output (`REG_SZ    de-DE`).
+#[cfg_attr(not(windows), allow(dead_code))]
+fn parse_locale_name(output: &str) -> Option<String> {
+    let line = output.lines().find(|l| l.contains("LocaleName"))?;
+    line.split_whitespace().last().map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses a catalog and returns its message IDs.
+    fn ids(language: Language) -> Vec<String> {
+        if let Err((_, errors)) = FluentResource::try_new(language.source().to_string()) {
+            panic!("{}.ftl: {errors:?}", language.code());
+        }
+        language
+            .source()
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+            .filter_map(|line| line.split_once(" =").map(|(id, _)| id.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_catalogs_parse_and_match_reference() {
+        let reference = ids(Language::English);
+        for lang in [Language::German, Language::French] {
+            let translated = ids(lang);
+            let missing: Vec<_> = reference.iter().filter(|id| !translated.contains(id)).collect();
+            let extra: Vec<_> = translated.iter().filter(|id| !reference.contains(id)).collect();
+            assert!(missing.is_empty(), "{} lacks {missing:?}", lang.code());
+            assert!(extra.is_empty(), "{} has unknown {extra:?}", lang.code());
+        }
+    }
+
+    #[test]
+    fn test_plural_forms() {
+        let en = Catalog::new(Language::English);
+        assert_eq!(
+            en.tr_args("status-available", &[("count", 1.into())]),
+            "1 app available for update"
+        );
+        assert_eq!(
+            en.tr_args("status-available", &[("count", 3.into())]),
+            "3 apps available for update"
+        );
+        assert_eq!(
+            en.tr_args("status-available", &[("count", 0.into())]),
+            "0 apps available for update"
+        );
+
+        // French treats zero as singular.
+        let fr = Catalog::new(Language::French);
+        assert_eq!(
+            fr.tr_args("status-available", &[("count", 0.into())]),
+            "0 application à mettre à jour"
+        );
+
+        let de = Catalog::new(Language::German);
+        assert_eq!(
+            de.tr_args("status-available", &[("count", 2.into())]),
+            "2 Apps können aktualisiert werden"
+        );
+    }
+
+    #[test]
+    fn test_arguments_are_not_isolated() {
+        let en = Catalog::new(Language::English);
+        assert_eq!(en.tr_args("status-updated-app", &[("name", "Git".into())]), "Updated Git");
+    }
+
+    #[test]
+    fn test_localized_errors() {
+        let err = LocalizedError::new("schedule-unknown-day").arg("day", "funday");
+        assert_eq!(err.to_string(), "Unknown day 'funday'");
+        let de = Catalog::new(Language::German);
+        assert_eq!(de.tr_error(&err), "Unbekannter Tag „funday“");
+        let fr = Catalog::new(Language::French);
+        assert_eq!(fr.tr_error(&err), "Jour inconnu « funday »");
+    }
+
+    #[test]
+    fn test_weekday_names() {
+        use crate::scheduler::weekday_label_id;
+        use chrono::Weekday;
+
+        let de = Catalog::new(Language::German);
+        let day = de.tr(weekday_label_id(Weekday::Tue));
+        assert_eq!(
+            de.tr_args("schedule-at", &[("day", day.into()), ("time", "03:00".into())]),
+            "Di 03:00"
+        );
+    }
+
+    #[test]
+    fn test_unknown_message_falls_back_to_id() {
+        let de = Catalog::new(Language::German);
+        assert_eq!(de.tr("no-such-message"), "no-such-message");
+        assert_eq!(de.tr("toolbar-refresh"), "Aktualisieren");
+    }
+
+    #[test]
+    fn test_language_from_locale() {
+        assert_eq!(Language::from_locale("de_DE.UTF-8"), Some(Language::German));
+        assert_eq!(Language::from_locale("fr-CA"), Some(Language::French));
+        assert_eq!(Language::from_locale("EN"), Some(Language::English));
+        assert_eq!(Language::from_locale("C"), None);
+        assert_eq!(Language::from_locale("ja_JP"), None);
+    }
+
+    #[test]
+    fn test_parse_locale_name() {
+        let output = "\r\nHKEY_CURRENT_USER\\Control Panel\\International\r\n    LocaleName    REG_SZ    de-DE\r\n";
+        assert_eq!(parse_locale_name(output).as_deref(), Some("de-DE"));
+        assert_eq!(parse_locale_name("ERROR"), None);
+    }
+
+    #[test]
+    fn test_language_serde() {
+        assert_eq!(serde_json::to_string(&Language::German).unwrap(), "\"de\"");
+        let parsed: Language = serde_json::from_str("\"fr\"").unwrap();
+        assert_eq!(parsed, Language::French);
+    }
+}
@@ -1,7 +1,19 @@
 // Library exports for testing and potential reuse
 
 pub mod app;
+pub mod cooldown;
+pub mod filter;
+pub mod fuzzy;
+pub mod i18n;
+pub mod manifest;
 pub mod models;
+pub mod notify;
+pub mod packages;
+pub mod rest_source;
+pub mod scheduler;
+pub mod settings;
+pub mod shortcuts;
 pub mod ui;
+pub mod version;
 pub mod winget;
 
@@ -0,0 +1,399 @@
+use crate::models::{InstalledApp, UpdatableApp};
+use crate::version::compare_versions;
+use crate::winget::{get_installed_apps, get_updatable_apps};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Version requirement for one package of a desired-state manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Constraint {
+    /// Installed, and updated whenever a newer release is available.
+    Latest,
+    /// Installed at this version or newer (`>=120`).
+    AtLeast(String),
+    /// Installed at exactly this version (`=1.2.3` or a bare `1.2.3`).
+    Exact(String),
+    /// Not installed at all.
+    Absent,
+}
+
+impl FromStr for Constraint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let version = |v: &str| {
+            let v = v.trim();
+            if v.is_empty() || v.contains(char::is_whitespace) {
+                Err(format!("Invalid version constraint '{s}'"))
+            } else {
+                Ok(v.to_string())
+            }
+        };
+        match s.to_lowercase().as_str() {
+            "latest" => Ok(Self::Latest),
+            "absent" => Ok(Self::Absent),
+            _ => {
+                if let Some(v) = s.strip_prefix(">=") {
+                    version(v).map(Self::AtLeast)
+                } else if let Some(v) = s.strip_prefix('=') {
+                    version(v).map(Self::Exact)
+                } else if s.starts_with(|c: char| c.is_ascii_digit()) {
+                    version(s).map(Self::Exact)
+                } else {
+                    Err(format!(
+                        "Invalid version constraint '{s}': expected latest, absent, >=<version> or =<version>"
+                    ))
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Latest => f.write_str("latest"),
+            Self::AtLeast(v) => write!(f, ">={v}"),
+            Self::Exact(v) => write!(f, "={v}"),
+            Self::Absent => f.write_str("absent"),
+        }
+    }
+}
+
+impl TryFrom<String> for Constraint {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Constraint> for String {
+    fn from(constraint: Constraint) -> Self {
+        constraint.to_string()
+    }
+}
+
+/// A desired-state manifest: the packages a machine should (or must not)
+/// have, written as TOML.
+///
+/// ```toml
+/// [packages]
+/// "Git.Git" = ">=2.44"
+/// "Mozilla.Firefox" = "latest"
+/// "7zip.7zip" = "=23.01"
+/// "Contoso.Toolbar" = "absent"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Constraints keyed by package ID.
+    pub packages: BTreeMap<String, Constraint>,
+}
+
+impl Manifest {
+    /// Parses a TOML manifest.
+    pub fn from_toml(input: &str) -> Result<Self, String> {
+        toml::from_str(input).map_err(|e| format!("Invalid manifest: {e}"))
+    }
+
+    /// Reads a TOML manifest file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let input = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+        Self::from_toml(&input)
+    }
+}
+
+/// How an installed package deviates from its manifest constraint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Drift {
+    /// Required but not installed.
+    Missing,
+    /// Installed, but a reachable version satisfies the constraint better.
+    Outdated { installed: String, target: String },
+    /// Marked `absent` but installed.
+    Forbidden { installed: String },
+    /// No known version satisfies the constraint (e.g. it needs a release
+    /// that doesn't exist yet, or a downgrade).
+    OverConstrained {
+        installed: String,
+        available: Option<String>,
+    },
+}
+
+/// One package that doesn't match the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriftItem {
+    pub id: String,
+    pub constraint: Constraint,
+    pub drift: Drift,
+}
+
+impl DriftItem {
+    /// Returns the exact version to install or upgrade to, or `None` for the
+    /// latest one.
+    pub fn pinned_version(&self) -> Option<String> {
+        match &self.constraint {
+            Constraint::Exact(v) => Some(v.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Compares the manifest with the installed packages and the pending
+/// updates, returning only the packages that drift.
+///
+/// The newest reachable version of a package comes from `updatable`, falling
+/// back to the `Available` column of the inventory.
+pub fn reconcile(
+    manifest: &Manifest,
+    inventory: &[InstalledApp],
+    updatable: &[UpdatableApp],
+) -> Vec<DriftItem> {
+    let mut report = Vec::new();
+    for (id, constraint) in &manifest.packages {
+        let Some(app) = inventory.iter().find(|app| app.id.eq_ignore_ascii_case(id)) else {
+            if *constraint != Constraint::Absent {
+                report.push(DriftItem {
+                    id: id.clone(),
+                    constraint: constraint.clone(),
+                    drift: Drift::Missing,
+                });
+            }
+            continue;
+        };
+
+        let installed = app.version.clone();
+        let available = updatable
+            .iter()
+            .find(|u| u.id.eq_ignore_ascii_case(id))
+            .map(|u| u.available.clone())
+            .or_else(|| Some(app.available.clone()).filter(|a| !a.is_empty()));
+        let at_least = |min: &str, v: &str| compare_versions(v, min) != Ordering::Less;
+
+        let drift = match constraint {
+            Constraint::Absent => Some(Drift::Forbidden { installed }),
+            Constraint::Latest => available.map(|target| Drift::Outdated { installed, target }),
+            Constraint::AtLeast(min) if at_least(min, &installed) => None,
+            Constraint::AtLeast(min) => match available {
+                Some(target) if at_least(min, &target) => {
+                    Some(Drift::Outdated { installed, target })
+                }
+                available => Some(Drift::OverConstrained {
+                    installed,
+                    available,
+                }),
+            },
+            Constraint::Exact(wanted) => match compare_versions(&installed, wanted) {
+                Ordering::Equal => None,
+                Ordering::Less => Some(Drift::Outdated {
+                    installed,
+                    target: wanted.clone(),
+                }),
+                Ordering::Greater => Some(Drift::OverConstrained {
+                    installed,
+                    available,
+                }),
+            },
+        };
+
+        if let Some(drift) = drift {
+            report.push(DriftItem {
+                id: id.clone(),
+                constraint: constraint.clone(),
+                drift,
+            });
+        }
+    }
+    report
+}
+
+/// Loads the manifest and reconciles it against a fresh `winget list` and
+/// `winget upgrade`.
+pub fn check_manifest(path: &Path) -> Result<(Vec<DriftItem>, Vec<InstalledApp>), String> {
+    let manifest = Manifest::load(path)?;
+    let inventory = get_installed_apps()?;
+    let updatable = get_updatable_apps()?;
+    Ok((reconcile(&manifest, &inventory, &updatable), inventory))
+}
+
+/// Formats a drift report as plain text, one package per line.
+pub fn format_report(report: &[DriftItem]) -> String {
+    if report.is_empty() {
+        return String::from("No drift: every package matches the manifest.\n");
+    }
+    let width = report.iter().map(|item| item.id.len()).max().unwrap_or(0);
+    let mut out = String::new();
+    for item in report {
+        let detail = match &item.drift {
+            Drift::Missing => String::from("missing"),
+            Drift::Outdated { installed, target } => format!("outdated: {installed} -> {target}"),
+            Drift::Forbidden { installed } => format!("forbidden: {installed} is installed"),
+            Drift::OverConstrained {
+                installed,
+                available,
+            } => format!(
+                "over-constrained: {installed} installed, {} available",
+                available.as_deref().unwrap_or("nothing newer")
+            ),
+        };
+        out.push_str(&format!("{:<width$}  {:<12}  {detail}\n", item.id, item.constraint.to_string()));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn installed(id: &str, version: &str) -> InstalledApp {
+        InstalledApp::new(
+            id.into(),
+            id.into(),
+            version.into(),
+            String::new(),
+            "winget".into(),
+        )
+    }
+
+    fn updatable(id: &str, version: &str, available: &str) -> UpdatableApp {
+        UpdatableApp::new(
+            id.into(),
+            id.into(),
+            version.into(),
+            available.into(),
+            "winget".into(),
+        )
+    }
+
+    #[test]
+    fn test_parse_constraints() {
+        assert_eq!("latest".parse(), Ok(Constraint::Latest));
+        assert_eq!(" Absent ".parse(), Ok(Constraint::Absent));
+        assert_eq!(">=120".parse(), Ok(Constraint::AtLeast("120".into())));
+        assert_eq!("=1.2.3".parse(), Ok(Constraint::Exact("1.2.3".into())));
+        assert_eq!("1.2.3".parse(), Ok(Constraint::Exact("1.2.3".into())));
+        assert!("~1.2".parse::<Constraint>().is_err());
+        assert!(">=".parse::<Constraint>().is_err());
+        assert!("= 1 2".parse::<Constraint>().is_err());
+    }
+
+    #[test]
+    fn test_constraint_display_round_trips() {
+        for s in ["latest", "absent", ">=120", "=1.2.3"] {
+            assert_eq!(s.parse::<Constraint>().unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_manifest_from_toml() {
+        let manifest = Manifest::from_toml(
+            r#"
+            [packages]
+            "Git.Git" = ">=2.44"
+            "Mozilla.Firefox" = "latest"
+            "Contoso.Toolbar" = "absent"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(manifest.packages.len(), 3);
+        assert_eq!(manifest.packages["Contoso.Toolbar"], Constraint::Absent);
+
+        let err = Manifest::from_toml("[packages]\n\"Git.Git\" = \"newest\"\n").unwrap_err();
+        assert!(err.contains("newest"), "{err}");
+    }
+
+    #[test]
+    fn test_reconcile_reports_every_kind_of_drift() {
+        let manifest = Manifest::from_toml(
+            r#"
+            [packages]
+            "Git.Git" = ">=2.44"
+            "Mozilla.Firefox" = "latest"
+            "Contoso.Toolbar" = "absent"
+            "Google.Chrome" = ">=200"
+            "7zip.7zip" = "=23.01"
+            "Microsoft.PowerToys" = "=0.79.0"
+            "Notepad++.Notepad++" = "latest"
+            "JetBrains.Toolbox" = "absent"
+            "#,
+        )
+        .unwrap();
+        let inventory = [
+            installed("Git.Git", "2.43.0"),
+            installed("Mozilla.Firefox", "122.0"),
+            installed("Contoso.Toolbar", "1.0"),
+            installed("Google.Chrome", "120.0"),
+            installed("Microsoft.PowerToys", "0.80.1"),
+            installed("Notepad++.Notepad++", "8.6"),
+        ];
+        let updates = [
+            updatable("Git.Git", "2.43.0", "2.45.0"),
+            updatable("Mozilla.Firefox", "122.0", "123.0"),
+            updatable("Google.Chrome", "120.0", "121.0"),
+        ];
+
+        let report = reconcile(&manifest, &inventory, &updates);
+        let drift = |id: &str| report.iter().find(|item| item.id == id).map(|item| &item.drift);
+
+        assert_eq!(report.len(), 6);
+        assert_eq!(
+            drift("Git.Git"),
+            Some(&Drift::Outdated {
+                installed: "2.43.0".into(),
+                target: "2.45.0".into()
+            })
+        );
+        assert!(matches!(drift("Mozilla.Firefox"), Some(Drift::Outdated { .. })));
+        assert!(matches!(drift("Contoso.Toolbar"), Some(Drift::Forbidden { .. })));
+        assert_eq!(
+            drift("Google.Chrome"),
+            Some(&Drift::OverConstrained {
+                installed: "120.0".into(),
+                available: Some("121.0".into())
+            })
+        );
+        assert_eq!(drift("7zip.7zip"), Some(&Drift::Missing));
+        assert!(matches!(drift("Microsoft.PowerToys"), Some(Drift::OverConstrained { .. })));
+        // Satisfied packages don't appear.
+        assert_eq!(drift("Notepad++.Notepad++"), None);
+        assert_eq!(drift("JetBrains.Toolbox"), None);
+    }
+
+    #[test]
+    fn test_exact_pin_upgrades_to_the_pinned_version() {
+        let manifest = Manifest::from_toml("[packages]\n\"7zip.7zip\" = \"=23.01\"\n").unwrap();
+        let report = reconcile(&manifest, &[installed("7zip.7zip", "22.01")], &[]);
+        assert_eq!(
+            report[0].drift,
+            Drift::Outdated {
+                installed: "22.01".into(),
+                target: "23.01".into()
+            }
+        );
+        assert_eq!(report[0].pinned_version().as_deref(), Some("23.01"));
+    }
+
+    #[test]
+    fn test_format_report() {
+        assert!(format_report(&[]).starts_with("No drift"));
+        let report = [DriftItem {
+            id: "Git.Git".into(),
+            constraint: Constraint::AtLeast("2.44".into()),
+            drift: Drift::Outdated {
+                installed: "2.43.0".into(),
+                target: "2.45.0".into(),
+            },
+        }];
+        assert_eq!(
+            format_report(&report),
+            "Git.Git  >=2.44        outdated: 2.43.0 -> 2.45.0\n"
+        );
+    }
+}
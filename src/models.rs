@@ -1,4 +1,10 @@
+use crate::i18n::{Language, LocalizedError};
+use crate::manifest::DriftItem;
+use crate::packages::PackageSet;
+use crate::settings::ThemeChoice;
+use crate::version::compare_versions;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
 
 /// Represents an application that has an available update.
@@ -40,17 +46,454 @@ impl fmt::Display for UpdatableApp {
     }
 }
 
+/// A package on the machine, as reported by `winget list`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InstalledApp {
+    pub name: String,
+    pub id: String,
+    pub version: String,
+    /// Newer version offered by the source (empty if none or unknown).
+    pub available: String,
+    /// Source the package was matched to (empty for ARP/MSIX entries).
+    pub source: String,
+}
+
+impl InstalledApp {
+    /// Creates a new `InstalledApp` instance.
+    pub fn new(
+        name: String,
+        id: String,
+        version: String,
+        available: String,
+        source: String,
+    ) -> Self {
+        Self {
+            name,
+            id,
+            version,
+            available,
+            source,
+        }
+    }
+
+    /// Returns `true` if winget matched the package to a source and can
+    /// therefore update it.
+    pub fn is_managed(&self) -> bool {
+        !self.source.is_empty()
+    }
+
+    /// Returns `true` if this package matches the given search query (case-insensitive).
+    pub fn matches_search(&self, query: &str) -> bool {
+        if query.is_empty() {
+            return true;
+        }
+        let q = query.to_lowercase();
+        self.name.to_lowercase().contains(&q) || self.id.to_lowercase().contains(&q)
+    }
+}
+
+/// A package offered by a source, as reported by `winget search`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    pub name: String,
+    pub id: String,
+    /// Latest version offered by the source.
+    pub version: String,
+    /// Why the package matched when not by name or ID (e.g. `Tag: browser`).
+    pub matched: String,
+    pub source: String,
+}
+
+impl SearchResult {
+    /// Creates a new `SearchResult` instance.
+    pub fn new(
+        name: String,
+        id: String,
+        version: String,
+        matched: String,
+        source: String,
+    ) -> Self {
+        Self {
+            name,
+            id,
+            version,
+            matched,
+            source,
+        }
+    }
+}
+
+/// Installation scope passed to `winget install --scope`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InstallScope {
+    /// Let the installer decide.
+    #[default]
+    Any,
+    User,
+    Machine,
+}
+
+impl InstallScope {
+    pub const ALL: [InstallScope; 3] = [Self::Any, Self::User, Self::Machine];
+
+    /// Returns the `--scope` value, or `None` to leave it out.
+    pub fn arg(self) -> Option<&'static str> {
+        match self {
+            Self::Any => None,
+            Self::User => Some("user"),
+            Self::Machine => Some("machine"),
+        }
+    }
+
+    /// Returns the message ID of the scope's label.
+    pub fn label_id(self) -> &'static str {
+        match self {
+            Self::Any => "scope-any",
+            Self::User => "scope-user",
+            Self::Machine => "scope-machine",
+        }
+    }
+}
+
+/// Installer architecture passed to `winget upgrade --architecture`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Architecture {
+    /// Let winget pick the best match for the machine.
+    #[default]
+    Any,
+    X64,
+    Arm64,
+    X86,
+}
+
+impl Architecture {
+    pub const ALL: [Architecture; 4] = [Self::Any, Self::X64, Self::Arm64, Self::X86];
+
+    /// Returns the `--architecture` value, or `None` to leave it out.
+    pub fn arg(self) -> Option<&'static str> {
+        match self {
+            Self::Any => None,
+            Self::X64 => Some("x64"),
+            Self::Arm64 => Some("arm64"),
+            Self::X86 => Some("x86"),
+        }
+    }
+}
+
+/// Installer types accepted by `winget upgrade --installer-type`.
+pub const INSTALLER_TYPES: [&str; 10] = [
+    "msix", "msi", "appx", "exe", "zip", "inno", "nullsoft", "wix", "burn", "portable",
+];
+
+/// Per-package upgrade options, remembered by package ID or ID pattern.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PackageOptions {
+    pub scope: InstallScope,
+    pub architecture: Architecture,
+    /// Installer type (empty lets winget pick).
+    pub installer_type: String,
+    /// Installer locale such as `en-US` (empty lets winget pick).
+    pub locale: String,
+}
+
+impl PackageOptions {
+    /// Returns `true` when no option differs from winget's defaults.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the winget arguments for the chosen options.
+    pub fn args(&self) -> Vec<&str> {
+        let mut args = Vec::new();
+        if let Some(scope) = self.scope.arg() {
+            args.extend(["--scope", scope]);
+        }
+        if let Some(architecture) = self.architecture.arg() {
+            args.extend(["--architecture", architecture]);
+        }
+        if !self.installer_type.is_empty() {
+            args.extend(["--installer-type", self.installer_type.as_str()]);
+        }
+        if !self.locale.is_empty() {
+            args.extend(["--locale", self.locale.as_str()]);
+        }
+        args
+    }
+}
+
+/// Formats the non-default options as `scope=machine arch=x64 type=msi
+/// locale=en-US`.
+impl fmt::Display for PackageOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(scope) = self.scope.arg() {
+            parts.push(format!("scope={scope}"));
+        }
+        if let Some(architecture) = self.architecture.arg() {
+            parts.push(format!("arch={architecture}"));
+        }
+        if !self.installer_type.is_empty() {
+            parts.push(format!("type={}", self.installer_type));
+        }
+        if !self.locale.is_empty() {
+            parts.push(format!("locale={}", self.locale));
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+impl std::str::FromStr for PackageOptions {
+    type Err = LocalizedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut options = Self::default();
+        for part in s.split_whitespace() {
+            let (key, raw) = part
+                .split_once('=')
+                .ok_or_else(|| LocalizedError::new("options-expected-pair").arg("part", part))?;
+            let value = raw.to_ascii_lowercase();
+            match key.to_ascii_lowercase().as_str() {
+                "scope" => {
+                    options.scope = InstallScope::ALL
+                        .into_iter()
+                        .find(|scope| scope.arg() == Some(value.as_str()))
+                        .ok_or_else(|| {
+                            LocalizedError::new("options-unknown-scope").arg("value", &value)
+                        })?;
+                }
+                "arch" | "architecture" => {
+                    options.architecture = Architecture::ALL
+                        .into_iter()
+                        .find(|arch| arch.arg() == Some(value.as_str()))
+                        .ok_or_else(|| {
+                            LocalizedError::new("options-unknown-architecture").arg("value", &value)
+                        })?;
+                }
+                "type" | "installer-type" => {
+                    if !INSTALLER_TYPES.contains(&value.as_str()) {
+                        return Err(LocalizedError::new("options-unknown-installer-type")
+                            .arg("value", value));
+                    }
+                    options.installer_type = value;
+                }
+                "locale" => {
+                    if raw.is_empty() || !raw.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                        return Err(LocalizedError::new("options-invalid-locale").arg("value", raw));
+                    }
+                    options.locale = raw.to_string();
+                }
+                other => {
+                    return Err(LocalizedError::new("options-unknown-option").arg("option", other))
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// A package queued for installation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstallRequest {
+    pub name: String,
+    pub id: String,
+    /// Source to install from (empty lets winget pick).
+    pub source: String,
+    /// Exact version to install (`None` for the latest).
+    pub version: Option<String>,
+    pub scope: InstallScope,
+    /// Upgrades an installed package instead of installing a new one.
+    pub upgrade: bool,
+    /// Passes `--accept-source-agreements`.
+    pub accept_source_agreements: bool,
+    /// Options remembered for the package, applied to upgrades; an explicit
+    /// `scope` takes precedence over the remembered one.
+    pub options: PackageOptions,
+    /// Installer switches from the package's arguments profile, applied to
+    /// upgrades.
+    pub extra_args: Vec<String>,
+}
+
+impl InstallRequest {
+    /// Creates a request for the latest version with the default scope.
+    pub fn new(name: String, id: String, source: String) -> Self {
+        Self {
+            name,
+            id,
+            source,
+            version: None,
+            scope: InstallScope::default(),
+            upgrade: false,
+            accept_source_agreements: true,
+            options: PackageOptions::default(),
+            extra_args: Vec::new(),
+        }
+    }
+}
+
+/// Characters a shell or the installer command line could interpret, which
+/// are never allowed in an arguments profile.
+pub const SHELL_METACHARACTERS: &[char] = &[
+    '&', '|', ';', '<', '>', '^', '`', '$', '%', '!', '(', ')', '"', '\'', '\n', '\r',
+];
+
+/// Placeholders expanded in an arguments profile.
+pub const ARG_PLACEHOLDERS: [&str; 2] = ["{id}", "{version}"];
+
+/// Extra installer switches merged into `winget upgrade` for one package.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArgsProfile {
+    /// Replaces the installer's own arguments (`--override`).
+    pub override_args: String,
+    /// Appended to the installer's own arguments (`--custom`).
+    pub custom_args: String,
+}
+
+impl ArgsProfile {
+    /// Returns `true` when neither switch is set.
+    pub fn is_empty(&self) -> bool {
+        self.override_args.trim().is_empty() && self.custom_args.trim().is_empty()
+    }
+
+    /// Returns `true` if either switch uses the `{version}` placeholder.
+    pub fn needs_version(&self) -> bool {
+        self.override_args.contains("{version}") || self.custom_args.contains("{version}")
+    }
+
+    /// Rejects shell metacharacters and unknown `{placeholders}`.
+    pub fn validate(&self) -> Result<(), String> {
+        for (flag, value) in [("--override", &self.override_args), ("--custom", &self.custom_args)] {
+            if let Some(c) = value.chars().find(|c| SHELL_METACHARACTERS.contains(c)) {
+                return Err(format!("{flag} must not contain '{}'", c.escape_default()));
+            }
+            let mut rest = value.as_str();
+            while let Some(start) = rest.find('{') {
+                rest = &rest[start..];
+                let placeholder = ARG_PLACEHOLDERS
+                    .into_iter()
+                    .find(|p| rest.starts_with(p))
+                    .ok_or_else(|| {
+                        format!("{flag} has an unknown placeholder; use {}", ARG_PLACEHOLDERS.join(" or "))
+                    })?;
+                rest = &rest[placeholder.len()..];
+            }
+            if rest.contains('}') {
+                return Err(format!("{flag} has an unmatched '}}'"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the winget arguments with `{id}` and `{version}` expanded.
+    pub fn args(&self, id: &str, version: &str) -> Vec<String> {
+        let expand = |value: &str| value.trim().replace("{id}", id).replace("{version}", version);
+        let mut args = Vec::new();
+        for (flag, value) in [("--override", &self.override_args), ("--custom", &self.custom_args)] {
+            if !value.trim().is_empty() {
+                args.extend([flag.to_string(), expand(value)]);
+            }
+        }
+        args
+    }
+}
+
+/// A package queued for `winget upgrade`, with everything that shapes its
+/// command line.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UpgradeRequest {
+    pub id: String,
+    /// Source to upgrade from (empty lets winget pick).
+    pub source: String,
+    /// Passes `--accept-source-agreements`.
+    pub accept_source_agreements: bool,
+    pub options: PackageOptions,
+    /// Expanded `--override` / `--custom` arguments from the package's profile.
+    pub extra_args: Vec<String>,
+}
+
+/// A configured winget source, as listed by `winget source list`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceInfo {
+    pub name: String,
+    /// URL or path the source reads from.
+    pub argument: String,
+    /// Explicit sources are only used when named with `--source`.
+    pub explicit: bool,
+}
+
+/// A `winget source` subcommand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceAction {
+    /// Adds a source; `kind` is its type (e.g. `Microsoft.Rest`), empty for
+    /// winget's default.
+    Add {
+        name: String,
+        argument: String,
+        kind: String,
+    },
+    Remove(String),
+    /// Refreshes one source, or all of them.
+    Update(Option<String>),
+    /// Resets one source, or all of them back to winget's defaults.
+    Reset(Option<String>),
+}
+
+impl SourceAction {
+    /// Returns the source the action applies to, if it names one.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Add { name, .. } | Self::Remove(name) => Some(name),
+            Self::Update(name) | Self::Reset(name) => name.as_deref(),
+        }
+    }
+}
+
+/// The top-level views of the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tab {
+    /// Apps with pending updates.
+    #[default]
+    Updates,
+    /// Everything `winget list` reports.
+    Installed,
+    /// Packages found with `winget search`, ready to install.
+    Discover,
+    /// The configured winget sources.
+    Sources,
+}
+
+/// What the running batch does to each queued package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchAction {
+    #[default]
+    Update,
+    Uninstall,
+    Install,
+}
+
 /// Wraps an `UpdatableApp` with UI selection state.
 #[derive(Debug, Clone)]
 pub struct AppItem {
     pub app: UpdatableApp,
     pub selected: bool,
+    /// Unix time until which the release is held back by the cooling-off period.
+    pub held_until: Option<u64>,
+    /// Whether the release appeared since the previous refresh.
+    pub is_new: bool,
 }
 
 impl AppItem {
     /// Creates a new `AppItem` with the given selection state.
     pub fn new(app: UpdatableApp, selected: bool) -> Self {
-        Self { app, selected }
+        Self {
+            app,
+            selected,
+            held_until: None,
+            is_new: false,
+        }
     }
 
     /// Creates a new unselected `AppItem`.
@@ -66,6 +509,11 @@ impl AppItem {
         let q = query.to_lowercase();
         self.app.name.to_lowercase().contains(&q) || self.app.id.to_lowercase().contains(&q)
     }
+
+    /// Returns `true` if the release is still in its cooling-off period at `now`.
+    pub fn is_held(&self, now: u64) -> bool {
+        self.held_until.is_some_and(|until| until > now)
+    }
 }
 
 impl From<UpdatableApp> for AppItem {
@@ -74,18 +522,54 @@ impl From<UpdatableApp> for AppItem {
     }
 }
 
+/// Columns the app list can be sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortColumn {
+    Name,
+    Id,
+    Installed,
+    Available,
+    Source,
+}
+
+impl SortColumn {
+    /// Compares two apps by this column, using version ordering for the
+    /// version columns and case-insensitive ordering for text.
+    pub fn compare(self, a: &UpdatableApp, b: &UpdatableApp) -> Ordering {
+        match self {
+            Self::Name => cmp_text(&a.name, &b.name),
+            Self::Id => cmp_text(&a.id, &b.id),
+            Self::Installed => compare_versions(&a.version, &b.version),
+            Self::Available => compare_versions(&a.available, &b.available),
+            Self::Source => cmp_text(&a.source, &b.source),
+        }
+    }
+}
+
+fn cmp_text(a: &str, b: &str) -> Ordering {
+    a.to_lowercase().cmp(&b.to_lowercase())
+}
+
+/// Active sort column and direction of the app list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortOrder {
+    pub column: SortColumn,
+    pub ascending: bool,
+}
+
 /// All application messages / events.
 #[derive(Debug, Clone)]
 pub enum Message {
     /// Trigger loading the list of updatable apps.
     LoadApps,
-    /// Apps have been loaded (success or error).
-    AppsLoaded(Result<Vec<UpdatableApp>, String>),
+    /// Apps have been loaded (success or error), with the errors of sources
+    /// that could not be queried.
+    AppsLoaded(Result<(Vec<UpdatableApp>, Vec<String>), String>),
     /// Toggle selection of an app at the given index.
     ToggleApp(usize),
     /// Initiate updating selected apps (shows confirmation).
     UpdateSelected,
-    /// A single app update completed with its result string.
+    /// A single package of the running batch finished with its result string.
     UpdateSingleComplete(String),
     /// Select all visible apps.
     SelectAll,
@@ -99,12 +583,212 @@ pub enum Message {
     CloseResultsDialog,
     /// Search / filter text changed.
     SearchChanged(String),
+    /// Open the settings dialog.
+    OpenSettings,
+    /// Close the settings dialog.
+    CloseSettings,
+    /// Cooling-off period input changed (days, as typed).
+    CooldownDaysChanged(String),
+    /// Schedule input changed (`;`-separated schedules, as typed).
+    SchedulesChanged(String),
+    /// Ignore-list input changed (comma-separated IDs, as typed).
+    IgnoredIdsChanged(String),
+    /// Periodic scheduler check.
+    SchedulerTick,
+    /// Global notification mute toggled.
+    MuteNotificationsToggled(bool),
+    /// Muted-packages input changed (comma-separated IDs, as typed).
+    MutedIdsChanged(String),
+    /// Periodic background refresh while idle.
+    BackgroundRefresh,
+    /// Background refresh interval input changed (minutes, as typed).
+    RefreshIntervalChanged(String),
+    /// The app list was scrolled.
+    ListScrolled(iced::widget::scrollable::Viewport),
+    /// The main window was opened or resized (new height in logical pixels).
+    WindowResized(f32),
+    /// Sort by a column (toggles direction if it is already active).
+    SortBy(SortColumn),
+    /// Fuzzy search mode toggled.
+    FuzzySearchToggled(bool),
+    /// Grouping of the list by source toggled.
+    GroupBySourceToggled(bool),
+    /// Collapse or expand the section of a source.
+    ToggleSourceCollapsed(String),
+    /// Select (`true`) or deselect (`false`) every visible app of a source.
+    SelectSource(String, bool),
+    /// A key press not handled by any widget.
+    KeyPressed(iced::keyboard::Key, iced::keyboard::Modifiers),
+    /// Move keyboard focus to the search bar.
+    FocusSearch,
+    /// Move the row cursor by the given number of rows.
+    MoveCursor(i32),
+    /// Toggle selection of the app at the row cursor.
+    ToggleCursor,
+    /// Show details of the app at the row cursor.
+    OpenDetails,
+    /// Close the details dialog.
+    CloseDetails,
+    /// Show or hide the keyboard shortcut help.
+    ToggleHelp,
+    /// Close the topmost dialog.
+    CloseOverlay,
+    /// Keyboard modifier state changed.
+    ModifiersChanged(iced::keyboard::Modifiers),
+    /// Shift-click: set every visible row between the anchor and this row
+    /// to the clicked row's new state.
+    SelectRange(usize),
+    /// Invert the selection of all visible apps.
+    InvertSelection,
+    /// Update a single app right away, leaving the selection untouched.
+    UpdateNow(usize),
+    /// Per-row update confirmation setting toggled.
+    SkipRowUpdateConfirmationToggled(bool),
+    /// Theme picked in the settings dialog.
+    ThemeChanged(ThemeChoice),
+    /// Timer tick to re-read the desktop colour scheme.
+    CheckSystemTheme,
+    /// Desktop colour scheme read (`true` if dark).
+    SystemThemeDetected(bool),
+    /// UI language picked in the settings dialog (`None` follows the system).
+    LanguageChanged(Option<Language>),
+    /// Switch between the updates list and the inventory.
+    TabSelected(Tab),
+    /// Trigger (re)loading of the installed-package inventory.
+    LoadInventory,
+    /// Installed packages loaded (or error).
+    InventoryLoaded(Result<Vec<InstalledApp>, String>),
+    /// Inventory filter "only packages winget can't manage" toggled.
+    UnmanagedOnlyToggled(bool),
+    /// The inventory list was scrolled.
+    InventoryScrolled(iced::widget::scrollable::Viewport),
+    /// Toggle selection of the inventory package at the given index.
+    ToggleInventoryApp(usize),
+    /// Initiate uninstalling the selected packages (shows confirmation).
+    UninstallSelected,
+    /// Confirm the uninstall after showing the confirmation dialog.
+    ConfirmUninstall,
+    /// Cancel the pending uninstall.
+    CancelUninstall,
+    /// Discover search input changed.
+    DiscoverQueryChanged(String),
+    /// Run `winget search` for the discover query.
+    SearchPackages,
+    /// Search results loaded (or error).
+    SearchResultsLoaded(Result<Vec<SearchResult>, String>),
+    /// Toggle selection of the search result at the given index.
+    ToggleSearchResult(usize),
+    /// Version to install for a package ID changed (empty for the latest).
+    InstallVersionChanged(String, String),
+    /// Installation scope picked in the discover toolbar.
+    InstallScopeChanged(InstallScope),
+    /// Initiate installing the selected search results (shows confirmation).
+    InstallSelected,
+    /// Confirm the install after showing the confirmation dialog.
+    ConfirmInstall,
+    /// Cancel the pending install.
+    CancelInstall,
+    /// The search results list was scrolled.
+    DiscoverScrolled(iced::widget::scrollable::Viewport),
+    /// Package set file path input changed.
+    PackageFileChanged(String),
+    /// Write the selected inventory packages to the package set file.
+    ExportPackages,
+    /// Read the package set file and preview what importing it would do.
+    ImportPackages,
+    /// Package set and fresh inventory loaded for an import (or error).
+    ImportLoaded(Result<(PackageSet, Vec<InstalledApp>), String>),
+    /// Queue the previewed installs and upgrades.
+    ConfirmImport,
+    /// Close the import preview without changing anything.
+    CancelImport,
+    /// Manifest path input in the settings dialog changed.
+    ManifestPathChanged(String),
+    /// Reconcile the manifest against the installed packages.
+    CheckDrift,
+    /// Drift report and fresh inventory computed (or error).
+    DriftChecked(Result<(Vec<DriftItem>, Vec<InstalledApp>), String>),
+    /// Queue the installs and upgrades that bring the machine in line with the manifest.
+    ApplyDrift,
+    /// Close the drift report without changing anything.
+    CloseDrift,
+    /// Reload the configured sources.
+    LoadSources,
+    /// Sources listed by `winget source list` (or error).
+    SourcesLoaded(Result<Vec<SourceInfo>, String>),
+    /// New source name input changed.
+    SourceNameChanged(String),
+    /// New source URL input changed.
+    SourceArgumentChanged(String),
+    /// New source type input changed.
+    SourceTypeChanged(String),
+    /// Whether `winget source add` may accept the source's agreements.
+    AcceptSourceAgreementsToggled(bool),
+    /// Add the source described by the inputs.
+    AddSource,
+    /// Run a `winget source` subcommand.
+    RunSourceAction(SourceAction),
+    /// A source action finished with its result string.
+    SourceActionComplete(String),
+    /// Include (`true`) or exclude (`false`) a source when listing and upgrading.
+    SourceEnabledToggled(String, bool),
+    /// Whether updates and installs of store apps accept the Microsoft Store's agreements.
+    AcceptStoreAgreementsToggled(bool),
+    /// Upgrade options edited for a package in the details dialog.
+    PackageOptionsChanged(String, PackageOptions),
+    /// Upgrade option rules input edited in the settings dialog.
+    OptionRulesChanged(String),
+    /// `--override` arguments edited in the details dialog.
+    OverrideArgsChanged(String),
+    /// `--custom` arguments edited in the details dialog.
+    CustomArgsChanged(String),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_args_profile_validation() {
+        let profile = |override_args: &str| ArgsProfile {
+            override_args: override_args.into(),
+            custom_args: String::new(),
+        };
+        assert!(profile("/S /D={id}-{version}").validate().is_ok());
+        assert!(profile("/S && del *").validate().is_err());
+        assert!(profile("/S > out.txt").validate().is_err());
+        assert!(profile("/DIR=\"C:\\Apps\"").validate().is_err());
+        assert!(profile("/S {name}").validate().is_err());
+        assert!(profile("/S version}").validate().is_err());
+    }
+
+    #[test]
+    fn test_args_profile_expands_placeholders() {
+        let profile = ArgsProfile {
+            override_args: " /S /LOG={id}-{version}.log ".into(),
+            custom_args: "/NoShortcut".into(),
+        };
+        assert_eq!(
+            profile.args("Git.Git", "2.45.0"),
+            ["--override", "/S /LOG=Git.Git-2.45.0.log", "--custom", "/NoShortcut"]
+        );
+        assert!(ArgsProfile::default().args("Git.Git", "1").is_empty());
+        assert!(ArgsProfile::default().is_empty());
+    }
+
+    #[test]
+    fn test_package_options_parse_and_display() {
+        let options: PackageOptions = "locale=en-US type=MSI arch=x64 scope=user".parse().unwrap();
+        assert_eq!(options.scope, InstallScope::User);
+        assert_eq!(options.architecture, Architecture::X64);
+        assert_eq!(options.installer_type, "msi");
+        assert_eq!(options.to_string(), "scope=user arch=x64 type=msi locale=en-US");
+        assert!("".parse::<PackageOptions>().unwrap().is_default());
+        assert!("scope".parse::<PackageOptions>().is_err());
+        assert!("color=red".parse::<PackageOptions>().is_err());
+        assert!("locale=en&US".parse::<PackageOptions>().is_err());
+    }
+
     #[test]
     fn test_updatable_app_creation() {
         let app = UpdatableApp::new(
@@ -208,5 +892,53 @@ mod tests {
         ));
         assert!(item.matches_search(""));
     }
-}
 
+    #[test]
+    fn test_sort_column_compare_versions() {
+        let a = UpdatableApp::new("a".into(), "A".into(), "1.9".into(), "2.0".into(), "msstore".into());
+        let b = UpdatableApp::new("B".into(), "b".into(), "1.10".into(), "10.0".into(), "winget".into());
+        assert_eq!(SortColumn::Installed.compare(&a, &b), Ordering::Less);
+        assert_eq!(SortColumn::Available.compare(&a, &b), Ordering::Less);
+        assert_eq!(SortColumn::Name.compare(&a, &b), Ordering::Less);
+        assert_eq!(SortColumn::Source.compare(&a, &b), Ordering::Less);
+    }
+
+    #[test]
+    fn test_is_held() {
+        let mut item = AppItem::from_app(UpdatableApp::new(
+            "Test".into(),
+            "Test.App".into(),
+            "1.0".into(),
+            "2.0".into(),
+            "winget".into(),
+        ));
+        assert!(!item.is_held(100));
+
+        item.held_until = Some(200);
+        assert!(item.is_held(100));
+        assert!(!item.is_held(200));
+    }
+
+    #[test]
+    fn test_installed_app_managed_and_search() {
+        let managed = InstalledApp::new(
+            "Git".into(),
+            "Git.Git".into(),
+            "2.44.0".into(),
+            "2.45.0".into(),
+            "winget".into(),
+        );
+        let unmanaged = InstalledApp::new(
+            "Contoso Tool".into(),
+            "ARP\\Machine\\X64\\Contoso".into(),
+            "1.0".into(),
+            String::new(),
+            String::new(),
+        );
+        assert!(managed.is_managed());
+        assert!(!unmanaged.is_managed());
+        assert!(managed.matches_search("git.g"));
+        assert!(unmanaged.matches_search("CONTOSO"));
+        assert!(!unmanaged.matches_search("git"));
+    }
+}
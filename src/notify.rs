@@ -0,0 +1,288 @@
+use crate::i18n::Catalog;
+use std::sync::{Arc, Mutex};
+
+#[cfg(any(windows, all(unix, not(target_os = "macos"))))]
+use std::process::{Command, Stdio};
+
+#[cfg(windows)]
+use std::os::windows::process::CommandExt;
+
+/// Application name shown as the notification sender.
+const APP_NAME: &str = "Windows Apps Updater";
+
+/// A desktop notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    pub title: String,
+    pub body: String,
+}
+
+impl Notification {
+    /// Creates a new notification.
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+        }
+    }
+}
+
+/// Delivers desktop notifications.
+///
+/// Implementations must not block the UI thread for long; the platform
+/// notifiers hand the notification to a helper process and return.
+pub trait Notifier: Send + Sync {
+    /// Shows a notification.
+    fn notify(&self, notification: &Notification) -> Result<(), String>;
+}
+
+/// Records notifications in memory instead of showing them.
+///
+/// Used by tests and as a no-op fallback on platforms without a notifier.
+#[derive(Debug, Default)]
+pub struct MemoryNotifier {
+    sent: Mutex<Vec<Notification>>,
+}
+
+impl MemoryNotifier {
+    /// Returns every notification delivered so far.
+    pub fn sent(&self) -> Vec<Notification> {
+        self.sent.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+}
+
+impl Notifier for MemoryNotifier {
+    fn notify(&self, notification: &Notification) -> Result<(), String> {
+        self.sent
+            .lock()
+            .map_err(|e| e.to_string())?
+            .push(notification.clone());
+        Ok(())
+    }
+}
+
+/// Sends notifications over the freedesktop D-Bus notification interface
+/// (`org.freedesktop.Notifications.Notify`) using `gdbus`.
+#[cfg(all(unix, not(target_os = "macos")))]
+#[derive(Debug, Default)]
+pub struct DbusNotifier;
+
+#[cfg(all(unix, not(target_os = "macos")))]
+impl Notifier for DbusNotifier {
+    fn notify(&self, notification: &Notification) -> Result<(), String> {
+        Command::new("gdbus")
+            .args([
+                "call",
+                "--session",
+                "--dest",
+                "org.freedesktop.Notifications",
+                "--object-path",
+                "/org/freedesktop/Notifications",
+                "--method",
+                "org.freedesktop.Notifications.Notify",
+                &gvariant_str(APP_NAME),
+                "0",
+                "''",
+                &gvariant_str(&notification.title),
+                &gvariant_str(&notification.body),
+                "[]",
+                "{}",
+                "-1",
+            ])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map(reap)
+            .map_err(|e| format!("Failed to execute gdbus: {e}"))
+    }
+}
+
+/// Waits for a helper process in the background so it does not linger as a zombie.
+#[cfg(any(windows, all(unix, not(target_os = "macos"))))]
+fn reap(mut child: std::process::Child) {
+    std::thread::spawn(move || child.wait());
+}
+
+/// Quotes a string as a GVariant text-format string literal.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn gvariant_str(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// Shows Windows toast notifications through the WinRT
+/// `ToastNotificationManager`, driven by a hidden PowerShell process.
+#[cfg(windows)]
+#[derive(Debug, Default)]
+pub struct ToastNotifier;
+
+/// Environment variable the toast XML is handed to PowerShell in.
+#[cfg(any(windows, test))]
+const TOAST_XML_VAR: &str = "WAU_TOAST_XML";
+
+/// PowerShell script that shows the toast in `$env:WAU_TOAST_XML`.
+///
+/// The XML holds package names, so it never becomes part of the script
+/// text, where PowerShell's many quote characters could end the string.
+/// Windows drops toasts from AppUserModelIDs that no Start-menu shortcut
+/// registers, so this borrows PowerShell's, which every installation has,
+/// and the XML names the app in the attribution line.
+#[cfg(any(windows, test))]
+const TOAST_SCRIPT: &str = concat!(
+    "[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] > $null; ",
+    "[Windows.Data.Xml.Dom.XmlDocument, Windows.Data.Xml.Dom.XmlDocument, ContentType = WindowsRuntime] > $null; ",
+    "$xml = New-Object Windows.Data.Xml.Dom.XmlDocument; ",
+    "$xml.LoadXml($env:WAU_TOAST_XML); ",
+    "$toast = [Windows.UI.Notifications.ToastNotification]::new($xml); ",
+    "[Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier(",
+    r"'{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\WindowsPowerShell\v1.0\powershell.exe'",
+    ").Show($toast)"
+);
+
+#[cfg(windows)]
+impl Notifier for ToastNotifier {
+    fn notify(&self, notification: &Notification) -> Result<(), String> {
+        let mut cmd = Command::new("powershell");
+        cmd.args(["-NoProfile", "-NonInteractive", "-Command", TOAST_SCRIPT])
+            .env(TOAST_XML_VAR, toast_xml(notification))
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        cmd.creation_flags(crate::winget::CREATE_NO_WINDOW);
+        cmd.spawn()
+            .map(reap)
+            .map_err(|e| format!("Failed to execute powershell: {e}"))
+    }
+}
+
+/// Builds the toast XML for a notification.
+#[cfg(any(windows, test))]
+fn toast_xml(notification: &Notification) -> String {
+    format!(
+        "<toast><visual><binding template=\"ToastGeneric\"><text>{}</text><text>{}</text><text placement=\"attribution\">{}</text></binding></visual></toast>",
+        xml_escape(&notification.title),
+        xml_escape(&notification.body),
+        xml_escape(APP_NAME)
+    )
+}
+
+#[cfg(any(windows, test))]
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Returns the notifier for the current platform.
+pub fn system_notifier() -> Arc<dyn Notifier> {
+    #[cfg(windows)]
+    let notifier: Arc<dyn Notifier> = Arc::new(ToastNotifier);
+    #[cfg(all(unix, not(target_os = "macos")))]
+    let notifier: Arc<dyn Notifier> = Arc::new(DbusNotifier);
+    #[cfg(not(any(windows, all(unix, not(target_os = "macos")))))]
+    let notifier: Arc<dyn Notifier> = Arc::new(MemoryNotifier::default());
+    notifier
+}
+
+/// Builds the notification for newly discovered updates, or `None` if there
+/// are none.
+pub fn updates_available(catalog: &Catalog, names: &[String]) -> Option<Notification> {
+    if names.is_empty() {
+        return None;
+    }
+    Some(Notification::new(
+        catalog.tr_args("notify-updates-title", &[("count", names.len().into())]),
+        summarize_names(catalog, names, 3),
+    ))
+}
+
+/// Joins up to `limit` names, summarising the rest as "and N more".
+pub fn summarize_names(catalog: &Catalog, names: &[String], limit: usize) -> String {
+    let summary = names
+        .iter()
+        .take(limit)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+    if names.len() > limit {
+        catalog.tr_args(
+            "notify-more-names",
+            &[("names", summary.into()), ("count", (names.len() - limit).into())],
+        )
+    } else {
+        summary
+    }
+}
+
+/// Builds the notification summarising a finished update batch.
+pub fn batch_finished(catalog: &Catalog, succeeded: usize, failed: usize, other: usize) -> Notification {
+    let body = [
+        ("results-succeeded", succeeded),
+        ("results-failed", failed),
+        ("results-other", other),
+    ]
+    .map(|(id, count)| catalog.tr_args(id, &[("count", count.into())]))
+    .join(", ");
+    Notification::new(catalog.tr("notify-batch-title"), body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Language;
+
+    #[test]
+    fn test_memory_notifier_records() {
+        let notifier = MemoryNotifier::default();
+        notifier.notify(&Notification::new("Title", "Body")).unwrap();
+        assert_eq!(notifier.sent(), vec![Notification::new("Title", "Body")]);
+    }
+
+    #[test]
+    fn test_updates_available_none() {
+        assert!(updates_available(&Catalog::default(), &[]).is_none());
+    }
+
+    #[test]
+    fn test_updates_available_lists_first_names() {
+        let names: Vec<String> = ["A", "B", "C", "D", "E"].iter().map(|s| s.to_string()).collect();
+        let n = updates_available(&Catalog::default(), &names).unwrap();
+        assert_eq!(n.title, "5 new updates available");
+        assert_eq!(n.body, "A, B, C and 2 more");
+
+        let n = updates_available(&Catalog::new(Language::German), &names[..1]).unwrap();
+        assert_eq!(n.title, "1 neues Update verfügbar");
+        assert_eq!(n.body, "A");
+    }
+
+    #[test]
+    fn test_summarize_names_within_limit() {
+        let names = vec!["A".to_string(), "B".to_string()];
+        assert_eq!(summarize_names(&Catalog::default(), &names, 3), "A, B");
+    }
+
+    #[test]
+    fn test_batch_finished() {
+        let n = batch_finished(&Catalog::default(), 3, 1, 0);
+        assert_eq!(n.title, "Update complete");
+        assert_eq!(n.body, "3 succeeded, 1 failed, 0 other");
+
+        let n = batch_finished(&Catalog::new(Language::French), 1, 2, 0);
+        assert_eq!(n.body, "1 réussie, 2 échouées, 0 autre");
+    }
+
+    #[test]
+    fn test_toast_keeps_names_out_of_the_script() {
+        let name = "Bob’s Tool’); Remove-Item C:\\ #";
+        let xml = toast_xml(&Notification::new("1 update", format!("{name} & <more>")));
+        assert!(xml.contains("<text>Bob’s Tool’); Remove-Item C:\\ # &amp; &lt;more&gt;</text>"));
+        assert!(TOAST_SCRIPT.contains(&format!("$env:{TOAST_XML_VAR}")));
+        assert!(!TOAST_SCRIPT.contains('’'));
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn test_gvariant_str_escapes_quotes() {
+        assert_eq!(gvariant_str("it's"), "'it\\'s'");
+        assert_eq!(gvariant_str("a\\b"), "'a\\\\b'");
+    }
+}
@@ -0,0 +1,337 @@
+use crate::models::InstalledApp;
+use crate::version::compare_versions;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+
+/// Schema URL written by `winget export`.
+pub const PACKAGES_SCHEMA: &str = "https://aka.ms/winget-packages.schema.2.0.json";
+
+/// A package set in winget's `packages.json` export format, as read and
+/// written by `winget export` / `winget import`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PackageSet {
+    #[serde(rename = "$schema", default)]
+    pub schema: String,
+    #[serde(default)]
+    pub creation_date: String,
+    pub sources: Vec<PackageSource>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub win_get_version: String,
+}
+
+/// The packages exported from one source.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PackageSource {
+    pub packages: Vec<PackageEntry>,
+    pub source_details: SourceDetails,
+}
+
+/// One exported package; the version is only present for pinned exports.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PackageEntry {
+    pub package_identifier: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// Identifies the source a group of packages comes from.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SourceDetails {
+    #[serde(default)]
+    pub argument: String,
+    #[serde(default)]
+    pub identifier: String,
+    pub name: String,
+    #[serde(default, rename = "Type")]
+    pub kind: String,
+}
+
+impl SourceDetails {
+    /// Returns the details winget writes for a source, filling in the
+    /// well-known ones so `winget import` recognises them.
+    pub fn for_name(name: &str) -> Self {
+        let (argument, identifier, kind) = match name {
+            "winget" => (
+                "https://cdn.winget.microsoft.com/cache",
+                "Microsoft.Winget.Source_8wekyb3d8bbwe",
+                "Microsoft.PreIndexed.Package",
+            ),
+            "msstore" => (
+                "https://storeedgefd.dsx.mp.microsoft.com/v9.0",
+                "StoreEdgeFD",
+                "Microsoft.Rest",
+            ),
+            _ => ("", name, ""),
+        };
+        Self {
+            argument: argument.into(),
+            identifier: identifier.into(),
+            name: name.into(),
+            kind: kind.into(),
+        }
+    }
+}
+
+impl PackageSet {
+    /// Snapshots `apps` with their installed versions, grouped by source in
+    /// first-seen order. Packages winget can't manage are left out.
+    pub fn from_installed<'a>(
+        apps: impl IntoIterator<Item = &'a InstalledApp>,
+        creation_date: String,
+    ) -> Self {
+        let mut sources: Vec<PackageSource> = Vec::new();
+        for app in apps.into_iter().filter(|app| app.is_managed()) {
+            let index = match sources
+                .iter()
+                .position(|s| s.source_details.name == app.source)
+            {
+                Some(index) => index,
+                None => {
+                    sources.push(PackageSource {
+                        packages: Vec::new(),
+                        source_details: SourceDetails::for_name(&app.source),
+                    });
+                    sources.len() - 1
+                }
+            };
+            sources[index].packages.push(PackageEntry {
+                package_identifier: app.id.clone(),
+                version: Some(app.version.clone()).filter(|v| !v.is_empty()),
+            });
+        }
+
+        Self {
+            schema: PACKAGES_SCHEMA.into(),
+            creation_date,
+            sources,
+            win_get_version: String::new(),
+        }
+    }
+
+    /// Parses a `packages.json` document.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid package set: {e}"))
+    }
+
+    /// Reads a `packages.json` file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+        Self::from_json(&json)
+    }
+
+    /// Returns the number of packages across all sources.
+    pub fn len(&self) -> usize {
+        self.sources.iter().map(|s| s.packages.len()).sum()
+    }
+
+    /// Returns `true` if the set lists no packages.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// What importing a package set does to one package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportAction {
+    /// Not installed yet.
+    Install,
+    /// Installed, but older than the pinned version (or an update is available).
+    Upgrade { installed: String },
+    /// Already installed at the pinned version or newer.
+    Skip { installed: String },
+}
+
+/// One package of an import plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportItem {
+    pub id: String,
+    pub source: String,
+    /// Version pinned by the package set (`None` for the latest).
+    pub version: Option<String>,
+    pub action: ImportAction,
+}
+
+/// Diffs a package set against the installed inventory.
+///
+/// Unpinned packages that are already installed are upgraded only if
+/// winget reports a newer version for them.
+pub fn plan_import(set: &PackageSet, inventory: &[InstalledApp]) -> Vec<ImportItem> {
+    let mut plan = Vec::new();
+    for source in &set.sources {
+        for entry in &source.packages {
+            let id = &entry.package_identifier;
+            let version = entry.version.clone().filter(|v| !v.is_empty());
+            let installed = inventory
+                .iter()
+                .find(|app| app.id.eq_ignore_ascii_case(id));
+            let action = match (installed, &version) {
+                (None, _) => ImportAction::Install,
+                (Some(app), Some(wanted))
+                    if compare_versions(&app.version, wanted) == Ordering::Less =>
+                {
+                    ImportAction::Upgrade {
+                        installed: app.version.clone(),
+                    }
+                }
+                (Some(app), None) if !app.available.is_empty() => ImportAction::Upgrade {
+                    installed: app.version.clone(),
+                },
+                (Some(app), _) => ImportAction::Skip {
+                    installed: app.version.clone(),
+                },
+            };
+            plan.push(ImportItem {
+                id: id.clone(),
+                source: source.source_details.name.clone(),
+                version,
+                action,
+            });
+        }
+    }
+    plan
+}
+
+/// Returns the default location for exported package sets: `packages.json`
+/// in the user's home directory.
+pub fn default_package_file() -> PathBuf {
+    let home = if cfg!(windows) {
+        std::env::var_os("USERPROFILE")
+    } else {
+        std::env::var_os("HOME")
+    };
+    home.map(PathBuf::from)
+        .unwrap_or_default()
+        .join("packages.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"{
+        "$schema": "https://aka.ms/winget-packages.schema.2.0.json",
+        "CreationDate": "2024-03-01T10:00:00.000-00:00",
+        "Sources": [
+            {
+                "Packages": [
+                    { "PackageIdentifier": "Git.Git", "Version": "2.44.0" },
+                    { "PackageIdentifier": "Mozilla.Firefox" },
+                    { "PackageIdentifier": "Microsoft.PowerToys", "Version": "0.79.0" }
+                ],
+                "SourceDetails": {
+                    "Argument": "https://cdn.winget.microsoft.com/cache",
+                    "Identifier": "Microsoft.Winget.Source_8wekyb3d8bbwe",
+                    "Name": "winget",
+                    "Type": "Microsoft.PreIndexed.Package"
+                }
+            }
+        ],
+        "WinGetVersion": "1.7.10661"
+    }"#;
+
+    fn installed(id: &str, version: &str, available: &str) -> InstalledApp {
+        InstalledApp::new(
+            id.into(),
+            id.into(),
+            version.into(),
+            available.into(),
+            "winget".into(),
+        )
+    }
+
+    #[test]
+    fn test_parse_winget_export() {
+        let set = PackageSet::from_json(EXPORT).unwrap();
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.win_get_version, "1.7.10661");
+        let source = &set.sources[0];
+        assert_eq!(source.source_details.name, "winget");
+        assert_eq!(source.source_details.kind, "Microsoft.PreIndexed.Package");
+        assert_eq!(source.packages[0].version.as_deref(), Some("2.44.0"));
+        assert_eq!(source.packages[1].version, None);
+    }
+
+    #[test]
+    fn test_invalid_package_set() {
+        assert!(PackageSet::from_json("{}").is_err());
+        assert!(PackageSet::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_export_groups_by_source_and_skips_unmanaged() {
+        let apps = [
+            installed("Git.Git", "2.44.0", ""),
+            InstalledApp::new(
+                "Spotify".into(),
+                "9NCBCSZSJRSB".into(),
+                "1.2".into(),
+                String::new(),
+                "msstore".into(),
+            ),
+            InstalledApp::new(
+                "Tool".into(),
+                r"ARP\Machine\X64\Tool".into(),
+                "1.0".into(),
+                String::new(),
+                String::new(),
+            ),
+            installed("Mozilla.Firefox", "123.0", ""),
+        ];
+        let set = PackageSet::from_installed(&apps, "2024-03-01".into());
+        assert_eq!(set.schema, PACKAGES_SCHEMA);
+        assert_eq!(set.sources.len(), 2);
+        assert_eq!(set.sources[0].source_details, SourceDetails::for_name("winget"));
+        assert_eq!(set.sources[0].packages.len(), 2);
+        assert_eq!(set.sources[1].source_details.identifier, "StoreEdgeFD");
+
+        // Round-trips through the winget field names.
+        let json = serde_json::to_string(&set).unwrap();
+        assert!(json.contains("\"PackageIdentifier\":\"Git.Git\""));
+        assert!(json.contains("\"Type\":\"Microsoft.PreIndexed.Package\""));
+        assert_eq!(PackageSet::from_json(&json).unwrap(), set);
+    }
+
+    #[test]
+    fn test_plan_import() {
+        let set = PackageSet::from_json(EXPORT).unwrap();
+        let inventory = [
+            installed("git.git", "2.43.0", "2.44.0"),
+            installed("Microsoft.PowerToys", "0.80.1", ""),
+        ];
+        let plan = plan_import(&set, &inventory);
+        assert_eq!(plan.len(), 3);
+        assert_eq!(
+            plan[0].action,
+            ImportAction::Upgrade {
+                installed: "2.43.0".into()
+            }
+        );
+        assert_eq!(plan[0].version.as_deref(), Some("2.44.0"));
+        assert_eq!(plan[1].action, ImportAction::Install);
+        assert_eq!(plan[1].source, "winget");
+        assert_eq!(
+            plan[2].action,
+            ImportAction::Skip {
+                installed: "0.80.1".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_plan_import_unpinned_upgrades_only_when_available() {
+        let set = PackageSet::from_json(EXPORT).unwrap();
+        let inventory = [installed("Mozilla.Firefox", "122.0", "123.0")];
+        let plan = plan_import(&set, &inventory);
+        assert!(matches!(plan[1].action, ImportAction::Upgrade { .. }));
+
+        let inventory = [installed("Mozilla.Firefox", "123.0", "")];
+        let plan = plan_import(&set, &inventory);
+        assert!(matches!(plan[1].action, ImportAction::Skip { .. }));
+    }
+}
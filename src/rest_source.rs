@@ -0,0 +1,597 @@
+//! A minimal stand-in for a winget REST source, for exercising private-source
+//! scenarios offline.
+//!
+//! Implements the read-only endpoints winget needs (`information`,
+//! `manifestSearch` and `packageManifests`) over plain HTTP, serving the
+//! package manifests found in a directory. Each `*.json` file holds one
+//! package in the REST API's `PackageManifest` shape:
+//!
+//! ```json
+//! {
+//!   "PackageIdentifier": "Contoso.Tool",
+//!   "Versions": [{
+//!     "PackageVersion": "1.2.0",
+//!     "DefaultLocale": { "PackageLocale": "en-US", "PackageName": "Contoso Tool", "Publisher": "Contoso" },
+//!     "Installers": [{ "Architecture": "x64", "InstallerType": "msi", "InstallerUrl": "...", "InstallerSha256": "..." }]
+//!   }]
+//! }
+//! ```
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Largest request body the server reads; search requests are far smaller.
+const MAX_BODY_LEN: usize = 64 * 1024;
+
+/// Longest request or header line the server reads.
+const MAX_LINE_LEN: usize = 8 * 1024;
+
+/// Most header lines the server reads per request.
+const MAX_HEADERS: usize = 100;
+
+/// How long the server waits for a client to send more of its request.
+/// Connections are served one at a time, so an idle client must not hold
+/// the server up for long.
+const READ_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// REST API versions the stand-in reports.
+pub const SUPPORTED_VERSIONS: [&str; 2] = ["1.0.0", "1.1.0"];
+
+/// An HTTP response: status code and JSON body (empty for `204`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            body: body.to_string(),
+        }
+    }
+
+    fn no_content() -> Self {
+        Self {
+            status: 204,
+            body: String::new(),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, json!({ "ErrorCode": status, "ErrorMessage": message }))
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            204 => "No Content",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            431 => "Request Header Fields Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+/// The body of a `manifestSearch` request.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+struct SearchRequest {
+    maximum_results: Option<usize>,
+    fetch_all_manifests: bool,
+    query: Option<RequestMatch>,
+    inclusions: Vec<PackageMatchFilter>,
+    filters: Vec<PackageMatchFilter>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RequestMatch {
+    key_word: String,
+    #[serde(default)]
+    match_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PackageMatchFilter {
+    package_match_field: String,
+    request_match: RequestMatch,
+}
+
+impl RequestMatch {
+    /// Compares `value` using the requested match type; the fuzzy and
+    /// wildcard types fall back to a case-insensitive substring match.
+    fn matches(&self, value: &str) -> bool {
+        let (value_lower, key_lower) = (value.to_lowercase(), self.key_word.to_lowercase());
+        match self.match_type.as_str() {
+            "Exact" => value == self.key_word,
+            "CaseInsensitive" => value_lower == key_lower,
+            "StartsWith" => value_lower.starts_with(&key_lower),
+            _ => value_lower.contains(&key_lower),
+        }
+    }
+}
+
+/// The packages served by the stand-in, sorted by identifier.
+#[derive(Debug, Clone, Default)]
+pub struct RestSource {
+    identifier: String,
+    packages: Vec<Value>,
+}
+
+impl RestSource {
+    /// Creates a source from `PackageManifest` documents.
+    pub fn new(identifier: &str, mut packages: Vec<Value>) -> Result<Self, String> {
+        for package in &packages {
+            if package_id(package).is_empty() {
+                return Err(format!("Manifest without a PackageIdentifier: {package}"));
+            }
+        }
+        packages.sort_by(|a, b| package_id(a).cmp(package_id(b)));
+        Ok(Self {
+            identifier: identifier.to_string(),
+            packages,
+        })
+    }
+
+    /// Loads every `*.json` manifest in `dir`; the directory name becomes
+    /// the source identifier.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let entries = std::fs::read_dir(dir)
+            .map_err(|e| format!("Failed to read {}: {e}", dir.display()))?;
+        let mut packages = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|e| format!("Failed to read {}: {e}", dir.display()))?
+                .path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                let json = std::fs::read_to_string(&path)
+                    .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+                packages.push(
+                    serde_json::from_str(&json)
+                        .map_err(|e| format!("Invalid manifest {}: {e}", path.display()))?,
+                );
+            }
+        }
+        let identifier = dir
+            .file_name()
+            .map_or_else(|| String::from("local"), |name| name.to_string_lossy().into_owned());
+        Self::new(&identifier, packages)
+    }
+
+    /// Returns the number of packages served.
+    pub fn len(&self) -> usize {
+        self.packages.len()
+    }
+
+    /// Returns `true` if the source serves no packages.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    /// Answers one request. `path` may carry a query string.
+    pub fn handle(&self, method: &str, path: &str, body: &str) -> Response {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        match (method, segments.as_slice()) {
+            ("GET", ["information"]) => Response::json(
+                200,
+                json!({ "Data": {
+                    "SourceIdentifier": self.identifier,
+                    "ServerSupportedVersions": SUPPORTED_VERSIONS,
+                }}),
+            ),
+            ("POST", ["manifestSearch"]) => match serde_json::from_str(body) {
+                Ok(request) => self.search(&request),
+                Err(e) => Response::error(400, &format!("Invalid search request: {e}")),
+            },
+            ("GET", ["packageManifests", id]) => self.manifest(id, query_param(query, "Version")),
+            (_, ["information" | "manifestSearch"] | ["packageManifests", _]) => {
+                Response::error(405, "Method not allowed")
+            }
+            _ => Response::error(404, "Not found"),
+        }
+    }
+
+    fn search(&self, request: &SearchRequest) -> Response {
+        let has_query = request.query.is_some() || !request.inclusions.is_empty();
+        let mut data: Vec<Value> = self
+            .packages
+            .iter()
+            .filter(|package| {
+                request.fetch_all_manifests
+                    || !has_query
+                    || request.query.as_ref().is_some_and(|query| {
+                        ["PackageIdentifier", "PackageName", "Moniker", "Tag"]
+                            .iter()
+                            .any(|field| field_values(package, field).any(|v| query.matches(&v)))
+                    })
+                    || request.inclusions.iter().any(|filter| filter_matches(package, filter))
+            })
+            .filter(|package| request.filters.iter().all(|filter| filter_matches(package, filter)))
+            .map(search_result)
+            .collect();
+        if let Some(max) = request.maximum_results.filter(|&max| max > 0) {
+            data.truncate(max);
+        }
+
+        if data.is_empty() {
+            Response::no_content()
+        } else {
+            Response::json(200, json!({ "Data": data }))
+        }
+    }
+
+    fn manifest(&self, id: &str, version: Option<String>) -> Response {
+        let Some(package) = self.packages.iter().find(|p| package_id(p) == id) else {
+            return Response::no_content();
+        };
+        let mut package = package.clone();
+        if let Some(version) = version {
+            if let Some(versions) = package["Versions"].as_array_mut() {
+                versions.retain(|v| v["PackageVersion"] == version.as_str());
+                if versions.is_empty() {
+                    return Response::no_content();
+                }
+            }
+        }
+        Response::json(200, json!({ "Data": package }))
+    }
+}
+
+fn package_id(package: &Value) -> &str {
+    package["PackageIdentifier"].as_str().unwrap_or_default()
+}
+
+fn versions(package: &Value) -> impl Iterator<Item = &Value> {
+    package["Versions"].as_array().into_iter().flatten()
+}
+
+/// Returns every value `field` takes across a package's versions.
+fn field_values<'a>(package: &'a Value, field: &str) -> Box<dyn Iterator<Item = String> + 'a> {
+    let strings = |value: &'a Value| -> Box<dyn Iterator<Item = String> + 'a> {
+        match value {
+            Value::String(s) => Box::new(std::iter::once(s.clone())),
+            Value::Array(items) => {
+                Box::new(items.iter().filter_map(|v| v.as_str().map(String::from)))
+            }
+            _ => Box::new(std::iter::empty()),
+        }
+    };
+    match field {
+        "PackageIdentifier" => strings(&package["PackageIdentifier"]),
+        "PackageName" | "Publisher" | "Moniker" => {
+            let field = field.to_string();
+            Box::new(versions(package).flat_map(move |v| strings(&v["DefaultLocale"][&field])))
+        }
+        "Tag" => Box::new(versions(package).flat_map(move |v| strings(&v["DefaultLocale"]["Tags"]))),
+        "Command" | "ProductCode" | "PackageFamilyName" => {
+            let key = if field == "Command" { "Commands" } else { field };
+            let key = key.to_string();
+            Box::new(versions(package).flat_map(move |v| {
+                let key = key.clone();
+                v["Installers"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .flat_map(move |installer| strings(&installer[&key]))
+            }))
+        }
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+fn filter_matches(package: &Value, filter: &PackageMatchFilter) -> bool {
+    field_values(package, &filter.package_match_field).any(|v| filter.request_match.matches(&v))
+}
+
+/// Summarises a package the way `manifestSearch` reports it.
+fn search_result(package: &Value) -> Value {
+    let first = |field: &str| field_values(package, field).next().unwrap_or_default();
+    let versions: Vec<Value> = versions(package)
+        .map(|v| {
+            let codes: Vec<String> = v["Installers"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|i| i["ProductCode"].as_str().map(String::from))
+                .collect();
+            let mut version = json!({ "PackageVersion": v["PackageVersion"] });
+            if !codes.is_empty() {
+                version["ProductCodes"] = json!(codes);
+            }
+            version
+        })
+        .collect();
+    json!({
+        "PackageIdentifier": package_id(package),
+        "PackageName": first("PackageName"),
+        "Publisher": first("Publisher"),
+        "Versions": versions,
+    })
+}
+
+/// Returns the decoded value of `name` in a `key=value&...` query string.
+fn query_param(query: &str, name: &str) -> Option<String> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.replace('+', " ").replace("%20", " ").replace("%2B", "+"))
+}
+
+/// A running stand-in server; stops when dropped.
+pub struct RestServer {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl RestServer {
+    /// Serves `source` on `addr` (e.g. `127.0.0.1:0` for any free port) from
+    /// a background thread, one connection at a time. Clients that stop
+    /// sending are dropped after a short timeout.
+    pub fn start(source: RestSource, addr: &str) -> Result<Self, String> {
+        let listener =
+            TcpListener::bind(addr).map_err(|e| format!("Failed to bind {addr}: {e}"))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| format!("Failed to read the bound address: {e}"))?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopping = Arc::clone(&stop);
+        let thread = std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stopping.load(Ordering::SeqCst) {
+                    break;
+                }
+                // A broken connection only fails its own client.
+                if let Ok(stream) = stream {
+                    let _ = serve_connection(&source, stream);
+                }
+            }
+        });
+        Ok(Self {
+            addr,
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Returns the address the server listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the URL to register with `winget source add --arg`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Blocks until the server thread exits, i.e. forever unless another
+    /// thread stops it.
+    pub fn wait(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for RestServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // Wake the blocking accept so the thread sees the flag. A wildcard
+        // address cannot be connected to everywhere, so use loopback.
+        let mut wake = self.addr;
+        if wake.ip().is_unspecified() {
+            wake.set_ip(match wake {
+                SocketAddr::V4(_) => Ipv4Addr::LOCALHOST.into(),
+                SocketAddr::V6(_) => Ipv6Addr::LOCALHOST.into(),
+            });
+        }
+        let _ = TcpStream::connect(wake);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Reads one HTTP/1.1 request from `stream` and writes the response.
+fn serve_connection(source: &RestSource, stream: TcpStream) -> Result<(), String> {
+    stream
+        .set_read_timeout(Some(READ_TIMEOUT))
+        .map_err(|e| format!("Failed to set the read timeout: {e}"))?;
+    let mut reader = BufReader::new(&stream);
+    let Some(request_line) = read_line(&mut reader)? else {
+        return reject(reader, &header_too_large());
+    };
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Ok(()); // Connection closed without a request
+    };
+
+    let mut content_length = 0;
+    let mut headers = 0;
+    loop {
+        let Some(line) = read_line(&mut reader)?.filter(|_| headers < MAX_HEADERS) else {
+            return reject(reader, &header_too_large());
+        };
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        headers += 1;
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+    if content_length > MAX_BODY_LEN {
+        return reject(reader, &Response::error(413, "Request body too large"));
+    }
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|e| format!("Failed to read body: {e}"))?;
+    write_response(&stream, &source.handle(method, path, &String::from_utf8_lossy(&body)))
+}
+
+/// Answers a request the server will not read in full, then discards what
+/// the client is still sending, so that closing the connection does not
+/// reset it before the client has read the response.
+fn reject(reader: BufReader<&TcpStream>, response: &Response) -> Result<(), String> {
+    let stream = *reader.get_ref();
+    write_response(stream, response)?;
+    let _ = stream.shutdown(Shutdown::Write);
+    let _ = std::io::copy(&mut reader.take(MAX_BODY_LEN as u64), &mut std::io::sink());
+    Ok(())
+}
+
+/// Reads one line of the request head, or `None` if it exceeds
+/// [`MAX_LINE_LEN`].
+fn read_line(reader: &mut BufReader<&TcpStream>) -> Result<Option<String>, String> {
+    let mut line = String::new();
+    reader
+        .by_ref()
+        .take(MAX_LINE_LEN as u64)
+        .read_line(&mut line)
+        .map_err(|e| format!("Failed to read request: {e}"))?;
+    Ok((line.len() < MAX_LINE_LEN || line.ends_with('\n')).then_some(line))
+}
+
+fn header_too_large() -> Response {
+    Response::error(431, "Request header too large")
+}
+
+fn write_response(mut stream: &TcpStream, response: &Response) -> Result<(), String> {
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    )
+    .map_err(|e| format!("Failed to write response: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(id: &str, name: &str, versions: &[&str], tags: &[&str]) -> Value {
+        json!({
+            "PackageIdentifier": id,
+            "Versions": versions.iter().map(|v| json!({
+                "PackageVersion": v,
+                "DefaultLocale": { "PackageName": name, "Publisher": "Contoso", "Tags": tags },
+                "Installers": [{ "Architecture": "x64", "ProductCode": format!("{{{id}-{v}}}") }],
+            })).collect::<Vec<_>>(),
+        })
+    }
+
+    fn source() -> RestSource {
+        RestSource::new(
+            "contoso",
+            vec![
+                package("Contoso.Tool", "Contoso Tool", &["1.0.0", "1.2.0"], &["cli"]),
+                package("Contoso.Agent", "Contoso Agent", &["3.1"], &["service"]),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn data(response: &Response) -> Value {
+        serde_json::from_str::<Value>(&response.body).unwrap()["Data"].clone()
+    }
+
+    #[test]
+    fn test_information() {
+        let response = source().handle("GET", "/information", "");
+        assert_eq!(response.status, 200);
+        assert_eq!(data(&response)["SourceIdentifier"], "contoso");
+        assert_eq!(data(&response)["ServerSupportedVersions"][0], "1.0.0");
+    }
+
+    #[test]
+    fn test_search_by_keyword() {
+        let body = r#"{"Query": {"KeyWord": "tool", "MatchType": "Substring"}}"#;
+        let response = source().handle("POST", "/manifestSearch", body);
+        assert_eq!(response.status, 200);
+        let results = data(&response);
+        assert_eq!(results.as_array().unwrap().len(), 1);
+        assert_eq!(results[0]["PackageIdentifier"], "Contoso.Tool");
+        assert_eq!(results[0]["PackageName"], "Contoso Tool");
+        assert_eq!(results[0]["Versions"][1]["PackageVersion"], "1.2.0");
+        assert_eq!(results[0]["Versions"][0]["ProductCodes"][0], "{Contoso.Tool-1.0.0}");
+    }
+
+    #[test]
+    fn test_search_filters_and_inclusions() {
+        let s = source();
+        let exact = r#"{"Filters": [{"PackageMatchField": "PackageIdentifier",
+            "RequestMatch": {"KeyWord": "Contoso.Agent", "MatchType": "Exact"}}]}"#;
+        let results = data(&s.handle("POST", "/manifestSearch", exact));
+        assert_eq!(results.as_array().unwrap().len(), 1);
+        assert_eq!(results[0]["PackageIdentifier"], "Contoso.Agent");
+
+        let by_tag = r#"{"Inclusions": [{"PackageMatchField": "Tag",
+            "RequestMatch": {"KeyWord": "service", "MatchType": "CaseInsensitive"}}]}"#;
+        let results = data(&s.handle("POST", "/manifestSearch", by_tag));
+        assert_eq!(results[0]["PackageIdentifier"], "Contoso.Agent");
+
+        let all = r#"{"FetchAllManifests": true, "MaximumResults": 1}"#;
+        assert_eq!(data(&s.handle("POST", "/manifestSearch", all)).as_array().unwrap().len(), 1);
+
+        let none = r#"{"Query": {"KeyWord": "nothing", "MatchType": "Exact"}}"#;
+        assert_eq!(s.handle("POST", "/manifestSearch", none).status, 204);
+        assert_eq!(s.handle("POST", "/manifestSearch", "not json").status, 400);
+    }
+
+    #[test]
+    fn test_package_manifests() {
+        let s = source();
+        let response = s.handle("GET", "/packageManifests/Contoso.Tool", "");
+        assert_eq!(response.status, 200);
+        assert_eq!(data(&response)["Versions"].as_array().unwrap().len(), 2);
+
+        let response = s.handle("GET", "/packageManifests/Contoso.Tool?Version=1.0.0", "");
+        let versions = data(&response)["Versions"].clone();
+        assert_eq!(versions.as_array().unwrap().len(), 1);
+        assert_eq!(versions[0]["PackageVersion"], "1.0.0");
+
+        assert_eq!(s.handle("GET", "/packageManifests/Contoso.Tool?Version=9", "").status, 204);
+        assert_eq!(s.handle("GET", "/packageManifests/Missing.App", "").status, 204);
+    }
+
+    #[test]
+    fn test_unknown_routes() {
+        let s = source();
+        assert_eq!(s.handle("GET", "/nope", "").status, 404);
+        assert_eq!(s.handle("DELETE", "/information", "").status, 405);
+        assert_eq!(s.handle("GET", "/manifestSearch", "").status, 405);
+    }
+
+    #[test]
+    fn test_rejects_manifest_without_identifier() {
+        assert!(RestSource::new("x", vec![json!({"Versions": []})]).is_err());
+    }
+
+    #[test]
+    fn test_query_param() {
+        assert_eq!(query_param("Version=1.0&Channel=beta", "version").as_deref(), Some("1.0"));
+        assert_eq!(query_param("Channel=beta", "Version"), None);
+    }
+}
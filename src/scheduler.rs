@@ -0,0 +1,411 @@
+use crate::i18n::LocalizedError;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDateTime, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// File name of the persisted scheduler state inside the data directory.
+pub const SCHEDULER_FILE: &str = "scheduler.json";
+
+/// Interval between scheduler checks.
+pub const TICK_SECS: u64 = 60;
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Returns the catalog ID of the short name of `day`, e.g. `weekday-mon`.
+pub fn weekday_label_id(day: Weekday) -> &'static str {
+    const IDS: [&str; 7] = [
+        "weekday-mon",
+        "weekday-tue",
+        "weekday-wed",
+        "weekday-thu",
+        "weekday-fri",
+        "weekday-sat",
+        "weekday-sun",
+    ];
+    IDS[day.num_days_from_monday() as usize]
+}
+
+/// What a schedule does when its maintenance window opens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduledAction {
+    /// Re-run `winget upgrade` to refresh the list.
+    Refresh,
+    /// Refresh, then update every eligible app without confirmation.
+    Update,
+}
+
+impl fmt::Display for ScheduledAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Refresh => write!(f, "refresh"),
+            Self::Update => write!(f, "update"),
+        }
+    }
+}
+
+/// A recurring maintenance window, e.g. `update mon-fri 12:00-13:00`.
+///
+/// The action runs once per window occurrence, as soon as the app notices
+/// the window is open. Windows whose end is before their start span midnight
+/// and belong to the day they start on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Schedule {
+    pub action: ScheduledAction,
+    /// Allowed start days, indexed Monday = 0 … Sunday = 6.
+    pub days: [bool; 7],
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl Schedule {
+    /// Returns the start of the window occurrence containing `now`, if any.
+    pub fn window_containing(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        let today = now.date();
+        let candidates = [Some(today), today.pred_opt()];
+        candidates.into_iter().flatten().find_map(|day| {
+            if !self.days[day.weekday().num_days_from_monday() as usize] {
+                return None;
+            }
+            let start = day.and_time(self.start);
+            let end = start + self.length();
+            (start <= now && now < end).then_some(start)
+        })
+    }
+
+    /// Returns `true` if the window is open at `now` and has not run during it yet.
+    pub fn is_due(&self, now: NaiveDateTime, last_run: Option<NaiveDateTime>) -> bool {
+        match self.window_containing(now) {
+            Some(start) => last_run.is_none_or(|last| last < start),
+            None => false,
+        }
+    }
+
+    /// Returns when the schedule will next run: `now` if it is due, otherwise
+    /// the start of the next window occurrence.
+    pub fn next_run(
+        &self,
+        now: NaiveDateTime,
+        last_run: Option<NaiveDateTime>,
+    ) -> Option<NaiveDateTime> {
+        if self.is_due(now, last_run) {
+            return Some(now);
+        }
+        (0..=7).find_map(|offset| {
+            let day = now.date() + Duration::days(offset);
+            let start = day.and_time(self.start);
+            (self.days[day.weekday().num_days_from_monday() as usize] && start > now)
+                .then_some(start)
+        })
+    }
+
+    fn length(&self) -> Duration {
+        let len = self.end - self.start;
+        if len <= Duration::zero() {
+            len + Duration::days(1)
+        } else {
+            len
+        }
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = LocalizedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        let [action, days, window] = parts[..] else {
+            return Err(LocalizedError::new("schedule-invalid").arg("schedule", s));
+        };
+
+        let action = match action.to_lowercase().as_str() {
+            "refresh" => ScheduledAction::Refresh,
+            "update" => ScheduledAction::Update,
+            other => {
+                return Err(LocalizedError::new("schedule-unknown-action").arg("action", other))
+            }
+        };
+
+        let days = parse_days(days)?;
+
+        let (start, end) = window
+            .split_once('-')
+            .ok_or_else(|| LocalizedError::new("schedule-invalid-window").arg("window", window))?;
+        let parse_time = |t: &str| {
+            NaiveTime::parse_from_str(t, "%H:%M")
+                .map_err(|_| LocalizedError::new("schedule-invalid-time").arg("time", t))
+        };
+
+        Ok(Self {
+            action,
+            days,
+            start: parse_time(start)?,
+            end: parse_time(end)?,
+        })
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}-{}",
+            self.action,
+            format_days(&self.days),
+            self.start.format("%H:%M"),
+            self.end.format("%H:%M")
+        )
+    }
+}
+
+impl TryFrom<String> for Schedule {
+    type Error = LocalizedError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Schedule> for String {
+    fn from(schedule: Schedule) -> Self {
+        schedule.to_string()
+    }
+}
+
+/// Parses a day specification: `daily`, `weekdays`, `weekends`, a range
+/// (`mon-fri`) or a comma-separated list (`sat,sun`).
+fn parse_days(spec: &str) -> Result<[bool; 7], LocalizedError> {
+    let spec = spec.to_lowercase();
+    match spec.as_str() {
+        "daily" | "*" => return Ok([true; 7]),
+        "weekdays" => return Ok([true, true, true, true, true, false, false]),
+        "weekends" => return Ok([false, false, false, false, false, true, true]),
+        _ => {}
+    }
+
+    let day_index = |name: &str| {
+        name.parse::<Weekday>()
+            .map(|d| d.num_days_from_monday() as usize)
+            .map_err(|_| LocalizedError::new("schedule-unknown-day").arg("day", name))
+    };
+
+    let mut days = [false; 7];
+    for part in spec.split(',') {
+        match part.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (day_index(from)?, day_index(to)?);
+                let mut i = from;
+                loop {
+                    days[i] = true;
+                    if i == to {
+                        break;
+                    }
+                    i = (i + 1) % 7;
+                }
+            }
+            None => days[day_index(part)?] = true,
+        }
+    }
+    Ok(days)
+}
+
+fn format_days(days: &[bool; 7]) -> String {
+    match days {
+        [true, true, true, true, true, true, true] => "daily".into(),
+        [true, true, true, true, true, false, false] => "weekdays".into(),
+        [false, false, false, false, false, true, true] => "weekends".into(),
+        _ => WEEKDAYS
+            .iter()
+            .zip(days)
+            .filter(|(_, &on)| on)
+            .map(|(d, _)| d.to_string().to_lowercase())
+            .collect::<Vec<_>>()
+            .join(","),
+    }
+}
+
+/// Parses a `;`-separated list of schedules as typed in the settings dialog.
+pub fn parse_schedules(input: &str) -> Result<Vec<Schedule>, LocalizedError> {
+    input
+        .split(';')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Formats schedules back into the `;`-separated settings representation.
+pub fn format_schedules(schedules: &[Schedule]) -> String {
+    schedules
+        .iter()
+        .map(Schedule::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Persisted scheduler bookkeeping: when each schedule last ran.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SchedulerState {
+    /// Unix timestamps keyed by the schedule's textual form.
+    last_runs: HashMap<String, u64>,
+}
+
+impl SchedulerState {
+    /// Returns when `schedule` last ran, in local time.
+    pub fn last_run(&self, schedule: &Schedule) -> Option<NaiveDateTime> {
+        self.last_runs
+            .get(&schedule.to_string())
+            .and_then(|&secs| to_local(secs))
+    }
+
+    /// Records that `schedule` ran at the given Unix time.
+    pub fn mark_run(&mut self, schedule: &Schedule, unix_secs: u64) {
+        self.last_runs.insert(schedule.to_string(), unix_secs);
+    }
+
+    /// Returns the first schedule that is due at `now`, if any.
+    pub fn due<'a>(&self, schedules: &'a [Schedule], now: NaiveDateTime) -> Option<&'a Schedule> {
+        schedules.iter().find(|s| s.is_due(now, self.last_run(s)))
+    }
+
+    /// Returns the earliest upcoming run across all schedules.
+    pub fn next_run<'a>(
+        &self,
+        schedules: &'a [Schedule],
+        now: NaiveDateTime,
+    ) -> Option<(NaiveDateTime, &'a Schedule)> {
+        schedules
+            .iter()
+            .filter_map(|s| s.next_run(now, self.last_run(s)).map(|t| (t, s)))
+            .min_by_key(|(t, _)| *t)
+    }
+}
+
+/// Returns the current local wall-clock time.
+pub fn local_now() -> NaiveDateTime {
+    Local::now().naive_local()
+}
+
+/// Converts a Unix timestamp to local wall-clock time.
+pub fn to_local(unix_secs: u64) -> Option<NaiveDateTime> {
+    DateTime::from_timestamp(i64::try_from(unix_secs).ok()?, 0)
+        .map(|t| t.with_timezone(&Local).naive_local())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    /// 2024-01-01 is a Monday.
+    fn at(day: u32, h: u32, m: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, day)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_schedule_range() {
+        let s: Schedule = "update mon-fri 12:00-13:00".parse().unwrap();
+        assert_eq!(s.action, ScheduledAction::Update);
+        assert_eq!(s.days, [true, true, true, true, true, false, false]);
+        assert_eq!(s.to_string(), "update weekdays 12:00-13:00");
+    }
+
+    #[test]
+    fn test_parse_schedule_list_and_wraparound_range() {
+        let s: Schedule = "refresh sat,sun 02:00-05:00".parse().unwrap();
+        assert_eq!(s.days, [false, false, false, false, false, true, true]);
+
+        let s: Schedule = "refresh fri-mon 02:00-05:00".parse().unwrap();
+        assert_eq!(s.days, [true, false, false, false, true, true, true]);
+        assert_eq!(s.to_string(), "refresh mon,fri,sat,sun 02:00-05:00");
+    }
+
+    #[test]
+    fn test_parse_schedule_errors() {
+        assert!("update mon-fri".parse::<Schedule>().is_err());
+        assert!("install daily 01:00-02:00".parse::<Schedule>().is_err());
+        assert!("update funday 01:00-02:00".parse::<Schedule>().is_err());
+        assert!("update daily 25:00-26:00".parse::<Schedule>().is_err());
+    }
+
+    #[test]
+    fn test_is_due_inside_window() {
+        let s: Schedule = "update weekdays 12:00-13:00".parse().unwrap();
+        assert!(s.is_due(at(1, 12, 30), None));
+        assert!(!s.is_due(at(1, 13, 0), None));
+        assert!(!s.is_due(at(1, 11, 59), None));
+        // Saturday
+        assert!(!s.is_due(at(6, 12, 30), None));
+    }
+
+    #[test]
+    fn test_is_due_runs_once_per_window() {
+        let s: Schedule = "update daily 12:00-13:00".parse().unwrap();
+        assert!(!s.is_due(at(1, 12, 45), Some(at(1, 12, 1))));
+        assert!(s.is_due(at(2, 12, 1), Some(at(1, 12, 1))));
+    }
+
+    #[test]
+    fn test_overnight_window() {
+        let s: Schedule = "refresh mon 23:00-01:00".parse().unwrap();
+        assert!(s.is_due(at(1, 23, 30), None));
+        assert!(s.is_due(at(2, 0, 30), None));
+        assert!(!s.is_due(at(2, 1, 30), None));
+    }
+
+    #[test]
+    fn test_next_run() {
+        let s: Schedule = "update weekdays 12:00-13:00".parse().unwrap();
+        assert_eq!(s.next_run(at(1, 9, 0), None), Some(at(1, 12, 0)));
+        assert_eq!(s.next_run(at(1, 12, 30), None), Some(at(1, 12, 30)));
+        assert_eq!(s.next_run(at(1, 12, 30), Some(at(1, 12, 5))), Some(at(2, 12, 0)));
+        // Friday afternoon → Monday
+        assert_eq!(s.next_run(at(5, 14, 0), None), Some(at(8, 12, 0)));
+    }
+
+    #[test]
+    fn test_parse_schedules_list() {
+        let list = parse_schedules("refresh daily 08:00-09:00; update weekends 02:00-04:00;").unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(
+            format_schedules(&list),
+            "refresh daily 08:00-09:00; update weekends 02:00-04:00"
+        );
+        assert!(parse_schedules("").unwrap().is_empty());
+        assert!(parse_schedules("bogus").is_err());
+    }
+
+    #[test]
+    fn test_scheduler_state_due_and_next() {
+        let schedules = parse_schedules("refresh daily 08:00-09:00; update daily 12:00-13:00").unwrap();
+        let state = SchedulerState::default();
+        assert_eq!(state.due(&schedules, at(1, 8, 10)), Some(&schedules[0]));
+        assert_eq!(state.due(&schedules, at(1, 10, 0)), None);
+
+        let (when, which) = state.next_run(&schedules, at(1, 10, 0)).unwrap();
+        assert_eq!(when, at(1, 12, 0));
+        assert_eq!(which, &schedules[1]);
+    }
+
+    #[test]
+    fn test_schedule_serde_as_string() {
+        let s: Schedule = "update daily 12:00-13:00".parse().unwrap();
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(json, "\"update daily 12:00-13:00\"");
+        let back: Schedule = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, s);
+    }
+}
@@ -0,0 +1,440 @@
+use crate::filter::id_pattern_matches;
+use crate::i18n::{Language, LocalizedError};
+use crate::models::{ArgsProfile, PackageOptions};
+use crate::scheduler::Schedule;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// File name of the persisted settings inside the data directory.
+pub const SETTINGS_FILE: &str = "settings.json";
+
+/// User preferences, persisted as JSON in the application data directory.
+///
+/// Every field has a default so that settings files written by older
+/// versions keep loading after new options are added.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    /// Minimum number of days a release must have been observed before it
+    /// can be selected for update (`0` disables the cooling-off period).
+    pub cooldown_days: u32,
+    /// Maintenance windows for unattended refreshes and updates.
+    #[serde(deserialize_with = "lenient_list")]
+    pub schedules: Vec<Schedule>,
+    /// Package IDs that scheduled updates never touch.
+    pub ignored_ids: Vec<String>,
+    /// Suppresses all desktop notifications.
+    pub mute_notifications: bool,
+    /// Package IDs that never trigger or appear in desktop notifications.
+    pub muted_ids: Vec<String>,
+    /// Minutes between background refreshes while idle (`0` disables).
+    pub refresh_interval_mins: u32,
+    /// Matches search text as a fuzzy subsequence and ranks rows by score.
+    pub fuzzy_search: bool,
+    /// Groups the app list into collapsible sections per source.
+    pub group_by_source: bool,
+    /// Starts per-row "Update" actions without asking for confirmation.
+    pub skip_row_update_confirmation: bool,
+    /// Colour theme; `System` follows the desktop's light/dark setting.
+    pub theme: ThemeChoice,
+    /// UI language; `None` follows the desktop locale.
+    pub language: Option<Language>,
+    /// Path of the desired-state manifest checked for drift (empty for none).
+    pub manifest_path: String,
+    /// Sources left out when listing and upgrading packages.
+    pub disabled_sources: Vec<String>,
+    /// Accepts the Microsoft Store's source agreements when updating or
+    /// installing store apps; other sources' agreements are always accepted.
+    /// On by default, as earlier versions always accepted them.
+    pub accept_store_agreements: bool,
+    /// Upgrade options chosen for individual packages, keyed by ID.
+    pub package_options: BTreeMap<String, PackageOptions>,
+    /// Upgrade options applied to packages matching an ID pattern, in order.
+    #[serde(deserialize_with = "lenient_list")]
+    pub option_rules: Vec<OptionRule>,
+    /// Extra `--override` / `--custom` installer arguments, keyed by ID.
+    pub args_profiles: BTreeMap<String, ArgsProfile>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            cooldown_days: 0,
+            schedules: Vec::new(),
+            ignored_ids: Vec::new(),
+            mute_notifications: false,
+            muted_ids: Vec::new(),
+            refresh_interval_mins: 0,
+            fuzzy_search: false,
+            group_by_source: false,
+            skip_row_update_confirmation: false,
+            theme: ThemeChoice::default(),
+            language: None,
+            manifest_path: String::new(),
+            disabled_sources: Vec::new(),
+            accept_store_agreements: true,
+            package_options: BTreeMap::new(),
+            option_rules: Vec::new(),
+            args_profiles: BTreeMap::new(),
+        }
+    }
+}
+
+/// The user's theme preference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeChoice {
+    /// Dark or light, following the desktop colour scheme.
+    System,
+    /// The original look, kept for settings files that predate the choice.
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeChoice {
+    /// Every choice, in the order shown in the settings dialog.
+    pub const ALL: [ThemeChoice; 4] = [Self::System, Self::Dark, Self::Light, Self::HighContrast];
+
+    /// Returns the message ID of the choice's label.
+    pub fn label_id(self) -> &'static str {
+        match self {
+            Self::System => "theme-system",
+            Self::Dark => "theme-dark",
+            Self::Light => "theme-light",
+            Self::HighContrast => "theme-high-contrast",
+        }
+    }
+}
+
+/// Deserializes a list of textual entries, skipping the ones that do not
+/// parse, so one bad entry does not make the whole settings file unreadable
+/// (which would reset every preference to its default).
+fn lenient_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Entry {
+        Text(String),
+        Other(IgnoredAny),
+    }
+
+    Ok(Vec::<Entry>::deserialize(deserializer)?
+        .into_iter()
+        .filter_map(|entry| match entry {
+            Entry::Text(text) => text.parse().ok(),
+            Entry::Other(_) => None,
+        })
+        .collect())
+}
+
+/// Upgrade options for every package whose ID matches `pattern`, written as
+/// `Contoso.* scope=machine arch=x64`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct OptionRule {
+    /// Exact package ID or glob pattern (`*`, `?`), matched case-insensitively.
+    pub pattern: String,
+    pub options: PackageOptions,
+}
+
+impl std::str::FromStr for OptionRule {
+    type Err = LocalizedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (pattern, options) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+        if pattern.is_empty() {
+            return Err(LocalizedError::new("option-rule-missing-pattern"));
+        }
+        let options: PackageOptions = options.parse()?;
+        if options.is_default() {
+            return Err(LocalizedError::new("option-rule-no-options").arg("pattern", pattern));
+        }
+        Ok(Self {
+            pattern: pattern.to_string(),
+            options,
+        })
+    }
+}
+
+impl fmt::Display for OptionRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.pattern, self.options)
+    }
+}
+
+impl TryFrom<String> for OptionRule {
+    type Error = LocalizedError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<OptionRule> for String {
+    fn from(rule: OptionRule) -> Self {
+        rule.to_string()
+    }
+}
+
+/// Parses `;`-separated option rules as typed in the settings dialog.
+pub fn parse_option_rules(input: &str) -> Result<Vec<OptionRule>, LocalizedError> {
+    input
+        .split(';')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Formats option rules back into the `;`-separated settings representation.
+pub fn format_option_rules(rules: &[OptionRule]) -> String {
+    rules
+        .iter()
+        .map(OptionRule::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Splits a comma-separated list of package IDs as typed in the settings dialog.
+pub fn parse_id_list(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+impl Settings {
+    /// Returns the cooling-off period in seconds.
+    pub fn cooldown_secs(&self) -> u64 {
+        u64::from(self.cooldown_days) * 24 * 60 * 60
+    }
+
+    /// Returns the upgrade options for `id`: the package's own choice if it
+    /// has one, otherwise the first matching rule's, otherwise the defaults.
+    pub fn package_options_for(&self, id: &str) -> PackageOptions {
+        self.package_options
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(id))
+            .map(|(_, options)| options)
+            .or_else(|| {
+                self.option_rules
+                    .iter()
+                    .find(|rule| id_pattern_matches(&rule.pattern, id))
+                    .map(|rule| &rule.options)
+            })
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the arguments profile stored for `id`, if any.
+    pub fn args_profile_for(&self, id: &str) -> Option<&ArgsProfile> {
+        self.args_profiles
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(id))
+            .map(|(_, profile)| profile)
+    }
+}
+
+/// Returns the per-user directory where settings and history are stored.
+///
+/// Uses `%APPDATA%\WinAppsUpdater` on Windows and the XDG config directory
+/// elsewhere. Returns `None` if no suitable base directory is known.
+pub fn data_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        std::env::var_os("APPDATA").map(|base| PathBuf::from(base).join("WinAppsUpdater"))
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+            .map(|base| base.join("win-apps-updater"))
+    }
+}
+
+/// Loads a JSON document, falling back to the default value if the file is
+/// missing or cannot be parsed.
+pub fn load_json<T: DeserializeOwned + Default>(path: &Path) -> T {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+/// Writes a value as pretty-printed JSON, creating parent directories as needed.
+pub fn save_json<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {e}", parent.display()))?;
+    }
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialise {}: {e}", path.display()))?;
+    std::fs::write(path, json).map_err(|e| format!("Failed to write {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Architecture, InstallScope};
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("win_apps_updater_{}_{name}", std::process::id()))
+            .join(SETTINGS_FILE)
+    }
+
+    #[test]
+    fn test_settings_default() {
+        let settings = Settings::default();
+        assert_eq!(settings.cooldown_days, 0);
+        assert_eq!(settings.cooldown_secs(), 0);
+        assert!(settings.schedules.is_empty());
+        assert!(settings.ignored_ids.is_empty());
+    }
+
+    #[test]
+    fn test_cooldown_secs() {
+        let settings = Settings {
+            cooldown_days: 7,
+            ..Default::default()
+        };
+        assert_eq!(settings.cooldown_secs(), 7 * 86_400);
+    }
+
+    #[test]
+    fn test_parse_id_list() {
+        assert_eq!(parse_id_list(" A.App, ,B.App "), vec!["A.App", "B.App"]);
+        assert!(parse_id_list("").is_empty());
+    }
+
+    #[test]
+    fn test_load_missing_file_returns_default() {
+        let settings: Settings = load_json(Path::new("/nonexistent/dir/settings.json"));
+        assert_eq!(settings, Settings::default());
+    }
+
+    #[test]
+    fn test_load_ignores_unknown_and_missing_fields() {
+        let settings: Settings = serde_json::from_str(r#"{"unknown": 1}"#).unwrap();
+        assert_eq!(settings, Settings::default());
+    }
+
+    #[test]
+    fn test_invalid_list_entries_are_skipped() {
+        let settings: Settings = serde_json::from_str(
+            r#"{
+                "cooldown_days": 4,
+                "schedules": ["update weekdays 12:00-13:00", "update someday", 7],
+                "option_rules": ["Contoso.* arch=mips", "Git.Git scope=user"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(settings.cooldown_days, 4);
+        assert_eq!(settings.schedules.len(), 1);
+        assert_eq!(settings.schedules[0].to_string(), "update weekdays 12:00-13:00");
+        assert_eq!(format_option_rules(&settings.option_rules), "Git.Git scope=user");
+    }
+
+    #[test]
+    fn test_theme_choice_serde() {
+        let json = serde_json::to_string(&ThemeChoice::HighContrast).unwrap();
+        assert_eq!(json, "\"high-contrast\"");
+        let parsed: ThemeChoice = serde_json::from_str("\"light\"").unwrap();
+        assert_eq!(parsed, ThemeChoice::Light);
+    }
+
+    #[test]
+    fn test_older_settings_keep_the_dark_theme() {
+        let settings: Settings = serde_json::from_str(r#"{"cooldown_days": 2}"#).unwrap();
+        assert_eq!(settings.theme, ThemeChoice::Dark);
+    }
+
+    #[test]
+    fn test_older_settings_keep_accepting_store_agreements() {
+        let settings: Settings = serde_json::from_str(r#"{"cooldown_days": 2}"#).unwrap();
+        assert!(settings.accept_store_agreements);
+        assert!(Settings::default().accept_store_agreements);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = temp_path("round_trip");
+        let settings = Settings {
+            cooldown_days: 3,
+            schedules: vec!["update weekdays 12:00-13:00".parse().unwrap()],
+            ignored_ids: vec!["Mozilla.Firefox".into()],
+            mute_notifications: true,
+            muted_ids: vec!["Google.Chrome".into()],
+            refresh_interval_mins: 30,
+            fuzzy_search: true,
+            group_by_source: true,
+            skip_row_update_confirmation: true,
+            theme: ThemeChoice::HighContrast,
+            language: Some(Language::French),
+            manifest_path: r"C:\Users\me\desired.toml".into(),
+            disabled_sources: vec!["msstore".into()],
+            accept_store_agreements: false,
+            package_options: BTreeMap::from([(
+                "Git.Git".to_string(),
+                "scope=machine arch=x64".parse().unwrap(),
+            )]),
+            option_rules: parse_option_rules("Contoso.* type=msi locale=en-US").unwrap(),
+            args_profiles: BTreeMap::from([(
+                "Contoso.Tool".to_string(),
+                ArgsProfile {
+                    override_args: "/S /LICENSE=lic-{version}".into(),
+                    custom_args: String::new(),
+                },
+            )]),
+        };
+        save_json(&path, &settings).unwrap();
+        let loaded: Settings = load_json(&path);
+        assert_eq!(loaded, settings);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_parse_and_format_option_rules() {
+        let rules = parse_option_rules(" Contoso.* scope=Machine arch=x64 ; Git.Git locale=de-DE;").unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].pattern, "Contoso.*");
+        assert_eq!(rules[0].options.scope, InstallScope::Machine);
+        assert_eq!(rules[0].options.architecture, Architecture::X64);
+        assert_eq!(rules[1].options.locale, "de-DE");
+        assert_eq!(
+            format_option_rules(&rules),
+            "Contoso.* scope=machine arch=x64; Git.Git locale=de-DE"
+        );
+        assert!(parse_option_rules("Contoso.*").is_err());
+        assert!(parse_option_rules("Contoso.* arch=mips").is_err());
+        assert!(parse_option_rules("Contoso.* type=dmg").is_err());
+    }
+
+    #[test]
+    fn test_package_options_prefer_id_over_rules() {
+        let settings = Settings {
+            package_options: BTreeMap::from([(
+                "Contoso.Tool".to_string(),
+                "scope=user".parse().unwrap(),
+            )]),
+            option_rules: parse_option_rules("Contoso.* scope=machine; *.Agent arch=arm64").unwrap(),
+            ..Settings::default()
+        };
+        assert_eq!(settings.package_options_for("contoso.tool").scope, InstallScope::User);
+        assert_eq!(settings.package_options_for("Contoso.Agent").scope, InstallScope::Machine);
+        assert_eq!(
+            settings.package_options_for("Fabrikam.Agent").architecture,
+            Architecture::Arm64
+        );
+        assert!(settings.package_options_for("Git.Git").is_default());
+    }
+}
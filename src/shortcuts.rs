@@ -0,0 +1,79 @@
+use crate::models::Message;
+use iced::keyboard::{key::Named, Key, Modifiers};
+
+/// Keyboard shortcuts as `(keys, description message ID)` pairs, listed in
+/// the help overlay.
+pub const SHORTCUTS: &[(&str, &str)] = &[
+    ("F5", "shortcut-refresh"),
+    ("Ctrl+F", "shortcut-focus-search"),
+    ("Ctrl+A", "shortcut-select-all"),
+    ("Ctrl+Shift+A", "shortcut-deselect-all"),
+    ("Up / Down", "shortcut-move-cursor"),
+    ("Space", "shortcut-toggle-cursor"),
+    ("Enter", "shortcut-details"),
+    ("Ctrl+U", "shortcut-update"),
+    ("Esc", "shortcut-close"),
+    ("F1", "shortcut-help"),
+];
+
+/// Maps a key press that no widget handled to the message it triggers.
+pub fn shortcut(key: &Key, modifiers: Modifiers) -> Option<Message> {
+    match key.as_ref() {
+        Key::Named(Named::F1) => Some(Message::ToggleHelp),
+        Key::Named(Named::F5) => Some(Message::LoadApps),
+        Key::Named(Named::Escape) => Some(Message::CloseOverlay),
+        Key::Named(Named::ArrowUp) => Some(Message::MoveCursor(-1)),
+        Key::Named(Named::ArrowDown) => Some(Message::MoveCursor(1)),
+        Key::Named(Named::Space) | Key::Character(" ") => Some(Message::ToggleCursor),
+        Key::Named(Named::Enter) => Some(Message::OpenDetails),
+        Key::Character(c) if modifiers.command() => match c.to_lowercase().as_str() {
+            "f" => Some(Message::FocusSearch),
+            "a" if modifiers.shift() => Some(Message::DeselectAll),
+            "a" => Some(Message::SelectAll),
+            "u" => Some(Message::UpdateSelected),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn char_key(c: &str) -> Key {
+        Key::Character(c.into())
+    }
+
+    #[test]
+    fn test_named_keys() {
+        let none = Modifiers::empty();
+        assert!(matches!(shortcut(&Key::Named(Named::F5), none), Some(Message::LoadApps)));
+        assert!(matches!(
+            shortcut(&Key::Named(Named::ArrowUp), none),
+            Some(Message::MoveCursor(-1))
+        ));
+        assert!(matches!(
+            shortcut(&Key::Named(Named::Escape), none),
+            Some(Message::CloseOverlay)
+        ));
+    }
+
+    #[test]
+    fn test_ctrl_shortcuts() {
+        let ctrl = Modifiers::CTRL;
+        assert!(matches!(shortcut(&char_key("a"), ctrl), Some(Message::SelectAll)));
+        assert!(matches!(
+            shortcut(&char_key("A"), ctrl | Modifiers::SHIFT),
+            Some(Message::DeselectAll)
+        ));
+        assert!(matches!(shortcut(&char_key("u"), ctrl), Some(Message::UpdateSelected)));
+        assert!(matches!(shortcut(&char_key("f"), ctrl), Some(Message::FocusSearch)));
+    }
+
+    #[test]
+    fn test_plain_letters_ignored() {
+        assert!(shortcut(&char_key("a"), Modifiers::empty()).is_none());
+        assert!(shortcut(&char_key("x"), Modifiers::CTRL).is_none());
+    }
+}
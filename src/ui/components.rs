@@ -1,28 +1,28 @@
-use crate::app::AppState;
-use crate::models::Message;
+use crate::app::{AppState, VisibleRow};
+use crate::cooldown::{format_countdown, unix_now};
+use crate::filter::parse_query;
+use crate::fuzzy::FuzzyMatch;
+use crate::i18n::Language;
+use crate::manifest::{Drift, DriftItem};
+use crate::models::{
+    AppItem, Architecture, BatchAction, InstallScope, Message, PackageOptions, SortColumn,
+    SortOrder, SourceAction, SourceInfo, Tab, INSTALLER_TYPES,
+};
+use crate::packages::ImportAction;
+use crate::scheduler::to_local;
+use crate::settings::ThemeChoice;
+use crate::shortcuts::SHORTCUTS;
+use crate::ui::theme::Palette;
+use crate::ui::virtual_list::{visible_window, GROUP_HEADER_HEIGHT, ROW_HEIGHT};
+use crate::winget::{format_command_line, is_unnamed_store_app, update_args, STORE_SOURCE};
 use iced::{
     widget::{
-        button, checkbox, column, container, horizontal_rule, progress_bar, row, scrollable,
-        stack, text, text_input, Column,
+        button, checkbox, column, container, horizontal_rule, pick_list, progress_bar, rich_text,
+        row, scrollable, span, stack, text, text::Span, text_input, Column, Space,
     },
     Alignment, Color, Element, Font, Length,
 };
-
-// ── Colour palette ───────────────────────────────────────────────────
-
-const ACCENT: Color = Color::from_rgb(0.30, 0.56, 0.93);
-const SURFACE: Color = Color::from_rgb(0.13, 0.13, 0.17);
-const SURFACE_LIGHT: Color = Color::from_rgb(0.17, 0.17, 0.22);
-const HEADER_BG: Color = Color::from_rgb(0.10, 0.10, 0.14);
-const ROW_ALT: Color = Color::from_rgb(0.15, 0.15, 0.19);
-const ROW_NORMAL: Color = Color::from_rgb(0.12, 0.12, 0.16);
-const TEXT_MUTED: Color = Color::from_rgb(0.55, 0.55, 0.60);
-const SUCCESS_CLR: Color = Color::from_rgb(0.30, 0.80, 0.30);
-const FAILURE_CLR: Color = Color::from_rgb(0.90, 0.25, 0.25);
-const WARNING_CLR: Color = Color::from_rgb(1.0, 0.65, 0.15);
-const INFO_CLR: Color = Color::from_rgb(0.40, 0.70, 1.0);
-const OVERLAY_BG: Color = Color::from_rgba(0.0, 0.0, 0.0, 0.70);
-const DIALOG_BG: Color = Color::from_rgb(0.16, 0.16, 0.20);
+use std::fmt;
 
 const BOLD: Font = Font {
     weight: iced::font::Weight::Bold,
@@ -42,9 +42,34 @@ pub fn build_view(state: &AppState) -> Element<'_, Message> {
     if state.show_confirmation {
         layers.push(build_confirmation_overlay(state));
     }
+    if state.show_uninstall_confirmation {
+        layers.push(build_uninstall_confirmation_overlay(state));
+    }
+    if state.show_install_confirmation {
+        layers.push(build_install_confirmation_overlay(state));
+    }
+    if state.show_import_preview {
+        layers.push(build_import_preview_overlay(state));
+    }
+    if let Some(report) = &state.drift_report {
+        layers.push(build_drift_overlay(state, report));
+    }
     if state.show_results_dialog {
         layers.push(build_results_overlay(state));
     }
+    if state.show_settings {
+        layers.push(build_settings_overlay(state));
+    }
+    if let Some(item) = state
+        .details
+        .as_ref()
+        .and_then(|id| state.apps.iter().find(|item| &item.app.id == id))
+    {
+        layers.push(build_details_overlay(state, item));
+    }
+    if state.show_help {
+        layers.push(build_help_overlay(state));
+    }
 
     stack(layers)
         .width(Length::Fill)
@@ -58,7 +83,12 @@ fn build_base_content(state: &AppState) -> Element<'_, Message> {
     let title_bar = build_title_bar(state);
     let toolbar = build_toolbar(state);
     let search = build_search_bar(state);
-    let app_list = build_app_list(state);
+    let app_list = match state.tab {
+        Tab::Updates => build_app_list(state),
+        Tab::Installed => build_inventory_list(state),
+        Tab::Discover => build_discover_list(state),
+        Tab::Sources => build_sources_list(state),
+    };
     let status_bar = build_status_bar(state);
 
     let mut content = Column::new().spacing(0).width(Length::Fill).height(Length::Fill);
@@ -74,101 +104,332 @@ fn build_base_content(state: &AppState) -> Element<'_, Message> {
 }
 
 fn build_title_bar(state: &AppState) -> Element<'_, Message> {
-    let title = text("Windows Apps Updater")
+    let p = state.palette();
+    let title = text(state.tr("app-title"))
         .size(26)
-        .color(Color::WHITE)
+        .color(p.text)
         .font(BOLD);
 
-    let selected = state.selected_count();
+    let selected = match state.tab {
+        Tab::Updates => state.selected_count(),
+        Tab::Installed => state.inventory_selected.len(),
+        Tab::Discover => state.discover_selected.len(),
+        Tab::Sources => 0,
+    };
     let badge: Element<'_, Message> = if selected > 0 {
-        text(format!("{selected} selected"))
+        text(state.tr_args("title-selected", &[("count", selected.into())]))
             .size(13)
-            .color(ACCENT)
+            .color(p.accent)
             .into()
     } else {
         text("").size(13).into()
     };
 
+    let tab = |id: &str, tab: Tab| {
+        if state.tab == tab {
+            styled_button_accent(state.tr(id), true, Message::TabSelected(tab))
+        } else {
+            styled_button(state.tr(id), true, Message::TabSelected(tab))
+        }
+    };
+
     container(
-        row![title, badge]
-            .spacing(16)
-            .align_y(Alignment::Center),
+        row![
+            title,
+            badge,
+            Space::with_width(Length::Fill),
+            tab("tab-updates", Tab::Updates),
+            tab("tab-installed", Tab::Installed),
+            tab("tab-discover", Tab::Discover),
+            tab("tab-sources", Tab::Sources),
+        ]
+        .spacing(16)
+        .align_y(Alignment::Center),
     )
     .padding([16, 24])
     .width(Length::Fill)
-    .style(|_| container::Style {
-        background: Some(iced::Background::Color(HEADER_BG)),
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(p.header_bg)),
         ..Default::default()
     })
     .into()
 }
 
 fn build_toolbar(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
     let enabled = !state.updating && !state.loading;
 
-    let refresh = styled_button("Refresh", enabled, Message::LoadApps);
-    let select_all = styled_button("Select All", enabled, Message::SelectAll);
-    let deselect_all = styled_button("Deselect All", enabled, Message::DeselectAll);
+    let buttons = match state.tab {
+        Tab::Updates => build_update_actions(state, enabled),
+        Tab::Installed => row![
+            styled_button(
+                state.tr("toolbar-refresh"),
+                !state.inventory_loading,
+                Message::LoadInventory
+            ),
+            build_uninstall_button(state),
+            build_package_file_input(state),
+            styled_button(
+                state.tr("toolbar-export"),
+                !state.inventory_selected.is_empty(),
+                Message::ExportPackages
+            ),
+            styled_button(
+                state.tr("toolbar-import"),
+                !state.updating && !state.inventory_loading,
+                Message::ImportPackages
+            ),
+            styled_button(
+                state.tr("toolbar-check-drift"),
+                !state.updating && !state.inventory_loading,
+                Message::CheckDrift
+            ),
+            styled_button(state.tr("toolbar-settings"), !state.updating, Message::OpenSettings),
+            styled_button(state.tr("toolbar-shortcuts"), true, Message::ToggleHelp),
+        ],
+        Tab::Discover => build_install_actions(state),
+        Tab::Sources => row![
+            styled_button(
+                state.tr("toolbar-refresh"),
+                !state.sources_loading,
+                Message::LoadSources
+            ),
+            styled_button(
+                state.tr("toolbar-update-sources"),
+                !state.source_busy,
+                Message::RunSourceAction(SourceAction::Update(None))
+            ),
+            styled_button(
+                state.tr("toolbar-reset-sources"),
+                !state.source_busy,
+                Message::RunSourceAction(SourceAction::Reset(None))
+            ),
+            styled_button(state.tr("toolbar-settings"), !state.updating, Message::OpenSettings),
+            styled_button(state.tr("toolbar-shortcuts"), true, Message::ToggleHelp),
+        ],
+    };
+
+    container(buttons.spacing(8).align_y(Alignment::Center))
+        .padding([10, 24])
+        .width(Length::Fill)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.surface_light)),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Toolbar buttons of the updates tab.
+fn build_update_actions(state: &AppState, enabled: bool) -> iced::widget::Row<'_, Message> {
+    let refresh = styled_button(state.tr("toolbar-refresh"), enabled, Message::LoadApps);
+    let select_all = styled_button(state.tr("toolbar-select-all"), enabled, Message::SelectAll);
+    let deselect_all = styled_button(state.tr("toolbar-deselect-all"), enabled, Message::DeselectAll);
+    let invert = styled_button(state.tr("toolbar-invert"), enabled, Message::InvertSelection);
+    let settings = styled_button(state.tr("toolbar-settings"), !state.updating, Message::OpenSettings);
+    let help = styled_button(state.tr("toolbar-shortcuts"), true, Message::ToggleHelp);
 
     let update_btn = if state.updating {
-        styled_button_accent("Updating...", false, Message::UpdateSelected)
+        styled_button_accent(state.tr(busy_label(state)), false, Message::UpdateSelected)
     } else {
         styled_button_accent(
-            "Update Selected",
+            state.tr("toolbar-update-selected"),
             enabled && state.selected_count() > 0,
             Message::UpdateSelected,
         )
     };
 
-    container(
-        row![refresh, select_all, deselect_all, update_btn]
-            .spacing(8)
-            .align_y(Alignment::Center),
-    )
-    .padding([10, 24])
-    .width(Length::Fill)
-    .style(|_| container::Style {
-        background: Some(iced::Background::Color(SURFACE_LIGHT)),
-        ..Default::default()
-    })
-    .into()
+    row![refresh, select_all, deselect_all, invert, update_btn, settings, help]
+}
+
+/// "Uninstall Selected" button of the inventory tab.
+fn build_uninstall_button(state: &AppState) -> Element<'_, Message> {
+    if state.updating {
+        styled_button_accent(state.tr(busy_label(state)), false, Message::UninstallSelected)
+    } else {
+        styled_button_accent(
+            state.tr("toolbar-uninstall-selected"),
+            !state.inventory_selected.is_empty(),
+            Message::UninstallSelected,
+        )
+    }
+}
+
+/// Path of the `packages.json` file used by "Export" and "Import".
+fn build_package_file_input(state: &AppState) -> Element<'_, Message> {
+    text_input(&state.tr("package-file-placeholder"), &state.package_file)
+        .on_input(Message::PackageFileChanged)
+        .size(13)
+        .padding(8)
+        .width(Length::Fixed(260.0))
+        .into()
+}
+
+/// Toolbar of the discover tab: search, install scope and "Install Selected".
+fn build_install_actions(state: &AppState) -> iced::widget::Row<'_, Message> {
+    let search = styled_button(
+        state.tr("toolbar-search"),
+        !state.discover_loading && !state.discover_query.trim().is_empty(),
+        Message::SearchPackages,
+    );
+
+    let scopes: Vec<Labeled<InstallScope>> = InstallScope::ALL
+        .map(|value| Labeled {
+            value,
+            label: state.tr(value.label_id()),
+        })
+        .to_vec();
+    let scope = Labeled::pick(&scopes, state.install_scope);
+    let scope = pick_list(scopes, scope, |o| Message::InstallScopeChanged(o.value))
+        .text_size(13)
+        .width(Length::Fixed(180.0));
+
+    let install = if state.updating {
+        styled_button_accent(state.tr(busy_label(state)), false, Message::InstallSelected)
+    } else {
+        styled_button_accent(
+            state.tr("toolbar-install-selected"),
+            !state.discover_selected.is_empty(),
+            Message::InstallSelected,
+        )
+    };
+
+    row![
+        search,
+        scope,
+        install,
+        styled_button(state.tr("toolbar-settings"), !state.updating, Message::OpenSettings),
+        styled_button(state.tr("toolbar-shortcuts"), true, Message::ToggleHelp),
+    ]
+}
+
+/// Label of the disabled action button while a batch runs.
+fn busy_label(state: &AppState) -> &'static str {
+    match state.batch_action {
+        BatchAction::Update => "toolbar-updating",
+        BatchAction::Uninstall => "toolbar-uninstalling",
+        BatchAction::Install => "toolbar-installing",
+    }
+}
+
+/// Identifies the search input so keyboard shortcuts can focus it.
+pub fn search_input_id() -> text_input::Id {
+    text_input::Id::new("search")
 }
 
 fn build_search_bar(state: &AppState) -> Element<'_, Message> {
-    let input = text_input("Filter apps by name or ID...", &state.search_query)
+    let p = state.palette();
+    if state.tab == Tab::Discover {
+        // Searches the sources on Enter rather than filtering as you type.
+        let input = text_input(&state.tr("discover-search-placeholder"), &state.discover_query)
+            .id(search_input_id())
+            .on_input(Message::DiscoverQueryChanged)
+            .on_submit(Message::SearchPackages)
+            .size(14)
+            .padding(10);
+        return container(input)
+            .padding([8, 24])
+            .width(Length::Fill)
+            .style(move |_| container::Style {
+                background: Some(iced::Background::Color(p.surface)),
+                ..Default::default()
+            })
+            .into();
+    }
+
+    if state.tab == Tab::Sources {
+        return build_add_source_bar(state);
+    }
+
+    let placeholder = if state.tab == Tab::Installed {
+        state.tr("inventory-search-placeholder")
+    } else {
+        state.tr("search-placeholder")
+    };
+    let input = text_input(&placeholder, &state.search_query)
+        .id(search_input_id())
         .on_input(Message::SearchChanged)
         .size(14)
         .padding(10);
 
-    container(input)
+    if state.tab == Tab::Installed {
+        let unmanaged = checkbox(state.tr("inventory-unmanaged-only"), state.unmanaged_only)
+            .on_toggle(Message::UnmanagedOnlyToggled)
+            .size(16)
+            .text_size(13);
+        return container(row![input, unmanaged].spacing(12).align_y(Alignment::Center))
+            .padding([8, 24])
+            .width(Length::Fill)
+            .style(move |_| container::Style {
+                background: Some(iced::Background::Color(p.surface)),
+                ..Default::default()
+            })
+            .into();
+    }
+
+    let fuzzy = checkbox(state.tr("search-fuzzy"), state.settings.fuzzy_search)
+        .on_toggle(Message::FuzzySearchToggled)
+        .size(16)
+        .text_size(13);
+
+    let grouped = checkbox(state.tr("search-group-by-source"), state.settings.group_by_source)
+        .on_toggle(Message::GroupBySourceToggled)
+        .size(16)
+        .text_size(13);
+
+    let mut content = column![row![input, fuzzy, grouped]
+        .spacing(12)
+        .align_y(Alignment::Center)]
+    .spacing(4);
+    if let Err(e) = parse_query(&state.search_query) {
+        content = content.push(text(state.tr_error(&e)).size(12).color(p.warning));
+    }
+
+    container(content)
         .padding([8, 24])
         .width(Length::Fill)
-        .style(|_| container::Style {
-            background: Some(iced::Background::Color(SURFACE)),
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.surface)),
             ..Default::default()
         })
         .into()
 }
 
 fn build_status_bar(state: &AppState) -> Element<'_, Message> {
-    let visible_count = state.visible_indices().len();
-    let total_count = state.apps.len();
+    let p = state.palette();
+    let (visible_count, total_count) = match state.tab {
+        Tab::Updates => (state.visible_indices().len(), state.apps.len()),
+        Tab::Installed => (state.visible_inventory().len(), state.inventory.len()),
+        Tab::Discover => (state.discover_results.len(), state.discover_results.len()),
+        Tab::Sources => (state.sources.len(), state.sources.len()),
+    };
 
-    let filter_info = if state.search_query.is_empty() {
+    let filter_info = if visible_count == total_count {
         String::new()
     } else {
-        format!(" (showing {visible_count} of {total_count})")
+        let showing = state.tr_args(
+            "status-showing",
+            &[("visible", visible_count.into()), ("total", total_count.into())],
+        );
+        format!(" ({showing})")
     };
 
+    let schedule_info = state
+        .next_scheduled_run()
+        .map(|next| text(next).size(13).color(p.text_muted));
+
     container(
-        text(format!("{}{filter_info}", state.status_message))
-            .size(13)
-            .color(TEXT_MUTED),
+        row![
+            text(format!("{}{filter_info}", state.status_message))
+                .size(13)
+                .color(p.text_muted)
+                .width(Length::Fill),
+        ]
+        .push_maybe(schedule_info),
     )
     .padding([10, 24])
     .width(Length::Fill)
-    .style(|_| container::Style {
-        background: Some(iced::Background::Color(HEADER_BG)),
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(p.header_bg)),
         ..Default::default()
     })
     .into()
@@ -176,12 +437,22 @@ fn build_status_bar(state: &AppState) -> Element<'_, Message> {
 
 // ── App list ─────────────────────────────────────────────────────────
 
+/// Width of the per-row action column.
+const ACTION_WIDTH: f32 = 110.0;
+
+/// Identifies the app list scrollable so its position can be restored.
+pub fn app_list_id() -> scrollable::Id {
+    scrollable::Id::new("app-list")
+}
+
 fn build_app_list(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
     let mut list = Column::new().spacing(0).width(Length::Fill);
+    let mut header = None;
 
     if state.loading {
         list = list.push(
-            container(text("Loading...").size(16).color(TEXT_MUTED))
+            container(text(state.tr("list-loading")).size(16).color(p.text_muted))
                 .padding(40)
                 .center_x(Length::Fill),
         );
@@ -189,12 +460,12 @@ fn build_app_list(state: &AppState) -> Element<'_, Message> {
         list = list.push(
             container(
                 column![
-                    text("All apps are up to date!")
+                    text(state.tr("list-up-to-date"))
                         .size(18)
-                        .color(SUCCESS_CLR),
-                    text("Click Refresh to check again.")
+                        .color(p.success),
+                    text(state.tr("list-check-again"))
                         .size(13)
-                        .color(TEXT_MUTED),
+                        .color(p.text_muted),
                 ]
                 .spacing(8)
                 .align_x(Alignment::Center),
@@ -203,61 +474,641 @@ fn build_app_list(state: &AppState) -> Element<'_, Message> {
             .center_x(Length::Fill),
         );
     } else {
-        list = list.push(build_list_header());
+        header = Some(build_list_header(state));
 
-        let visible = state.visible_indices();
-        if visible.is_empty() {
+        let entries = list_entries(state);
+        if entries.is_empty() {
             list = list.push(
                 container(
-                    text("No apps match your filter.")
+                    text(state.tr("list-no-match"))
                         .size(14)
-                        .color(TEXT_MUTED),
+                        .color(p.text_muted),
                 )
                 .padding(20)
                 .center_x(Length::Fill),
             );
         } else {
-            for (row_num, &idx) in visible.iter().enumerate() {
-                let bg = if row_num % 2 == 0 {
-                    ROW_NORMAL
-                } else {
-                    ROW_ALT
-                };
-                list = list.push(build_app_row(&state.apps[idx], idx, state.updating, bg));
+            // Only build the rows inside the viewport; spacers stand in for
+            // the rest so the scrollbar still reflects the full list.
+            let heights: Vec<f32> = entries.iter().map(ListEntry::height).collect();
+            let window = visible_window(&heights, state.list_scroll, state.list_viewport_height);
+            let now = unix_now();
+
+            list = list.push(Space::with_height(window.before));
+            for entry in entries.into_iter().take(window.end).skip(window.start) {
+                list = list.push(match entry {
+                    ListEntry::Group {
+                        source,
+                        rows,
+                        collapsed,
+                    } => build_group_header(state, &source, &rows, collapsed, now),
+                    ListEntry::Row {
+                        index,
+                        matched,
+                        stripe,
+                    } => {
+                        let bg = if stripe { p.row_alt } else { p.row_normal };
+                        build_app_row(state, index, matched, now, bg)
+                    }
+                });
             }
+            list = list.push(Space::with_height(window.after));
         }
     }
 
-    scrollable(list)
+    let list = scrollable(list)
+        .id(app_list_id())
+        .on_scroll(Message::ListScrolled)
         .height(Length::Fill)
-        .width(Length::Fill)
-        .into()
+        .width(Length::Fill);
+
+    column![].push_maybe(header).push(list).into()
+}
+
+/// One fixed-height line of the app list.
+enum ListEntry {
+    /// Section header of a source, with the app indices it contains.
+    Group {
+        source: String,
+        rows: Vec<usize>,
+        collapsed: bool,
+    },
+    /// An app row; `stripe` selects the alternate background.
+    Row {
+        index: usize,
+        matched: Option<FuzzyMatch>,
+        stripe: bool,
+    },
+}
+
+impl ListEntry {
+    fn height(&self) -> f32 {
+        match self {
+            Self::Group { .. } => GROUP_HEADER_HEIGHT,
+            Self::Row { .. } => ROW_HEIGHT,
+        }
+    }
 }
 
-fn build_list_header() -> Element<'static, Message> {
+/// Flattens the visible rows, and section headers when grouping by source,
+/// into display order.
+fn list_entries(state: &AppState) -> Vec<ListEntry> {
+    let rows = |rows: Vec<VisibleRow>| {
+        rows.into_iter()
+            .enumerate()
+            .map(|(row_num, (index, matched))| ListEntry::Row {
+                index,
+                matched,
+                stripe: row_num % 2 == 1,
+            })
+    };
+
+    if !state.settings.group_by_source {
+        return rows(state.visible_matches()).collect();
+    }
+
+    let mut entries = Vec::new();
+    for (source, group) in state.source_groups() {
+        let collapsed = state.collapsed_sources.contains(&source);
+        entries.push(ListEntry::Group {
+            rows: group.iter().map(|(index, _)| *index).collect(),
+            source,
+            collapsed,
+        });
+        if !collapsed {
+            entries.extend(rows(group));
+        }
+    }
+    entries
+}
+
+fn build_list_header(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let column = |id: &str, col: SortColumn, portion: u16| {
+        sort_header(p, state.tr(id), col, state.sort, Length::FillPortion(portion))
+    };
+
     container(
         row![
             text("").width(Length::Fixed(40.0)),
-            text("Application")
+            column("column-application", SortColumn::Name, 4),
+            column("column-id", SortColumn::Id, 4),
+            column("column-installed", SortColumn::Installed, 2),
+            column("column-available", SortColumn::Available, 2),
+            column("column-source", SortColumn::Source, 1),
+            text("").width(Length::Fixed(ACTION_WIDTH)),
+        ]
+        .spacing(8)
+        .padding([0, 8])
+        .align_y(Alignment::Center),
+    )
+    .padding([8, 16])
+    .width(Length::Fill)
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(p.header_bg)),
+        border: iced::Border {
+            color: p.border,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        ..Default::default()
+    })
+    .into()
+}
+
+/// Section header for one source: select-all checkbox, collapse toggle and
+/// selected/total count.
+fn build_group_header<'a>(
+    state: &'a AppState,
+    source: &str,
+    rows: &[usize],
+    collapsed: bool,
+    now: u64,
+) -> Element<'a, Message> {
+    let p = state.palette();
+    let items = || rows.iter().map(|idx| &state.apps[*idx]);
+    let selected = items().filter(|item| item.selected).count();
+    let all_selected = items()
+        .filter(|item| !item.is_held(now))
+        .all(|item| item.selected)
+        && selected > 0;
+
+    let owned = source.to_string();
+    let cb = if state.updating {
+        checkbox("", all_selected)
+    } else {
+        checkbox("", all_selected).on_toggle(move |on| Message::SelectSource(owned.clone(), on))
+    };
+
+    let arrow = if collapsed { "\u{25B6}" } else { "\u{25BC}" };
+    let label = if source.is_empty() {
+        state.tr("group-other")
+    } else {
+        source_text(state, source)
+    };
+    let toggle = button(
+        row![
+            text(arrow).size(10).color(p.text_muted),
+            text(label).size(14).color(p.text).font(BOLD),
+            text(state.tr_args(
+                "group-selected",
+                &[("selected", selected.into()), ("total", rows.len().into())],
+            ))
                 .size(12)
-                .color(TEXT_MUTED)
-                .font(BOLD)
+                .color(p.text_muted),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+    )
+    .on_press(Message::ToggleSourceCollapsed(source.to_string()))
+    .padding(0)
+    .style(|_, _| button::Style::default());
+
+    container(
+        row![container(cb).width(Length::Fixed(40.0)), toggle]
+            .spacing(8)
+            .padding([0, 8])
+            .align_y(Alignment::Center),
+    )
+    .padding([0, 16])
+    .center_y(Length::Fixed(GROUP_HEADER_HEIGHT))
+    .width(Length::Fill)
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(p.surface_light)),
+        ..Default::default()
+    })
+    .into()
+}
+
+/// Clickable column label showing the sort direction when active.
+fn sort_header(
+    p: &Palette,
+    label: String,
+    column: SortColumn,
+    sort: Option<SortOrder>,
+    width: Length,
+) -> Element<'static, Message> {
+    let (label, color) = match sort {
+        Some(order) if order.column == column => {
+            let arrow = if order.ascending { "\u{25B2}" } else { "\u{25BC}" };
+            (format!("{label} {arrow}"), p.text_soft)
+        }
+        _ => (label, p.text_muted),
+    };
+
+    button(text(label).size(12).color(color).font(BOLD))
+        .on_press(Message::SortBy(column))
+        .padding(0)
+        .style(|_, _| button::Style::default())
+        .width(width)
+        .into()
+}
+
+fn build_app_row(
+    state: &AppState,
+    index: usize,
+    matched: Option<FuzzyMatch>,
+    now: u64,
+    bg: Color,
+) -> Element<'_, Message> {
+    let p = state.palette();
+    let item = &state.apps[index];
+    let at_cursor = state.cursor.as_ref() == Some(&item.app.id);
+    let held = item.is_held(now);
+    let cb = if state.updating || held {
+        checkbox("", item.selected)
+    } else if state.modifiers.shift() {
+        checkbox("", item.selected).on_toggle(move |_| Message::SelectRange(index))
+    } else {
+        checkbox("", item.selected).on_toggle(move |_| Message::ToggleApp(index))
+    };
+
+    let available: Element<'_, Message> = match item.held_until {
+        Some(until) if held => column![
+            text(&item.app.available).size(13).color(p.text_muted),
+            text(state.tr_args(
+                "row-available-in",
+                &[("time", format_countdown(&state.catalog, until - now).into())],
+            ))
+                .size(11)
+                .color(p.warning),
+        ]
+        .spacing(2)
+        .width(Length::FillPortion(2))
+        .into(),
+        _ => text(&item.app.available)
+            .size(13)
+            .color(p.accent)
+            .width(Length::FillPortion(2))
+            .into(),
+    };
+
+    let (name_hits, id_hits) = matched.map(|m| (m.name, m.id)).unwrap_or_default();
+
+    let app = &item.app;
+    let name: Element<'_, Message> = if is_unnamed_store_app(&app.name, &app.id, &app.source) {
+        text(app_name(state, &app.name, &app.id, &app.source)).size(14).color(p.text).into()
+    } else {
+        highlighted(p, &app.name, &name_hits, 14, p.text)
+    };
+    let mut name = row![name]
+        .spacing(8)
+        .align_y(Alignment::Center)
+        .width(Length::FillPortion(4));
+    if item.is_new {
+        name = name.push(result_badge(p, &state.tr("row-new"), p.accent));
+    }
+    if let Some(result) = state.row_results.get(&item.app.id) {
+        let (badge, _, color) = format_result_row(p, result);
+        name = name.push(result_badge(p, &badge, color));
+    }
+    let is_new = item.is_new;
+
+    let action: Element<'_, Message> = if state.updating_id() == Some(item.app.id.as_str()) {
+        text(state.tr("row-updating")).size(12).color(p.info).into()
+    } else {
+        let enabled = !state.updating && !state.loading && !held;
+        button(text(state.tr("row-update")).size(12))
+            .padding([4, 10])
+            .on_press_maybe(enabled.then_some(Message::UpdateNow(index)))
+            .style(toolbar_button_style)
+            .into()
+    };
+
+    container(
+        row![
+            container(cb).width(Length::Fixed(40.0)),
+            name,
+            container(highlighted(p, &item.app.id, &id_hits, 13, p.text_muted))
                 .width(Length::FillPortion(4)),
-            text("ID")
+            text(&item.app.version)
+                .size(13)
+                .width(Length::FillPortion(2)),
+            available,
+            text(&item.app.source)
                 .size(12)
-                .color(TEXT_MUTED)
-                .font(BOLD)
+                .color(p.text_muted)
+                .width(Length::FillPortion(1)),
+            container(action).width(Length::Fixed(ACTION_WIDTH)),
+        ]
+        .spacing(8)
+        .padding([0, 8])
+        .align_y(Alignment::Center),
+    )
+    .padding([0, 16])
+    .center_y(Length::Fixed(ROW_HEIGHT))
+    .width(Length::Fill)
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(bg)),
+        border: if at_cursor {
+            iced::Border {
+                color: p.accent,
+                width: 2.0,
+                radius: 0.0.into(),
+            }
+        } else if is_new {
+            iced::Border {
+                color: Color { a: 0.6, ..p.accent },
+                width: 1.0,
+                radius: 0.0.into(),
+            }
+        } else {
+            iced::Border::default()
+        },
+        ..Default::default()
+    })
+    .into()
+}
+
+/// Renders `content` with the characters at `positions` (as returned by
+/// the fuzzy matcher) emphasised.
+fn highlighted<'a>(
+    p: &Palette,
+    content: &str,
+    positions: &[usize],
+    size: u16,
+    color: Color,
+) -> Element<'a, Message> {
+    if positions.is_empty() {
+        return text(content.to_string()).size(size).color(color).into();
+    }
+
+    let mut spans: Vec<Span<'a, Message>> = Vec::new();
+    let mut run = String::new();
+    let mut run_hit = false;
+    for (i, c) in content.chars().enumerate() {
+        let hit = positions.contains(&i);
+        if hit != run_hit && !run.is_empty() {
+            spans.push(hit_span(p, std::mem::take(&mut run), run_hit, color));
+        }
+        run_hit = hit;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        spans.push(hit_span(p, run, run_hit, color));
+    }
+    rich_text(spans).size(size).into()
+}
+
+fn hit_span<'a>(p: &Palette, content: String, hit: bool, color: Color) -> Span<'a, Message> {
+    if hit {
+        span(content).color(p.accent).font(BOLD)
+    } else {
+        span(content).color(color)
+    }
+}
+
+// ── Inventory ────────────────────────────────────────────────────────
+
+/// Identifies the inventory scrollable.
+fn inventory_list_id() -> scrollable::Id {
+    scrollable::Id::new("inventory")
+}
+
+fn build_inventory_list(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let mut list = Column::new().spacing(0).width(Length::Fill);
+    let mut header = None;
+
+    let visible = state.visible_inventory();
+    if state.inventory_loading && state.inventory.is_empty() {
+        list = list.push(
+            container(text(state.tr("list-loading")).size(16).color(p.text_muted))
+                .padding(40)
+                .center_x(Length::Fill),
+        );
+    } else if visible.is_empty() {
+        let message = if state.inventory.is_empty() {
+            state.tr("inventory-empty")
+        } else {
+            state.tr("list-no-match")
+        };
+        list = list.push(
+            container(text(message).size(14).color(p.text_muted))
+                .padding(20)
+                .center_x(Length::Fill),
+        );
+    } else {
+        header = Some(build_inventory_header(state));
+
+        let heights = vec![ROW_HEIGHT; visible.len()];
+        let window = visible_window(
+            &heights,
+            state.inventory_scroll,
+            state.inventory_viewport_height,
+        );
+        list = list.push(Space::with_height(window.before));
+        for (row_num, &index) in visible.iter().enumerate().take(window.end).skip(window.start) {
+            let bg = if row_num % 2 == 1 { p.row_alt } else { p.row_normal };
+            list = list.push(build_inventory_row(state, index, bg));
+        }
+        list = list.push(Space::with_height(window.after));
+    }
+
+    let list = scrollable(list)
+        .id(inventory_list_id())
+        .on_scroll(Message::InventoryScrolled)
+        .height(Length::Fill)
+        .width(Length::Fill);
+
+    column![].push_maybe(header).push(list).into()
+}
+
+fn build_inventory_header(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let column = |id: &str, portion: u16| {
+        text(state.tr(id))
+            .size(12)
+            .color(p.text_muted)
+            .font(BOLD)
+            .width(Length::FillPortion(portion))
+    };
+
+    container(
+        row![
+            text("").width(Length::Fixed(40.0)),
+            column("column-application", 4),
+            column("column-id", 4),
+            column("column-installed", 2),
+            column("column-available", 2),
+            column("column-source", 1),
+        ]
+        .spacing(8)
+        .padding([0, 8])
+        .align_y(Alignment::Center),
+    )
+    .padding([8, 16])
+    .width(Length::Fill)
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(p.header_bg)),
+        border: iced::Border {
+            color: p.border,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        ..Default::default()
+    })
+    .into()
+}
+
+/// One inventory row; packages winget can't manage get an "unmanaged" badge
+/// in place of their source.
+fn build_inventory_row(state: &AppState, index: usize, bg: Color) -> Element<'_, Message> {
+    let p = state.palette();
+    let app = &state.inventory[index];
+    let selected = state.inventory_selected.contains(&app.id);
+    let cb = if state.updating {
+        checkbox("", selected)
+    } else {
+        checkbox("", selected).on_toggle(move |_| Message::ToggleInventoryApp(index))
+    };
+
+    let source: Element<'_, Message> = if app.is_managed() {
+        text(&app.source).size(12).color(p.text_muted).into()
+    } else {
+        result_badge(p, &state.tr("inventory-unmanaged"), p.warning)
+    };
+
+    container(
+        row![
+            container(cb).width(Length::Fixed(40.0)),
+            text(app_name(state, &app.name, &app.id, &app.source))
+                .size(14)
+                .color(p.text)
                 .width(Length::FillPortion(4)),
-            text("Installed")
-                .size(12)
-                .color(TEXT_MUTED)
-                .font(BOLD)
+            text(&app.id)
+                .size(13)
+                .color(p.text_muted)
+                .width(Length::FillPortion(4)),
+            text(&app.version).size(13).width(Length::FillPortion(2)),
+            text(&app.available)
+                .size(13)
+                .color(p.accent)
                 .width(Length::FillPortion(2)),
-            text("Available")
+            container(source).width(Length::FillPortion(1)),
+        ]
+        .spacing(8)
+        .padding([0, 8])
+        .align_y(Alignment::Center),
+    )
+    .padding([0, 16])
+    .center_y(Length::Fixed(ROW_HEIGHT))
+    .width(Length::Fill)
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(bg)),
+        ..Default::default()
+    })
+    .into()
+}
+
+// ── Sources ──────────────────────────────────────────────────────────
+
+/// Form for adding a source, with the outcome of the last source command
+/// (including any agreements winget asked to accept) below it.
+fn build_add_source_bar(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let input = |placeholder: String, value: &str, on_input: fn(String) -> Message| {
+        text_input(&placeholder, value)
+            .on_input(on_input)
+            .on_submit(Message::AddSource)
+            .size(14)
+            .padding(10)
+    };
+    let form = row![
+        input(
+            state.tr("source-name-placeholder"),
+            &state.source_name_input,
+            Message::SourceNameChanged
+        )
+        .id(search_input_id())
+        .width(Length::FillPortion(2)),
+        input(
+            state.tr("source-argument-placeholder"),
+            &state.source_argument_input,
+            Message::SourceArgumentChanged
+        )
+        .width(Length::FillPortion(4)),
+        input(
+            state.tr("source-type-placeholder"),
+            &state.source_type_input,
+            Message::SourceTypeChanged
+        )
+        .width(Length::FillPortion(2)),
+        checkbox(state.tr("source-accept-agreements"), state.accept_source_agreements)
+            .on_toggle(Message::AcceptSourceAgreementsToggled)
+            .size(16)
+            .text_size(13),
+        styled_button_accent(state.tr("source-add"), !state.source_busy, Message::AddSource),
+    ]
+    .spacing(12)
+    .align_y(Alignment::Center);
+
+    let result = state.source_result.as_deref().map(|result| {
+        let (label, detail, color) = format_result_row(p, result);
+        row![result_badge(p, &label, color), text(detail).size(13).color(p.text_soft)]
+            .spacing(8)
+            .align_y(Alignment::Center)
+    });
+
+    container(column![form].push_maybe(result).spacing(8))
+        .padding([8, 24])
+        .width(Length::Fill)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.surface)),
+            ..Default::default()
+        })
+        .into()
+}
+
+fn build_sources_list(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let mut list = Column::new().spacing(0).width(Length::Fill);
+    let mut header = None;
+
+    if state.sources_loading && state.sources.is_empty() {
+        list = list.push(
+            container(text(state.tr("list-loading")).size(16).color(p.text_muted))
+                .padding(40)
+                .center_x(Length::Fill),
+        );
+    } else if state.sources.is_empty() {
+        list = list.push(
+            container(text(state.tr("sources-empty")).size(14).color(p.text_muted))
+                .padding(20)
+                .center_x(Length::Fill),
+        );
+    } else {
+        header = Some(build_sources_header(state));
+        for (index, source) in state.sources.iter().enumerate() {
+            let bg = if index % 2 == 1 { p.row_alt } else { p.row_normal };
+            list = list.push(build_source_row(state, source, bg));
+        }
+    }
+
+    let list = scrollable(list).height(Length::Fill).width(Length::Fill);
+    column![].push_maybe(header).push(list).into()
+}
+
+fn build_sources_header(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let column = |id: &str, portion: u16| {
+        text(state.tr(id))
+            .size(12)
+            .color(p.text_muted)
+            .font(BOLD)
+            .width(Length::FillPortion(portion))
+    };
+
+    container(
+        row![
+            text(state.tr("column-enabled"))
                 .size(12)
-                .color(TEXT_MUTED)
+                .color(p.text_muted)
                 .font(BOLD)
-                .width(Length::FillPortion(2)),
+                .width(Length::Fixed(70.0)),
+            column("column-source", 2),
+            column("column-argument", 5),
+            text("").width(Length::Fixed(SOURCE_ACTIONS_WIDTH)),
         ]
         .spacing(8)
         .padding([0, 8])
@@ -265,10 +1116,154 @@ fn build_list_header() -> Element<'static, Message> {
     )
     .padding([8, 16])
     .width(Length::Fill)
-    .style(|_| container::Style {
-        background: Some(iced::Background::Color(HEADER_BG)),
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(p.header_bg)),
+        border: iced::Border {
+            color: p.border,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        ..Default::default()
+    })
+    .into()
+}
+
+/// Width of the per-source Update / Reset / Remove buttons.
+const SOURCE_ACTIONS_WIDTH: f32 = 260.0;
+
+/// One source: its enable toggle, an "explicit" badge for sources winget
+/// only uses when named, and per-source actions.
+fn build_source_row<'a>(state: &'a AppState, source: &'a SourceInfo, bg: Color) -> Element<'a, Message> {
+    let p = state.palette();
+    let enabled = !state.settings.disabled_sources.contains(&source.name);
+    let name = source.name.clone();
+    let toggle = checkbox("", enabled)
+        .on_toggle(move |enabled| Message::SourceEnabledToggled(name.clone(), enabled));
+
+    let mut name = row![text(&source.name).size(14).color(p.text)]
+        .spacing(8)
+        .align_y(Alignment::Center);
+    if source.explicit {
+        name = name.push(result_badge(p, &state.tr("source-explicit"), p.info));
+    }
+
+    let action = |id: &str, action: SourceAction| {
+        styled_button(state.tr(id), !state.source_busy, Message::RunSourceAction(action))
+    };
+    let actions = row![
+        action("source-update", SourceAction::Update(Some(source.name.clone()))),
+        action("source-reset", SourceAction::Reset(Some(source.name.clone()))),
+        action("source-remove", SourceAction::Remove(source.name.clone())),
+    ]
+    .spacing(6);
+
+    container(
+        row![
+            container(toggle).width(Length::Fixed(70.0)),
+            container(name).width(Length::FillPortion(2)),
+            text(&source.argument)
+                .size(13)
+                .color(p.text_muted)
+                .width(Length::FillPortion(5)),
+            container(actions).width(Length::Fixed(SOURCE_ACTIONS_WIDTH)),
+        ]
+        .spacing(8)
+        .padding([0, 8])
+        .align_y(Alignment::Center),
+    )
+    .padding([0, 16])
+    .center_y(Length::Fixed(ROW_HEIGHT + 8.0))
+    .width(Length::Fill)
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(bg)),
+        ..Default::default()
+    })
+    .into()
+}
+
+// ── Discover ─────────────────────────────────────────────────────────
+
+/// Width of the per-row version input.
+const VERSION_INPUT_WIDTH: f32 = 120.0;
+
+/// Identifies the search results scrollable.
+fn discover_list_id() -> scrollable::Id {
+    scrollable::Id::new("discover")
+}
+
+fn build_discover_list(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let mut list = Column::new().spacing(0).width(Length::Fill);
+    let mut header = None;
+
+    if state.discover_loading {
+        list = list.push(
+            container(text(state.tr("list-loading")).size(16).color(p.text_muted))
+                .padding(40)
+                .center_x(Length::Fill),
+        );
+    } else if state.discover_results.is_empty() {
+        list = list.push(
+            container(text(state.tr("discover-empty")).size(14).color(p.text_muted))
+                .padding(20)
+                .center_x(Length::Fill),
+        );
+    } else {
+        header = Some(build_discover_header(state));
+
+        let heights = vec![ROW_HEIGHT; state.discover_results.len()];
+        let window = visible_window(
+            &heights,
+            state.discover_scroll,
+            state.discover_viewport_height,
+        );
+        list = list.push(Space::with_height(window.before));
+        for index in window.start..window.end {
+            let bg = if index % 2 == 1 { p.row_alt } else { p.row_normal };
+            list = list.push(build_discover_row(state, index, bg));
+        }
+        list = list.push(Space::with_height(window.after));
+    }
+
+    let list = scrollable(list)
+        .id(discover_list_id())
+        .on_scroll(Message::DiscoverScrolled)
+        .height(Length::Fill)
+        .width(Length::Fill);
+
+    column![].push_maybe(header).push(list).into()
+}
+
+fn build_discover_header(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let column = |id: &str, width: Length| {
+        text(state.tr(id))
+            .size(12)
+            .color(p.text_muted)
+            .font(BOLD)
+            .width(width)
+    };
+
+    container(
+        row![
+            text("").width(Length::Fixed(40.0)),
+            column("column-application", Length::FillPortion(4)),
+            column("column-id", Length::FillPortion(4)),
+            column("column-latest", Length::FillPortion(2)),
+            column("column-match", Length::FillPortion(2)),
+            column("column-source", Length::FillPortion(1)),
+            column("column-install-version", Length::Fixed(VERSION_INPUT_WIDTH)),
+        ]
+        .spacing(8)
+        .padding([0, 8])
+        .align_y(Alignment::Center),
+    )
+    .padding([8, 16])
+    .width(Length::Fill)
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(p.header_bg)),
         border: iced::Border {
-            color: Color::from_rgb(0.2, 0.2, 0.25),
+            color: p.border,
             width: 1.0,
             radius: 0.0.into(),
         },
@@ -277,41 +1272,62 @@ fn build_list_header() -> Element<'static, Message> {
     .into()
 }
 
-fn build_app_row(
-    item: &crate::models::AppItem,
-    index: usize,
-    updating: bool,
-    bg: Color,
-) -> Element<'_, Message> {
-    let cb = if updating {
-        checkbox("", item.selected)
+/// One search result with its selection checkbox and the version to
+/// install (blank for the latest).
+fn build_discover_row(state: &AppState, index: usize, bg: Color) -> Element<'_, Message> {
+    let p = state.palette();
+    let result = &state.discover_results[index];
+    let selected = state.discover_selected.contains(&result.id);
+    let cb = if state.updating {
+        checkbox("", selected)
     } else {
-        checkbox("", item.selected).on_toggle(move |_| Message::ToggleApp(index))
+        checkbox("", selected).on_toggle(move |_| Message::ToggleSearchResult(index))
     };
 
+    let typed = state
+        .install_versions
+        .get(&result.id)
+        .map_or("", String::as_str);
+    let mut version = text_input(&state.tr("discover-version-latest"), typed)
+        .size(12)
+        .padding(4)
+        .width(Length::Fixed(VERSION_INPUT_WIDTH));
+    if !state.updating {
+        let id = result.id.clone();
+        version = version.on_input(move |v| Message::InstallVersionChanged(id.clone(), v));
+    }
+
     container(
         row![
             container(cb).width(Length::Fixed(40.0)),
-            text(&item.app.name)
+            text(app_name(state, &result.name, &result.id, &result.source))
                 .size(14)
+                .color(p.text)
                 .width(Length::FillPortion(4)),
-            text(&item.app.id)
+            text(&result.id)
                 .size(13)
-                .color(TEXT_MUTED)
+                .color(p.text_muted)
                 .width(Length::FillPortion(4)),
-            text(&item.app.version)
+            text(&result.version)
                 .size(13)
+                .color(p.accent)
                 .width(Length::FillPortion(2)),
-            text(&item.app.available)
-                .size(13)
-                .color(ACCENT)
+            text(&result.matched)
+                .size(12)
+                .color(p.text_muted)
                 .width(Length::FillPortion(2)),
+            text(&result.source)
+                .size(12)
+                .color(p.text_muted)
+                .width(Length::FillPortion(1)),
+            version,
         ]
         .spacing(8)
         .padding([0, 8])
         .align_y(Alignment::Center),
     )
-    .padding([6, 16])
+    .padding([0, 16])
+    .center_y(Length::Fixed(ROW_HEIGHT))
     .width(Length::Fill)
     .style(move |_| container::Style {
         background: Some(iced::Background::Color(bg)),
@@ -323,34 +1339,282 @@ fn build_app_row(
 // ── Dialogs ──────────────────────────────────────────────────────────
 
 fn build_confirmation_overlay(state: &AppState) -> Element<'_, Message> {
-    let mut apps_col = Column::new().spacing(4);
-    for (name, id) in &state.pending_updates {
-        apps_col = apps_col.push(
-            text(format!("  {name}  ({id})"))
-                .size(13)
-                .color(Color::from_rgb(0.8, 0.8, 0.85)),
+    confirmation_dialog(
+        state,
+        state.tr("confirm-title"),
+        state.tr_args("confirm-intro", &[("count", state.pending_updates.len().into())]),
+        update_lines(state),
+        state.tr("confirm-note"),
+        (state.tr("confirm-proceed"), Message::ConfirmUpdate),
+        Message::CancelUpdate,
+    )
+}
+
+fn build_uninstall_confirmation_overlay(state: &AppState) -> Element<'_, Message> {
+    confirmation_dialog(
+        state,
+        state.tr("uninstall-confirm-title"),
+        state.tr_args(
+            "uninstall-confirm-intro",
+            &[("count", state.pending_updates.len().into())],
+        ),
+        pending_lines(state),
+        state.tr("uninstall-confirm-note"),
+        (state.tr("uninstall-confirm-proceed"), Message::ConfirmUninstall),
+        Message::CancelUninstall,
+    )
+}
+
+/// Lists each queued package with the version it will get, and the scope.
+fn build_install_confirmation_overlay(state: &AppState) -> Element<'_, Message> {
+    let lines = state
+        .install_requests
+        .iter()
+        .map(|r| {
+            let version = r
+                .version
+                .clone()
+                .unwrap_or_else(|| state.tr("discover-version-latest"));
+            format!("  {}  ({})  {version}", r.name, r.id)
+        })
+        .collect();
+    let scope = state.tr(state.install_scope.label_id());
+
+    confirmation_dialog(
+        state,
+        state.tr("install-confirm-title"),
+        state.tr_args(
+            "install-confirm-intro",
+            &[("count", state.install_requests.len().into())],
+        ),
+        lines,
+        state.tr_args("install-confirm-note", &[("scope", scope.into())]),
+        (state.tr("install-confirm-proceed"), Message::ConfirmInstall),
+        Message::CancelInstall,
+    )
+}
+
+/// Previews an import: what each package of the set would get, with counts.
+fn build_import_preview_overlay(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let count = |pred: fn(&ImportAction) -> bool| {
+        state.import_plan.iter().filter(|item| pred(&item.action)).count()
+    };
+    let installs = count(|a| matches!(a, ImportAction::Install));
+    let upgrades = count(|a| matches!(a, ImportAction::Upgrade { .. }));
+    let skips = count(|a| matches!(a, ImportAction::Skip { .. }));
+
+    let summary = row![
+        result_badge(p, &state.tr_args("import-installs", &[("count", installs.into())]), p.success),
+        result_badge(p, &state.tr_args("import-upgrades", &[("count", upgrades.into())]), p.accent),
+        result_badge(p, &state.tr_args("import-skips", &[("count", skips.into())]), p.text_muted),
+    ]
+    .spacing(8);
+
+    let mut items = Column::new().spacing(4);
+    for item in &state.import_plan {
+        let version = item
+            .version
+            .clone()
+            .unwrap_or_else(|| state.tr("discover-version-latest"));
+        let (action, color) = match &item.action {
+            ImportAction::Install => (state.tr("import-action-install"), p.success),
+            ImportAction::Upgrade { installed } => (
+                state.tr_args("import-action-upgrade", &[("installed", installed.as_str().into())]),
+                p.accent,
+            ),
+            ImportAction::Skip { installed } => (
+                state.tr_args("import-action-skip", &[("installed", installed.as_str().into())]),
+                p.text_muted,
+            ),
+        };
+        items = items.push(
+            row![
+                text(&item.id).size(13).color(p.text_soft).width(Length::FillPortion(3)),
+                text(version).size(13).color(p.text_muted).width(Length::FillPortion(1)),
+                text(action).size(12).color(color).width(Length::FillPortion(2)),
+            ]
+            .spacing(8),
+        );
+    }
+
+    let buttons = row![
+        styled_button_accent(
+            state.tr("import-proceed"),
+            installs + upgrades > 0,
+            Message::ConfirmImport
+        ),
+        styled_button(state.tr("confirm-cancel"), true, Message::CancelImport),
+    ]
+    .spacing(12);
+
+    let dialog = container(
+        column![
+            text(state.tr("import-title")).size(22).color(p.text).font(BOLD),
+            horizontal_rule(1),
+            text(state.tr_args("import-intro", &[("path", state.package_file.as_str().into())]))
+                .size(14),
+            summary,
+            container(scrollable(items)).max_height(280),
+            buttons,
+        ]
+        .spacing(16)
+        .padding(28)
+        .max_width(620),
+    )
+    .style(dialog_style);
+
+    overlay_backdrop(dialog)
+}
+
+/// Lists how the installed packages deviate from the manifest.
+fn build_drift_overlay<'a>(state: &'a AppState, report: &'a [DriftItem]) -> Element<'a, Message> {
+    let p = state.palette();
+    let mut items = Column::new().spacing(4);
+    for item in report {
+        let (detail, color) = match &item.drift {
+            Drift::Missing => (state.tr("drift-missing"), p.success),
+            Drift::Outdated { installed, target } => (
+                state.tr_args(
+                    "drift-outdated",
+                    &[
+                        ("installed", installed.as_str().into()),
+                        ("target", target.as_str().into()),
+                    ],
+                ),
+                p.accent,
+            ),
+            Drift::Forbidden { installed } => (
+                state.tr_args("drift-forbidden", &[("installed", installed.as_str().into())]),
+                p.failure,
+            ),
+            Drift::OverConstrained {
+                installed,
+                available,
+            } => (
+                state.tr_args(
+                    "drift-over-constrained",
+                    &[
+                        ("installed", installed.as_str().into()),
+                        (
+                            "available",
+                            available
+                                .clone()
+                                .unwrap_or_else(|| state.tr("drift-nothing-newer"))
+                                .into(),
+                        ),
+                    ],
+                ),
+                p.warning,
+            ),
+        };
+        items = items.push(
+            row![
+                text(&item.id).size(13).color(p.text_soft).width(Length::FillPortion(3)),
+                text(item.constraint.to_string())
+                    .size(13)
+                    .color(p.text_muted)
+                    .width(Length::FillPortion(1)),
+                text(detail).size(12).color(color).width(Length::FillPortion(3)),
+            ]
+            .spacing(8),
         );
     }
 
-    let note = text("These apps may need to be closed before updating.")
+    let actionable = report
+        .iter()
+        .any(|item| !matches!(item.drift, Drift::OverConstrained { .. }));
+    let intro = if report.is_empty() {
+        state.tr("drift-none")
+    } else {
+        state.tr_args(
+            "drift-intro",
+            &[("path", state.settings.manifest_path.as_str().into())],
+        )
+    };
+
+    let buttons = row![
+        styled_button_accent(state.tr("drift-apply"), actionable, Message::ApplyDrift),
+        styled_button(state.tr("common-close"), true, Message::CloseDrift),
+    ]
+    .spacing(12);
+
+    let dialog = container(
+        column![
+            text(state.tr("drift-title")).size(22).color(p.text).font(BOLD),
+            horizontal_rule(1),
+            text(intro).size(14),
+            container(scrollable(items)).max_height(280),
+            text(state.tr("drift-note")).size(12).color(p.text_muted),
+            buttons,
+        ]
+        .spacing(16)
+        .padding(28)
+        .max_width(620),
+    )
+    .style(dialog_style);
+
+    overlay_backdrop(dialog)
+}
+
+/// The pending packages as `name (id)` lines.
+fn pending_lines(state: &AppState) -> Vec<String> {
+    state
+        .pending_updates
+        .iter()
+        .map(|(name, id)| format!("  {name}  ({id})"))
+        .collect()
+}
+
+/// Lists each queued update, followed by the effective winget command for
+/// packages with upgrade options or an arguments profile, or the reason the
+/// profile keeps the package from being updated.
+fn update_lines(state: &AppState) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (name, id) in &state.pending_updates {
+        lines.push(format!("  {name}  ({id})"));
+        match state.upgrade_request(id) {
+            Ok(request) if !request.options.is_default() || !request.extra_args.is_empty() => {
+                lines.push(format!("      {}", format_command_line(&update_args(&request))));
+            }
+            Ok(_) => {}
+            Err(e) => lines.push(format!("      {}", e.trim_start_matches("FAILURE:"))),
+        }
+    }
+    lines
+}
+
+/// Shared layout of the dialogs that confirm a batch.
+fn confirmation_dialog<'a>(
+    state: &AppState,
+    title: String,
+    intro: String,
+    lines: Vec<String>,
+    note: String,
+    (proceed, on_proceed): (String, Message),
+    on_cancel: Message,
+) -> Element<'a, Message> {
+    let p = state.palette();
+    let mut apps_col = Column::new().spacing(4);
+    for line in lines {
+        apps_col = apps_col.push(text(line).size(13).color(p.text_soft));
+    }
+
+    let note = text(note)
         .size(12)
-        .color(WARNING_CLR);
+        .color(p.warning);
 
     let buttons = row![
-        styled_button_accent("Yes, Proceed", true, Message::ConfirmUpdate),
-        styled_button("Cancel", true, Message::CancelUpdate),
+        styled_button_accent(proceed, true, on_proceed),
+        styled_button(state.tr("confirm-cancel"), true, on_cancel),
     ]
     .spacing(12);
 
     let dialog = container(
         column![
-            text("Confirm Update").size(22).color(Color::WHITE).font(BOLD),
+            text(title).size(22).color(p.text).font(BOLD),
             horizontal_rule(1),
-            text(format!(
-                "The following {} app(s) will be updated:",
-                state.pending_updates.len()
-            ))
-            .size(14),
+            text(intro).size(14),
             container(scrollable(apps_col)).max_height(250),
             note,
             buttons,
@@ -364,39 +1628,269 @@ fn build_confirmation_overlay(state: &AppState) -> Element<'_, Message> {
     overlay_backdrop(dialog)
 }
 
-fn build_results_overlay(state: &AppState) -> Element<'_, Message> {
-    // Tally results
-    let mut ok_count = 0usize;
-    let mut fail_count = 0usize;
-    let mut warn_count = 0usize;
-    for r in &state.update_results {
-        if r.starts_with("SUCCESS:") {
-            ok_count += 1;
-        } else if r.starts_with("FAILURE:") {
-            fail_count += 1;
-        } else {
-            warn_count += 1;
+fn build_details_overlay<'a>(state: &'a AppState, item: &'a AppItem) -> Element<'a, Message> {
+    let p = state.palette();
+    let app = &item.app;
+    let now = unix_now();
+
+    let header = row![
+        text(app_name(state, &app.name, &app.id, &app.source))
+            .size(22)
+            .color(p.text)
+            .font(BOLD)
+            .width(Length::Fill),
+        button(text("X").size(14).font(BOLD))
+            .on_press(Message::CloseDetails)
+            .padding([4, 10])
+            .style(close_button_style),
+    ]
+    .align_y(Alignment::Center);
+
+    let first_seen = state
+        .first_seen
+        .first_seen(&app.id, &app.available)
+        .and_then(to_local)
+        .map_or_else(|| String::from("—"), |t| t.format("%Y-%m-%d %H:%M").to_string());
+    let cooling_off = match item.held_until {
+        Some(until) if item.is_held(now) => {
+            state.tr_args("details-held", &[("time", format_countdown(&state.catalog, until - now).into())])
         }
+        _ => String::from("—"),
+    };
+
+    let mut fields = Column::new().spacing(8);
+    for (label, value) in [
+        ("column-id", app.id.clone()),
+        ("column-installed", app.version.clone()),
+        ("column-available", app.available.clone()),
+        ("column-source", source_text(state, &app.source)),
+        ("details-first-seen", first_seen),
+        ("details-cooling-off", cooling_off),
+        ("details-selected", state.tr(if item.selected { "common-yes" } else { "common-no" })),
+    ] {
+        fields = fields.push(
+            row![
+                text(state.tr(label))
+                    .size(13)
+                    .color(p.text_muted)
+                    .width(Length::Fixed(140.0)),
+                text(value).size(13),
+            ]
+            .spacing(8),
+        );
+    }
+
+    let options = build_package_options(state, &app.id);
+
+    let done_btn = container(styled_button_accent(state.tr("common-close"), true, Message::CloseDetails))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let dialog = container(
+        column![header, horizontal_rule(1), fields, options, done_btn]
+            .spacing(16)
+            .padding(28)
+            .max_width(520),
+    )
+    .style(dialog_style);
+
+    overlay_backdrop(dialog)
+}
+
+/// Scope, architecture, installer type and locale pickers for the upgrade
+/// options remembered for `id`.
+fn build_package_options<'a>(state: &'a AppState, id: &'a str) -> Element<'a, Message> {
+    let p = state.palette();
+    let current = state.settings.package_options_for(id);
+
+    let scopes: Vec<Labeled<InstallScope>> = InstallScope::ALL
+        .map(|value| Labeled {
+            value,
+            label: state.tr(value.label_id()),
+        })
+        .to_vec();
+    let scope = Labeled::pick(&scopes, current.scope);
+    let options = current.clone();
+    let scope = pick_list(scopes, scope, move |o| {
+        options_changed(id, &options, |options| options.scope = o.value)
+    })
+    .text_size(13)
+    .width(Length::Fill);
+
+    let architectures: Vec<Labeled<Architecture>> = Architecture::ALL
+        .map(|value| Labeled {
+            value,
+            label: value.arg().map_or_else(|| state.tr("arch-any"), String::from),
+        })
+        .to_vec();
+    let architecture = Labeled::pick(&architectures, current.architecture);
+    let options = current.clone();
+    let architecture = pick_list(architectures, architecture, move |o| {
+        options_changed(id, &options, |options| options.architecture = o.value)
+    })
+    .text_size(13)
+    .width(Length::Fill);
+
+    let installer_types: Vec<Labeled<String>> = std::iter::once(Labeled {
+        value: String::new(),
+        label: state.tr("installer-type-any"),
+    })
+    .chain(INSTALLER_TYPES.iter().map(|&kind| Labeled {
+        value: kind.to_string(),
+        label: kind.to_string(),
+    }))
+    .collect();
+    let installer_type = Labeled::pick(&installer_types, current.installer_type.clone());
+    let options = current.clone();
+    let installer_type = pick_list(installer_types, installer_type, move |o| {
+        options_changed(id, &options, |options| options.installer_type = o.value)
+    })
+    .text_size(13)
+    .width(Length::Fill);
+
+    let locale = text_input("en-US", &current.locale)
+        .on_input(move |locale| {
+            options_changed(id, &current, |options| options.locale = locale.trim().to_string())
+        })
+        .size(13)
+        .padding(6);
+
+    let mut fields = Column::new().spacing(8);
+    for (label, control) in [
+        ("details-scope", Element::from(scope)),
+        ("details-architecture", architecture.into()),
+        ("details-installer-type", installer_type.into()),
+        ("details-locale", locale.into()),
+    ] {
+        fields = fields.push(
+            row![
+                text(state.tr(label))
+                    .size(13)
+                    .color(p.text_muted)
+                    .width(Length::Fixed(140.0)),
+                control,
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+    }
+
+    for (label, value, on_input) in [
+        (
+            "details-override-args",
+            &state.override_args_input,
+            Message::OverrideArgsChanged as fn(String) -> Message,
+        ),
+        ("details-custom-args", &state.custom_args_input, Message::CustomArgsChanged),
+    ] {
+        fields = fields.push(
+            row![
+                text(state.tr(label))
+                    .size(13)
+                    .color(p.text_muted)
+                    .width(Length::Fixed(140.0)),
+                text_input("/S /LICENSE={version}", value)
+                    .on_input(on_input)
+                    .size(13)
+                    .padding(6),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+        );
+    }
+
+    let mut section = column![
+        text(state.tr("details-options")).size(14).font(BOLD),
+        fields,
+        text(state.tr("details-options-hint"))
+            .size(12)
+            .color(p.text_muted),
+        text(state.tr("details-args-hint"))
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(8);
+    if let Some(err) = &state.args_profile_error {
+        section = section.push(text(err).size(12).color(p.failure));
+    }
+    section.into()
+}
+
+/// Builds the message that stores `current` with one option changed.
+fn options_changed(
+    id: &str,
+    current: &PackageOptions,
+    update: impl FnOnce(&mut PackageOptions),
+) -> Message {
+    let mut options = current.clone();
+    update(&mut options);
+    Message::PackageOptionsChanged(id.to_string(), options)
+}
+
+fn build_help_overlay(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let header = row![
+        text(state.tr("help-title"))
+            .size(22)
+            .color(p.text)
+            .font(BOLD)
+            .width(Length::Fill),
+        button(text("X").size(14).font(BOLD))
+            .on_press(Message::ToggleHelp)
+            .padding([4, 10])
+            .style(close_button_style),
+    ]
+    .align_y(Alignment::Center);
+
+    let mut shortcuts = Column::new().spacing(8);
+    for &(keys, action) in SHORTCUTS {
+        shortcuts = shortcuts.push(
+            row![
+                text(keys)
+                    .size(13)
+                    .font(BOLD)
+                    .color(p.accent)
+                    .width(Length::Fixed(130.0)),
+                text(state.tr(action)).size(13),
+            ]
+            .spacing(8),
+        );
     }
 
+    let dialog = container(
+        column![header, horizontal_rule(1), shortcuts]
+            .spacing(16)
+            .padding(28)
+            .max_width(460),
+    )
+    .style(dialog_style);
+
+    overlay_backdrop(dialog)
+}
+
+fn build_results_overlay(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    // Tally results
+    let (ok_count, fail_count, warn_count) = state.result_counts();
+
     // Summary badges
     let summary = row![
-        result_badge(&format!("{ok_count} succeeded"), SUCCESS_CLR),
-        result_badge(&format!("{fail_count} failed"), FAILURE_CLR),
-        result_badge(&format!("{warn_count} other"), WARNING_CLR),
+        result_badge(p, &state.tr_args("results-succeeded", &[("count", ok_count.into())]), p.success),
+        result_badge(p, &state.tr_args("results-failed", &[("count", fail_count.into())]), p.failure),
+        result_badge(p, &state.tr_args("results-other", &[("count", warn_count.into())]), p.warning),
     ]
     .spacing(8);
 
     // Per-app result rows
     let mut results_col = Column::new().spacing(2);
     for (i, result) in state.update_results.iter().enumerate() {
-        let (icon_txt, label, color) = format_result_row(result);
-        let bg = if i % 2 == 0 { ROW_NORMAL } else { ROW_ALT };
+        let (icon_txt, label, color) = format_result_row(p, result);
+        let bg = if i % 2 == 0 { p.row_normal } else { p.row_alt };
 
         let result_row = container(
             row![
                 container(
-                    text(icon_txt).size(12).color(Color::WHITE).font(BOLD)
+                    text(icon_txt).size(12).color(p.text).font(BOLD)
                 )
                 .padding([2, 8])
                 .style(move |_| container::Style {
@@ -404,7 +1898,7 @@ fn build_results_overlay(state: &AppState) -> Element<'_, Message> {
                     border: iced::Border { radius: 4.0.into(), ..Default::default() },
                     ..Default::default()
                 }),
-                text(label).size(13).color(Color::from_rgb(0.85, 0.85, 0.88)),
+                text(label).size(13).color(p.text_soft),
             ]
             .spacing(10)
             .align_y(Alignment::Center),
@@ -420,10 +1914,15 @@ fn build_results_overlay(state: &AppState) -> Element<'_, Message> {
     }
 
     // Header row with title and close button
+    let title = match state.batch_action {
+        BatchAction::Update => "results-title",
+        BatchAction::Uninstall => "uninstall-results-title",
+        BatchAction::Install => "install-results-title",
+    };
     let header = row![
-        text("Update Results")
+        text(state.tr(title))
             .size(22)
-            .color(Color::WHITE)
+            .color(p.text)
             .font(BOLD)
             .width(Length::Fill),
         button(text("X").size(14).font(BOLD))
@@ -435,7 +1934,7 @@ fn build_results_overlay(state: &AppState) -> Element<'_, Message> {
 
     // "Done" button at the bottom
     let done_btn = container(
-        styled_button_accent("Done", true, Message::CloseResultsDialog),
+        styled_button_accent(state.tr("common-done"), true, Message::CloseResultsDialog),
     )
     .width(Length::Fill)
     .center_x(Length::Fill);
@@ -451,10 +1950,10 @@ fn build_results_overlay(state: &AppState) -> Element<'_, Message> {
                     .width(Length::Fill),
             )
             .width(Length::Fill)
-            .style(|_| container::Style {
-                background: Some(iced::Background::Color(Color::from_rgb(0.11, 0.11, 0.14))),
+            .style(move |_| container::Style {
+                background: Some(iced::Background::Color(p.header_bg)),
                 border: iced::Border {
-                    color: Color::from_rgb(0.22, 0.22, 0.28),
+                    color: p.border,
                     width: 1.0,
                     radius: 8.0.into(),
                 },
@@ -474,10 +1973,264 @@ fn build_results_overlay(state: &AppState) -> Element<'_, Message> {
     overlay_backdrop(dialog)
 }
 
+fn build_settings_overlay(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
+    let header = row![
+        text(state.tr("settings-title"))
+            .size(22)
+            .color(p.text)
+            .font(BOLD)
+            .width(Length::Fill),
+        button(text("X").size(14).font(BOLD))
+            .on_press(Message::CloseSettings)
+            .padding([4, 10])
+            .style(close_button_style),
+    ]
+    .align_y(Alignment::Center);
+
+    let cooldown = column![
+        text(state.tr("settings-cooldown")).size(14),
+        text_input("0", &state.cooldown_input)
+            .on_input(Message::CooldownDaysChanged)
+            .size(14)
+            .padding(8)
+            .width(Length::Fixed(120.0)),
+        text(state.tr("settings-cooldown-hint"))
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(6);
+
+    let mut schedules = column![
+        text(state.tr("settings-schedules")).size(14),
+        text_input("update weekdays 12:00-13:00; refresh daily 08:00-09:00", &state.schedules_input)
+            .on_input(Message::SchedulesChanged)
+            .size(14)
+            .padding(8),
+        text(state.tr("settings-schedules-hint"))
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(6);
+    if let Some(err) = &state.schedules_error {
+        schedules = schedules.push(text(state.tr_error(err)).size(12).color(p.failure));
+    }
+
+    let mut option_rules = column![
+        text(state.tr("settings-option-rules")).size(14),
+        text_input("Contoso.* scope=machine arch=x64; Git.Git type=msi", &state.option_rules_input)
+            .on_input(Message::OptionRulesChanged)
+            .size(14)
+            .padding(8),
+        text(state.tr("settings-option-rules-hint"))
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(6);
+    if let Some(err) = &state.option_rules_error {
+        option_rules = option_rules.push(text(state.tr_error(err)).size(12).color(p.failure));
+    }
+
+    let ignored = column![
+        text(state.tr("settings-ignored")).size(14),
+        text_input("Publisher.App, Other.App", &state.ignored_input)
+            .on_input(Message::IgnoredIdsChanged)
+            .size(14)
+            .padding(8),
+        text(state.tr("settings-ignored-hint"))
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(6);
+
+    let refresh = column![
+        text(state.tr("settings-refresh")).size(14),
+        text_input("0", &state.refresh_interval_input)
+            .on_input(Message::RefreshIntervalChanged)
+            .size(14)
+            .padding(8)
+            .width(Length::Fixed(120.0)),
+        text(state.tr("settings-refresh-hint"))
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(6);
+
+    let notifications = column![
+        text(state.tr("settings-notifications")).size(14),
+        checkbox(state.tr("settings-mute"), state.settings.mute_notifications)
+            .on_toggle(Message::MuteNotificationsToggled)
+            .size(16)
+            .text_size(13),
+        text_input("Publisher.App, Other.App", &state.muted_input)
+            .on_input(Message::MutedIdsChanged)
+            .size(14)
+            .padding(8),
+        text(state.tr("settings-muted-hint"))
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(6);
+
+    let languages: Vec<Labeled<Option<Language>>> = std::iter::once(None)
+        .chain(Language::ALL.map(Some))
+        .map(|value| Labeled {
+            value,
+            label: value.map_or_else(
+                || state.tr("settings-language-system"),
+                |lang| lang.native_name().to_string(),
+            ),
+        })
+        .collect();
+    let language = Labeled::pick(&languages, state.settings.language);
+
+    let themes: Vec<Labeled<ThemeChoice>> = ThemeChoice::ALL
+        .map(|value| Labeled {
+            value,
+            label: state.tr(value.label_id()),
+        })
+        .to_vec();
+    let theme = Labeled::pick(&themes, state.settings.theme);
+
+    let appearance = column![
+        text(state.tr("settings-language")).size(14),
+        pick_list(languages, language, |o| Message::LanguageChanged(o.value))
+            .text_size(13)
+            .width(Length::Fixed(200.0)),
+        text(state.tr("settings-theme")).size(14),
+        pick_list(themes, theme, |o| Message::ThemeChanged(o.value))
+            .text_size(13)
+            .width(Length::Fixed(200.0)),
+        text(state.tr("settings-theme-hint"))
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(6);
+
+    let row_updates = column![
+        text(state.tr("settings-row-updates")).size(14),
+        checkbox(
+            state.tr("settings-skip-row-confirmation"),
+            state.settings.skip_row_update_confirmation,
+        )
+        .on_toggle(Message::SkipRowUpdateConfirmationToggled)
+        .size(16)
+        .text_size(13),
+    ]
+    .spacing(6);
+
+    let manifest = column![
+        text(state.tr("settings-manifest")).size(14),
+        text_input(r"C:\Users\me\desired.toml", &state.settings.manifest_path)
+            .on_input(Message::ManifestPathChanged)
+            .size(14)
+            .padding(8),
+        text(state.tr("settings-manifest-hint"))
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(6);
+
+    let store = column![
+        text(state.tr("settings-store")).size(14),
+        checkbox(
+            state.tr("settings-store-exclude"),
+            state.settings.disabled_sources.iter().any(|s| s == STORE_SOURCE),
+        )
+        .on_toggle(|exclude| Message::SourceEnabledToggled(STORE_SOURCE.into(), !exclude))
+        .size(16)
+        .text_size(13),
+        checkbox(
+            state.tr("settings-store-agreements"),
+            state.settings.accept_store_agreements,
+        )
+        .on_toggle(Message::AcceptStoreAgreementsToggled)
+        .size(16)
+        .text_size(13),
+        text(state.tr("settings-store-hint"))
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(6);
+
+    let done_btn = container(styled_button_accent(state.tr("common-done"), true, Message::CloseSettings))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    // The sections scroll so the dialog still fits small windows.
+    let sections = scrollable(
+        column![
+            appearance,
+            cooldown,
+            refresh,
+            schedules,
+            ignored,
+            row_updates,
+            option_rules,
+            notifications,
+            store,
+            manifest
+        ]
+            .spacing(16)
+            .padding([0, 12]),
+    );
+
+    let dialog = container(
+        column![header, horizontal_rule(1), sections, done_btn]
+            .spacing(16)
+            .padding(28)
+            .max_width(560),
+    )
+    .max_height(640)
+    .style(dialog_style);
+
+    overlay_backdrop(dialog)
+}
+
+/// A pick-list option shown with a translated label.
+#[derive(Debug, Clone, PartialEq)]
+struct Labeled<T> {
+    value: T,
+    label: String,
+}
+
+impl<T: PartialEq + Clone> Labeled<T> {
+    /// Returns the option holding `value`, for use as the selected entry.
+    fn pick(options: &[Self], value: T) -> Option<Self> {
+        options.iter().find(|o| o.value == value).cloned()
+    }
+}
+
+impl<T> fmt::Display for Labeled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// Returns the translated label of a source; only the Microsoft Store has
+/// one, other sources show their configured name.
+fn source_text(state: &AppState, source: &str) -> String {
+    if source == STORE_SOURCE {
+        state.tr("source-msstore")
+    } else {
+        source.to_string()
+    }
+}
+
+/// Returns the name to show for a package, labelling store apps that winget
+/// lists under their bare product ID.
+fn app_name(state: &AppState, name: &str, id: &str, source: &str) -> String {
+    if is_unnamed_store_app(name, id, source) {
+        state.tr_args("store-app-name", &[("id", id.into())])
+    } else {
+        name.to_string()
+    }
+}
+
 /// Small coloured badge for the results summary row.
-fn result_badge<'a>(label: &str, color: Color) -> Element<'a, Message> {
+fn result_badge<'a>(p: &Palette, label: &str, color: Color) -> Element<'a, Message> {
     container(
-        text(label.to_string()).size(12).color(Color::WHITE).font(BOLD),
+        text(label.to_string()).size(12).color(p.text).font(BOLD),
     )
     .padding([3, 10])
     .style(move |_| container::Style {
@@ -496,17 +2249,17 @@ fn result_badge<'a>(label: &str, color: Color) -> Element<'a, Message> {
 }
 
 /// Returns `(badge_text, description, badge_color)` for one result line.
-fn format_result_row(result: &str) -> (String, String, Color) {
+fn format_result_row(p: &Palette, result: &str) -> (String, String, Color) {
     if let Some(rest) = result.strip_prefix("SUCCESS:") {
-        ("OK".into(), rest.to_string(), SUCCESS_CLR)
+        ("OK".into(), rest.to_string(), p.success)
     } else if let Some(rest) = result.strip_prefix("FAILURE:") {
-        ("FAIL".into(), rest.to_string(), FAILURE_CLR)
+        ("FAIL".into(), rest.to_string(), p.failure)
     } else if let Some(rest) = result.strip_prefix("[!]") {
-        ("WARN".into(), rest.trim().to_string(), WARNING_CLR)
+        ("WARN".into(), rest.trim().to_string(), p.warning)
     } else if let Some(rest) = result.strip_prefix("[i]") {
-        ("INFO".into(), rest.trim().to_string(), INFO_CLR)
+        ("INFO".into(), rest.trim().to_string(), p.info)
     } else {
-        ("—".into(), result.to_string(), TEXT_MUTED)
+        ("—".into(), result.to_string(), p.text_muted)
     }
 }
 
@@ -517,18 +2270,19 @@ fn overlay_backdrop<'a>(content: impl Into<Element<'a, Message>>) -> Element<'a,
         .width(Length::Fill)
         .height(Length::Fill)
         .center(Length::Fill)
-        .style(|_| container::Style {
-            background: Some(iced::Background::Color(OVERLAY_BG)),
+        .style(|theme| container::Style {
+            background: Some(iced::Background::Color(Palette::of(theme).overlay_bg)),
             ..Default::default()
         })
         .into()
 }
 
-fn dialog_style(_theme: &iced::Theme) -> container::Style {
+fn dialog_style(theme: &iced::Theme) -> container::Style {
+    let p = Palette::of(theme);
     container::Style {
-        background: Some(iced::Background::Color(DIALOG_BG)),
+        background: Some(iced::Background::Color(p.dialog_bg)),
         border: iced::Border {
-            color: Color::from_rgb(0.3, 0.3, 0.38),
+            color: p.dialog_border,
             width: 1.5,
             radius: 12.0.into(),
         },
@@ -536,15 +2290,16 @@ fn dialog_style(_theme: &iced::Theme) -> container::Style {
     }
 }
 
-fn close_button_style(_theme: &iced::Theme, status: button::Status) -> button::Style {
+fn close_button_style(theme: &iced::Theme, status: button::Status) -> button::Style {
+    let p = Palette::of(theme);
     let bg = match status {
-        button::Status::Hovered => Color::from_rgb(0.35, 0.15, 0.15),
-        button::Status::Pressed => Color::from_rgb(0.50, 0.15, 0.15),
-        _ => Color::from_rgb(0.22, 0.22, 0.27),
+        button::Status::Hovered => p.close_hover,
+        button::Status::Pressed => p.close_pressed,
+        _ => p.button_bg,
     };
     button::Style {
         background: Some(iced::Background::Color(bg)),
-        text_color: Color::from_rgb(0.85, 0.85, 0.85),
+        text_color: p.text_soft,
         border: iced::Border {
             color: Color::TRANSPARENT,
             width: 0.0,
@@ -554,7 +2309,7 @@ fn close_button_style(_theme: &iced::Theme, status: button::Status) -> button::S
     }
 }
 
-fn styled_button(label: &str, enabled: bool, msg: Message) -> Element<'_, Message> {
+fn styled_button<'a>(label: String, enabled: bool, msg: Message) -> Element<'a, Message> {
     let btn = button(text(label).size(13))
         .padding([8, 16])
         .style(toolbar_button_style);
@@ -562,7 +2317,7 @@ fn styled_button(label: &str, enabled: bool, msg: Message) -> Element<'_, Messag
     if enabled { btn.on_press(msg) } else { btn }.into()
 }
 
-fn styled_button_accent(label: &str, enabled: bool, msg: Message) -> Element<'_, Message> {
+fn styled_button_accent<'a>(label: String, enabled: bool, msg: Message) -> Element<'a, Message> {
     let btn = button(text(label).size(13))
         .padding([8, 16])
         .style(accent_button_style);
@@ -570,18 +2325,19 @@ fn styled_button_accent(label: &str, enabled: bool, msg: Message) -> Element<'_,
     if enabled { btn.on_press(msg) } else { btn }.into()
 }
 
-fn toolbar_button_style(_theme: &iced::Theme, status: button::Status) -> button::Style {
+fn toolbar_button_style(theme: &iced::Theme, status: button::Status) -> button::Style {
+    let p = Palette::of(theme);
     let bg = match status {
-        button::Status::Hovered => Color::from_rgb(0.25, 0.25, 0.30),
-        button::Status::Pressed => Color::from_rgb(0.20, 0.20, 0.25),
-        _ => Color::from_rgb(0.22, 0.22, 0.27),
+        button::Status::Hovered => p.button_hover,
+        button::Status::Pressed => p.button_pressed,
+        _ => p.button_bg,
     };
 
     button::Style {
         background: Some(iced::Background::Color(bg)),
-        text_color: Color::from_rgb(0.85, 0.85, 0.88),
+        text_color: p.text_soft,
         border: iced::Border {
-            color: Color::from_rgb(0.3, 0.3, 0.35),
+            color: p.button_border,
             width: 1.0,
             radius: 6.0.into(),
         },
@@ -589,16 +2345,17 @@ fn toolbar_button_style(_theme: &iced::Theme, status: button::Status) -> button:
     }
 }
 
-fn accent_button_style(_theme: &iced::Theme, status: button::Status) -> button::Style {
+fn accent_button_style(theme: &iced::Theme, status: button::Status) -> button::Style {
+    let p = Palette::of(theme);
     let bg = match status {
-        button::Status::Hovered => Color::from_rgb(0.35, 0.60, 0.96),
-        button::Status::Pressed => Color::from_rgb(0.25, 0.50, 0.86),
-        _ => ACCENT,
+        button::Status::Hovered => p.accent_hover,
+        button::Status::Pressed => p.accent_pressed,
+        _ => p.accent,
     };
 
     button::Style {
         background: Some(iced::Background::Color(bg)),
-        text_color: Color::WHITE,
+        text_color: p.on_accent,
         border: iced::Border {
             color: Color::TRANSPARENT,
             width: 0.0,
@@ -611,18 +2368,24 @@ fn accent_button_style(_theme: &iced::Theme, status: button::Status) -> button::
 // ── Progress bar ─────────────────────────────────────────────────────
 
 fn build_progress_bar(state: &AppState) -> Element<'_, Message> {
+    let p = state.palette();
     let pct = if state.update_total > 0 {
         state.update_completed as f32 / state.update_total as f32 * 100.0
     } else {
         0.0
     };
 
-    let label = text(format!(
-        "{}/{} apps updated",
-        state.update_completed, state.update_total
+    let progress = match state.batch_action {
+        BatchAction::Update => "progress-updated",
+        BatchAction::Uninstall => "progress-uninstalled",
+        BatchAction::Install => "progress-installed",
+    };
+    let label = text(state.tr_args(
+        progress,
+        &[("done", state.update_completed.into()), ("total", state.update_total.into())],
     ))
     .size(12)
-    .color(TEXT_MUTED);
+    .color(p.text_muted);
 
     container(
         column![
@@ -635,17 +2398,18 @@ fn build_progress_bar(state: &AppState) -> Element<'_, Message> {
     )
     .padding([8, 24])
     .width(Length::Fill)
-    .style(|_| container::Style {
-        background: Some(iced::Background::Color(SURFACE)),
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(p.surface)),
         ..Default::default()
     })
     .into()
 }
 
-fn progress_bar_style(_theme: &iced::Theme) -> progress_bar::Style {
+fn progress_bar_style(theme: &iced::Theme) -> progress_bar::Style {
+    let p = Palette::of(theme);
     progress_bar::Style {
-        background: iced::Background::Color(Color::from_rgb(0.20, 0.20, 0.25)),
-        bar: iced::Background::Color(ACCENT),
+        background: iced::Background::Color(p.track),
+        bar: iced::Background::Color(p.accent),
         border: iced::Border {
             color: Color::TRANSPARENT,
             width: 0.0,
@@ -657,52 +2421,70 @@ fn progress_bar_style(_theme: &iced::Theme) -> progress_bar::Style {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ui::theme::{DARK, HIGH_CONTRAST};
 
     #[test]
     fn test_format_result_row_success() {
-        let (badge, label, color) = format_result_row("SUCCESS:App.Id - updated successfully");
+        let (badge, label, color) = format_result_row(&DARK, "SUCCESS:App.Id - updated successfully");
         assert_eq!(badge, "OK");
         assert!(label.contains("App.Id"));
-        assert_eq!(color, SUCCESS_CLR);
+        assert_eq!(color, DARK.success);
     }
 
     #[test]
     fn test_format_result_row_failure() {
-        let (badge, label, color) = format_result_row("FAILURE:App.Id - download error");
+        let (badge, label, color) = format_result_row(&DARK, "FAILURE:App.Id - download error");
         assert_eq!(badge, "FAIL");
         assert!(label.contains("App.Id"));
-        assert_eq!(color, FAILURE_CLR);
+        assert_eq!(color, DARK.failure);
     }
 
     #[test]
     fn test_format_result_row_warning() {
-        let (badge, label, color) = format_result_row("[!] App.Id - needs to be closed");
+        let (badge, label, color) = format_result_row(&DARK, "[!] App.Id - needs to be closed");
         assert_eq!(badge, "WARN");
         assert!(label.contains("App.Id"));
-        assert_eq!(color, WARNING_CLR);
+        assert_eq!(color, DARK.warning);
     }
 
     #[test]
     fn test_format_result_row_info() {
-        let (badge, label, color) = format_result_row("[i] App.Id - already up to date");
+        let (badge, label, color) = format_result_row(&DARK, "[i] App.Id - already up to date");
         assert_eq!(badge, "INFO");
         assert!(label.contains("App.Id"));
-        assert_eq!(color, INFO_CLR);
+        assert_eq!(color, DARK.info);
     }
 
     #[test]
     fn test_format_result_row_plain() {
-        let (badge, label, color) = format_result_row("Some unknown format");
+        let (badge, label, color) = format_result_row(&DARK, "Some unknown format");
         assert_eq!(badge, "\u{2014}");
         assert_eq!(label, "Some unknown format");
-        assert_eq!(color, TEXT_MUTED);
+        assert_eq!(color, DARK.text_muted);
+    }
+
+    #[test]
+    fn test_store_labels_come_from_the_catalog() {
+        let mut state = AppState::default();
+        assert_eq!(
+            app_name(&state, "9NBLGGH4NNS1", "9NBLGGH4NNS1", STORE_SOURCE),
+            "Microsoft Store app 9NBLGGH4NNS1"
+        );
+        assert_eq!(app_name(&state, "Paint", "9PCFS5B6T72H", STORE_SOURCE), "Paint");
+        assert_eq!(source_text(&state, "winget"), "winget");
+
+        let _ = state.update(Message::LanguageChanged(Some(Language::French)));
+        assert_eq!(
+            app_name(&state, "9NBLGGH4NNS1", "9NBLGGH4NNS1", STORE_SOURCE),
+            "Application Microsoft Store 9NBLGGH4NNS1"
+        );
     }
 
     #[test]
     fn test_progress_bar_style_colors() {
         let style = progress_bar_style(&iced::Theme::Dark);
         // Verify it produces the expected accent bar colour
-        assert_eq!(style.bar, iced::Background::Color(ACCENT));
+        assert_eq!(style.bar, iced::Background::Color(DARK.accent));
     }
 
     #[test]
@@ -739,6 +2521,18 @@ mod tests {
         assert_ne!(hovered.background, pressed.background);
     }
 
+    #[test]
+    fn test_styles_follow_theme() {
+        let light = toolbar_button_style(&iced::Theme::Light, button::Status::Active);
+        let dark = toolbar_button_style(&iced::Theme::Dark, button::Status::Active);
+        assert_ne!(light.background, dark.background);
+
+        let contrast = ThemeChoice::HighContrast.theme(true);
+        let accent = accent_button_style(&contrast, button::Status::Active);
+        assert_eq!(accent.text_color, HIGH_CONTRAST.on_accent);
+        assert_eq!(dialog_style(&contrast).border.color, HIGH_CONTRAST.dialog_border);
+    }
+
     #[test]
     fn test_dialog_style_has_border() {
         let style = dialog_style(&iced::Theme::Dark);
@@ -1,6 +1,9 @@
 mod components;
 mod icon;
+mod theme;
+mod virtual_list;
 
-pub use components::build_view;
+pub use components::{app_list_id, build_view, search_input_id};
 pub use icon::create_icon;
+pub use theme::{system_prefers_dark, Palette, SYSTEM_THEME_POLL_SECS};
 
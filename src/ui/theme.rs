@@ -0,0 +1,273 @@
+use crate::settings::ThemeChoice;
+use iced::{Color, Theme};
+use std::sync::LazyLock;
+
+#[cfg(any(windows, all(unix, not(target_os = "macos"))))]
+use std::process::Command;
+
+#[cfg(windows)]
+use std::os::windows::process::CommandExt;
+
+/// Seconds between checks of the desktop colour scheme while following it.
+pub const SYSTEM_THEME_POLL_SECS: u64 = 30;
+
+/// Colours used by the custom-styled widgets, supplied by the active theme.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    pub accent: Color,
+    pub accent_hover: Color,
+    pub accent_pressed: Color,
+    /// Text drawn on top of `accent`.
+    pub on_accent: Color,
+    pub surface: Color,
+    pub surface_light: Color,
+    pub header_bg: Color,
+    pub row_alt: Color,
+    pub row_normal: Color,
+    pub text: Color,
+    /// Secondary text that still needs to be easy to read.
+    pub text_soft: Color,
+    pub text_muted: Color,
+    pub border: Color,
+    pub success: Color,
+    pub failure: Color,
+    pub warning: Color,
+    pub info: Color,
+    pub overlay_bg: Color,
+    pub dialog_bg: Color,
+    pub dialog_border: Color,
+    pub button_bg: Color,
+    pub button_hover: Color,
+    pub button_pressed: Color,
+    pub button_border: Color,
+    pub close_hover: Color,
+    pub close_pressed: Color,
+    pub track: Color,
+}
+
+/// The original dark palette.
+pub const DARK: Palette = Palette {
+    accent: Color::from_rgb(0.30, 0.56, 0.93),
+    accent_hover: Color::from_rgb(0.35, 0.60, 0.96),
+    accent_pressed: Color::from_rgb(0.25, 0.50, 0.86),
+    on_accent: Color::WHITE,
+    surface: Color::from_rgb(0.13, 0.13, 0.17),
+    surface_light: Color::from_rgb(0.17, 0.17, 0.22),
+    header_bg: Color::from_rgb(0.10, 0.10, 0.14),
+    row_alt: Color::from_rgb(0.15, 0.15, 0.19),
+    row_normal: Color::from_rgb(0.12, 0.12, 0.16),
+    text: Color::WHITE,
+    text_soft: Color::from_rgb(0.85, 0.85, 0.88),
+    text_muted: Color::from_rgb(0.55, 0.55, 0.60),
+    border: Color::from_rgb(0.22, 0.22, 0.28),
+    success: Color::from_rgb(0.30, 0.80, 0.30),
+    failure: Color::from_rgb(0.90, 0.25, 0.25),
+    warning: Color::from_rgb(1.0, 0.65, 0.15),
+    info: Color::from_rgb(0.40, 0.70, 1.0),
+    overlay_bg: Color::from_rgba(0.0, 0.0, 0.0, 0.70),
+    dialog_bg: Color::from_rgb(0.16, 0.16, 0.20),
+    dialog_border: Color::from_rgb(0.3, 0.3, 0.38),
+    button_bg: Color::from_rgb(0.22, 0.22, 0.27),
+    button_hover: Color::from_rgb(0.25, 0.25, 0.30),
+    button_pressed: Color::from_rgb(0.20, 0.20, 0.25),
+    button_border: Color::from_rgb(0.3, 0.3, 0.35),
+    close_hover: Color::from_rgb(0.35, 0.15, 0.15),
+    close_pressed: Color::from_rgb(0.50, 0.15, 0.15),
+    track: Color::from_rgb(0.20, 0.20, 0.25),
+};
+
+/// A light palette for light-mode desktops.
+pub const LIGHT: Palette = Palette {
+    accent: Color::from_rgb(0.15, 0.42, 0.85),
+    accent_hover: Color::from_rgb(0.20, 0.48, 0.90),
+    accent_pressed: Color::from_rgb(0.10, 0.36, 0.76),
+    on_accent: Color::WHITE,
+    surface: Color::from_rgb(0.96, 0.96, 0.97),
+    surface_light: Color::from_rgb(0.92, 0.92, 0.94),
+    header_bg: Color::from_rgb(0.88, 0.88, 0.91),
+    row_alt: Color::from_rgb(0.95, 0.95, 0.97),
+    row_normal: Color::WHITE,
+    text: Color::from_rgb(0.10, 0.10, 0.12),
+    text_soft: Color::from_rgb(0.22, 0.22, 0.26),
+    text_muted: Color::from_rgb(0.40, 0.40, 0.45),
+    border: Color::from_rgb(0.80, 0.80, 0.85),
+    success: Color::from_rgb(0.10, 0.55, 0.15),
+    failure: Color::from_rgb(0.80, 0.15, 0.15),
+    warning: Color::from_rgb(0.72, 0.42, 0.0),
+    info: Color::from_rgb(0.10, 0.45, 0.80),
+    overlay_bg: Color::from_rgba(0.0, 0.0, 0.0, 0.35),
+    dialog_bg: Color::WHITE,
+    dialog_border: Color::from_rgb(0.75, 0.75, 0.80),
+    button_bg: Color::from_rgb(0.90, 0.90, 0.93),
+    button_hover: Color::from_rgb(0.85, 0.85, 0.89),
+    button_pressed: Color::from_rgb(0.80, 0.80, 0.85),
+    button_border: Color::from_rgb(0.75, 0.75, 0.80),
+    close_hover: Color::from_rgb(0.95, 0.80, 0.80),
+    close_pressed: Color::from_rgb(0.90, 0.65, 0.65),
+    track: Color::from_rgb(0.85, 0.85, 0.88),
+};
+
+/// Black background, white text and a yellow accent, with visible borders.
+pub const HIGH_CONTRAST: Palette = Palette {
+    accent: Color::from_rgb(1.0, 0.85, 0.0),
+    accent_hover: Color::from_rgb(1.0, 0.92, 0.35),
+    accent_pressed: Color::from_rgb(0.85, 0.72, 0.0),
+    on_accent: Color::BLACK,
+    surface: Color::BLACK,
+    surface_light: Color::from_rgb(0.08, 0.08, 0.08),
+    header_bg: Color::BLACK,
+    row_alt: Color::from_rgb(0.10, 0.10, 0.10),
+    row_normal: Color::BLACK,
+    text: Color::WHITE,
+    text_soft: Color::WHITE,
+    text_muted: Color::from_rgb(0.85, 0.85, 0.85),
+    border: Color::WHITE,
+    success: Color::from_rgb(0.30, 1.0, 0.30),
+    failure: Color::from_rgb(1.0, 0.40, 0.40),
+    warning: Color::from_rgb(1.0, 0.75, 0.0),
+    info: Color::from_rgb(0.40, 0.85, 1.0),
+    overlay_bg: Color::from_rgba(0.0, 0.0, 0.0, 0.85),
+    dialog_bg: Color::BLACK,
+    dialog_border: Color::WHITE,
+    button_bg: Color::BLACK,
+    button_hover: Color::from_rgb(0.20, 0.20, 0.20),
+    button_pressed: Color::from_rgb(0.30, 0.30, 0.30),
+    button_border: Color::WHITE,
+    close_hover: Color::from_rgb(0.45, 0.0, 0.0),
+    close_pressed: Color::from_rgb(0.60, 0.0, 0.0),
+    track: Color::from_rgb(0.25, 0.25, 0.25),
+};
+
+/// Iced theme for the high-contrast palette, so built-in widgets
+/// (text inputs, checkboxes, scrollbars) match the custom ones.
+static HIGH_CONTRAST_THEME: LazyLock<Theme> = LazyLock::new(|| {
+    Theme::custom(
+        String::from("High contrast"),
+        iced::theme::Palette {
+            background: HIGH_CONTRAST.surface,
+            text: HIGH_CONTRAST.text,
+            primary: HIGH_CONTRAST.accent,
+            success: HIGH_CONTRAST.success,
+            danger: HIGH_CONTRAST.failure,
+        },
+    )
+});
+
+impl Palette {
+    /// Returns the palette belonging to an iced theme.
+    pub fn of(theme: &Theme) -> &'static Palette {
+        match theme {
+            Theme::Light => &LIGHT,
+            Theme::Custom(_) if theme == &*HIGH_CONTRAST_THEME => &HIGH_CONTRAST,
+            _ => &DARK,
+        }
+    }
+}
+
+impl ThemeChoice {
+    /// Returns the iced theme to use, resolving `System` with `system_dark`.
+    pub fn theme(self, system_dark: bool) -> Theme {
+        match self {
+            Self::System if system_dark => Theme::Dark,
+            Self::System | Self::Light => Theme::Light,
+            Self::Dark => Theme::Dark,
+            Self::HighContrast => HIGH_CONTRAST_THEME.clone(),
+        }
+    }
+
+    /// Returns the palette to use, resolving `System` with `system_dark`.
+    pub fn palette(self, system_dark: bool) -> &'static Palette {
+        match self {
+            Self::System if system_dark => &DARK,
+            Self::System | Self::Light => &LIGHT,
+            Self::Dark => &DARK,
+            Self::HighContrast => &HIGH_CONTRAST,
+        }
+    }
+}
+
+
+/// Returns `true` if the desktop uses a dark colour scheme.
+///
+/// Reads `AppsUseLightTheme` from the registry on Windows and the GNOME
+/// `color-scheme` setting elsewhere; defaults to dark if neither is available.
+pub fn system_prefers_dark() -> bool {
+    #[cfg(windows)]
+    let dark = {
+        let mut cmd = Command::new("reg");
+        cmd.args([
+            "query",
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+            "/v",
+            "AppsUseLightTheme",
+        ]);
+        cmd.creation_flags(crate::winget::CREATE_NO_WINDOW);
+        cmd.output()
+            .ok()
+            .and_then(|o| parse_apps_use_light_theme(&String::from_utf8_lossy(&o.stdout)))
+            .map(|light| !light)
+    };
+    #[cfg(all(unix, not(target_os = "macos")))]
+    let dark = Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "color-scheme"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| parse_color_scheme(&String::from_utf8_lossy(&o.stdout)));
+    #[cfg(not(any(windows, all(unix, not(target_os = "macos")))))]
+    let dark: Option<bool> = None;
+    dark.unwrap_or(true)
+}
+
+/// Parses `reg query ... /v AppsUseLightTheme` output (`REG_DWORD 0x0`/`0x1`).
+#[cfg_attr(not(windows), allow(dead_code))]
+fn parse_apps_use_light_theme(output: &str) -> Option<bool> {
+    let line = output.lines().find(|l| l.contains("AppsUseLightTheme"))?;
+    let value = line.split_whitespace().last()?;
+    let value = u32::from_str_radix(value.trim_start_matches("0x"), 16).ok()?;
+    Some(value != 0)
+}
+
+/// Parses `gsettings get org.gnome.desktop.interface color-scheme` output.
+#[cfg_attr(not(all(unix, not(target_os = "macos"))), allow(dead_code))]
+fn parse_color_scheme(output: &str) -> bool {
+    output.contains("dark")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette_of_theme() {
+        assert_eq!(Palette::of(&Theme::Dark), &DARK);
+        assert_eq!(Palette::of(&Theme::Light), &LIGHT);
+        assert_eq!(Palette::of(&ThemeChoice::HighContrast.theme(false)), &HIGH_CONTRAST);
+        assert_eq!(Palette::of(&Theme::Nord), &DARK);
+    }
+
+    #[test]
+    fn test_system_choice_follows_desktop() {
+        assert_eq!(ThemeChoice::System.theme(true), Theme::Dark);
+        assert_eq!(ThemeChoice::System.theme(false), Theme::Light);
+        assert_eq!(ThemeChoice::System.palette(false), &LIGHT);
+        assert_eq!(ThemeChoice::Dark.palette(false), &DARK);
+    }
+
+    #[test]
+    fn test_parse_apps_use_light_theme() {
+        let output = "\r\nHKEY_CURRENT_USER\\Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize\r\n    AppsUseLightTheme    REG_DWORD    0x0\r\n";
+        assert_eq!(parse_apps_use_light_theme(output), Some(false));
+        assert_eq!(
+            parse_apps_use_light_theme("    AppsUseLightTheme    REG_DWORD    0x1"),
+            Some(true)
+        );
+        assert_eq!(parse_apps_use_light_theme("ERROR: not found"), None);
+    }
+
+    #[test]
+    fn test_parse_color_scheme() {
+        assert!(parse_color_scheme("'prefer-dark'\n"));
+        assert!(!parse_color_scheme("'default'\n"));
+    }
+}
@@ -0,0 +1,110 @@
+/// Height of an app row, in logical pixels.
+pub const ROW_HEIGHT: f32 = 48.0;
+/// Height of a source section header, in logical pixels.
+pub const GROUP_HEADER_HEIGHT: f32 = 36.0;
+/// Viewport height assumed until the scrollable has reported its size.
+pub const DEFAULT_VIEWPORT_HEIGHT: f32 = 1200.0;
+/// Extra height rendered above and below the viewport so fast scrolling
+/// does not reveal empty space before the next frame.
+const OVERSCAN: f32 = 4.0 * ROW_HEIGHT;
+
+/// The slice of list entries to build for the current scroll position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Window {
+    /// First entry to build.
+    pub start: usize,
+    /// One past the last entry to build.
+    pub end: usize,
+    /// Height of the skipped entries above `start`.
+    pub before: f32,
+    /// Height of the skipped entries from `end` onwards.
+    pub after: f32,
+}
+
+/// Picks the entries (given their fixed heights, top to bottom) that
+/// intersect the viewport.
+///
+/// `scroll` is the relative vertical scroll position (`0.0..=1.0`) as
+/// reported by the scrollable, so programmatic snaps are honoured before the
+/// scrollable reports the new absolute offset.
+pub fn visible_window(heights: &[f32], scroll: f32, viewport: f32) -> Window {
+    let viewport = if viewport > 0.0 {
+        viewport
+    } else {
+        DEFAULT_VIEWPORT_HEIGHT
+    };
+    let total: f32 = heights.iter().sum();
+    let offset = scroll.clamp(0.0, 1.0) * (total - viewport).max(0.0);
+    let (top, bottom) = (offset - OVERSCAN, offset + viewport + OVERSCAN);
+
+    let mut window = Window {
+        start: heights.len(),
+        end: heights.len(),
+        before: 0.0,
+        after: 0.0,
+    };
+    let mut y = 0.0;
+    for (i, &height) in heights.iter().enumerate() {
+        if y + height <= top {
+            window.before += height;
+        } else if y >= bottom {
+            window.end = window.end.min(i);
+            window.after += height;
+        } else {
+            window.start = window.start.min(i);
+        }
+        y += height;
+    }
+    window.start = window.start.min(window.end);
+    window
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_at_top() {
+        let heights = vec![ROW_HEIGHT; 1000];
+        let w = visible_window(&heights, 0.0, 10.0 * ROW_HEIGHT);
+        assert_eq!(w.start, 0);
+        assert_eq!(w.end, 14);
+        assert_eq!(w.before, 0.0);
+        assert_eq!(w.after, 986.0 * ROW_HEIGHT);
+    }
+
+    #[test]
+    fn test_window_at_bottom() {
+        let heights = vec![ROW_HEIGHT; 1000];
+        let w = visible_window(&heights, 1.0, 10.0 * ROW_HEIGHT);
+        assert_eq!(w.start, 986);
+        assert_eq!(w.end, 1000);
+        assert_eq!(w.before, 986.0 * ROW_HEIGHT);
+        assert_eq!(w.after, 0.0);
+    }
+
+    #[test]
+    fn test_window_preserves_total_height() {
+        let heights: Vec<f32> = (0..500)
+            .map(|i| if i % 20 == 0 { GROUP_HEADER_HEIGHT } else { ROW_HEIGHT })
+            .collect();
+        let w = visible_window(&heights, 0.37, 600.0);
+        let built: f32 = heights[w.start..w.end].iter().sum();
+        let total: f32 = heights.iter().sum();
+        assert!((w.before + built + w.after - total).abs() < 0.01);
+        assert!(w.end - w.start < 30);
+    }
+
+    #[test]
+    fn test_short_list_is_built_entirely() {
+        let heights = vec![ROW_HEIGHT; 5];
+        let w = visible_window(&heights, 0.5, 0.0);
+        assert_eq!((w.start, w.end), (0, 5));
+    }
+
+    #[test]
+    fn test_empty_list() {
+        let w = visible_window(&[], 0.0, 500.0);
+        assert_eq!((w.start, w.end), (0, 0));
+    }
+}
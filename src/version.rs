@@ -0,0 +1,153 @@
+use std::cmp::Ordering;
+
+/// One dot-separated component of a version, e.g. `"3rc1"` → `(3, "rc1")`.
+#[derive(Debug, PartialEq, Eq)]
+struct Part<'a> {
+    number: u64,
+    suffix: &'a str,
+}
+
+impl<'a> Part<'a> {
+    fn parse(s: &'a str) -> Self {
+        let digits = s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        Self {
+            number: s[..digits].parse().unwrap_or(0),
+            suffix: &s[digits..],
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.number == 0 && self.suffix.is_empty()
+    }
+}
+
+impl Ord for Part<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.number.cmp(&other.number).then_with(|| {
+            // A bare number sorts after the same number with a suffix
+            // (`1.0` > `1.0-beta`), otherwise suffixes compare case-insensitively.
+            match (self.suffix.is_empty(), other.suffix.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => self
+                    .suffix
+                    .to_lowercase()
+                    .cmp(&other.suffix.to_lowercase()),
+            }
+        })
+    }
+}
+
+impl PartialOrd for Part<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Strips the decorations winget adds to versions it cannot pin down
+/// exactly (`"< 2.0"`, `"> 1.5"`) and surrounding whitespace.
+fn normalize(version: &str) -> &str {
+    version
+        .trim()
+        .trim_start_matches(['<', '>'])
+        .trim_start()
+}
+
+/// Splits a version into comparable parts, dropping trailing zero parts so
+/// that `1.2` and `1.2.0` compare equal.
+fn parts(version: &str) -> Vec<Part<'_>> {
+    let normalized = normalize(version);
+    if normalized.is_empty() {
+        return Vec::new();
+    }
+    let mut parts: Vec<Part<'_>> = normalized.split('.').map(Part::parse).collect();
+    while parts.last().is_some_and(Part::is_zero) {
+        parts.pop();
+    }
+    parts
+}
+
+/// Compares two version strings the way winget orders package versions.
+///
+/// Versions are compared part by part on `.`; each part compares its leading
+/// number numerically and then any suffix. Missing parts count as zero.
+/// Non-versions such as `"Unknown"` sort before every real version.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (a_known, b_known) = (is_known(a), is_known(b));
+    if a_known != b_known {
+        return a_known.cmp(&b_known);
+    }
+
+    let (a, b) = (parts(a), parts(b));
+    let zero = Part::parse("0");
+    for i in 0..a.len().max(b.len()) {
+        let ord = a.get(i).unwrap_or(&zero).cmp(b.get(i).unwrap_or(&zero));
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Returns `true` if the string looks like a version (starts with a digit
+/// once winget's `<`/`>` markers are removed).
+pub fn is_known(version: &str) -> bool {
+    normalize(version)
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_digit())
+}
+
+/// Returns the leading (major) number of a version, if it has one.
+pub fn major(version: &str) -> Option<u64> {
+    if !is_known(version) {
+        return None;
+    }
+    parts(version).first().map(|p| p.number).or(Some(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_ordering() {
+        assert_eq!(compare_versions("1.10", "1.9"), Ordering::Greater);
+        assert_eq!(compare_versions("120.0.6099.109", "120.0.6099.130"), Ordering::Less);
+        assert_eq!(compare_versions("2.0", "10.0"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_trailing_zeros_equal() {
+        assert_eq!(compare_versions("1.2", "1.2.0.0"), Ordering::Equal);
+        assert_eq!(compare_versions("1.2.0.1", "1.2"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_suffixes() {
+        assert_eq!(compare_versions("1.0", "1.0-beta"), Ordering::Greater);
+        assert_eq!(compare_versions("1.0.1", "1.0.1rc2"), Ordering::Greater);
+        assert_eq!(compare_versions("1.0.1rc1", "1.0.1RC2"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_unknown_sorts_first() {
+        assert_eq!(compare_versions("Unknown", "0.1"), Ordering::Less);
+        assert_eq!(compare_versions("1.0", ""), Ordering::Greater);
+        assert_eq!(compare_versions("Unknown", "Unknown"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_range_markers_ignored() {
+        assert_eq!(compare_versions("< 2.0", "2.0"), Ordering::Equal);
+        assert!(is_known("< 2.0"));
+    }
+
+    #[test]
+    fn test_major() {
+        assert_eq!(major("120.0.6099"), Some(120));
+        assert_eq!(major("0.0"), Some(0));
+        assert_eq!(major("Unknown"), None);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::models::UpdatableApp;
+use crate::models::{
+    InstallRequest, InstalledApp, SearchResult, SourceAction, SourceInfo, UpdatableApp,
+    UpgradeRequest,
+};
 use std::process::Command;
 
 #[cfg(windows)]
@@ -6,12 +9,134 @@ use std::os::windows::process::CommandExt;
 
 /// Flag to prevent winget from spawning a visible console window.
 #[cfg(windows)]
-const CREATE_NO_WINDOW: u32 = 0x08000000;
+pub(crate) const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+/// Name of the Microsoft Store source.
+pub const STORE_SOURCE: &str = "msstore";
+
+/// Returns `true` for Microsoft Store product IDs such as `9NBLGGH4NNS1`
+/// or `XP89DCGQ3K6VLD`, which say nothing about the app they identify.
+pub fn is_store_product_id(id: &str) -> bool {
+    ((id.len() == 12 && id.starts_with('9')) || (id.len() == 14 && id.starts_with("XP")))
+        && id.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// Drops the `…` winget appends to names it truncates.
+pub fn clean_name(name: &str) -> String {
+    name.trim_end_matches('…').trim_end().to_string()
+}
+
+/// Returns `true` for store apps that winget lists under their bare product
+/// ID instead of a name, which the view labels as such.
+pub fn is_unnamed_store_app(name: &str, id: &str, source: &str) -> bool {
+    source == STORE_SOURCE && (name.is_empty() || name == id) && is_store_product_id(id)
+}
+
+/// Returns `true` if winget reports that the Microsoft Store, not winget,
+/// updates the package.
+fn managed_by_store(combined: &str) -> bool {
+    let lower = combined.to_lowercase();
+    lower.contains("managed by the microsoft store")
+        || lower.contains("managed by the store")
+        || lower.contains("update it from the microsoft store")
+}
 
 /// Retrieves the list of updatable applications from winget.
 pub fn get_updatable_apps() -> Result<Vec<UpdatableApp>, String> {
+    parse_winget_output(&run_winget(&["upgrade", "--include-unknown"])?)
+}
+
+/// Retrieves the updatable applications of every source except `disabled`,
+/// along with an error message for each source that could not be queried.
+///
+/// `winget upgrade` takes a single `--source`, so when sources are disabled
+/// each enabled one is queried on its own and the results are merged. An
+/// unreachable source only fails the refresh if every source failed.
+pub fn get_updatable_apps_excluding(
+    disabled: &[String],
+) -> Result<(Vec<UpdatableApp>, Vec<String>), String> {
+    if disabled.is_empty() {
+        return get_updatable_apps().map(|apps| (apps, Vec::new()));
+    }
+    let listings = enabled_sources(&list_sources()?, disabled)
+        .into_iter()
+        .map(|source| {
+            let output = run_winget(&["upgrade", "--include-unknown", "--source", &source]);
+            (source, output)
+        })
+        .collect();
+    merge_source_listings(listings)
+}
+
+/// Merges the `winget upgrade` output of individual sources, collecting the
+/// failures as `"<source>: <error>"`.
+fn merge_source_listings(
+    listings: Vec<(String, Result<String, String>)>,
+) -> Result<(Vec<UpdatableApp>, Vec<String>), String> {
+    let queried = listings.len();
+    let mut apps = Vec::new();
+    let mut errors = Vec::new();
+    for (source, output) in listings {
+        match output.and_then(|output| parse_winget_output(&output)) {
+            Ok(listed) => apps.extend(listed.into_iter().map(|mut app| {
+                if app.source.is_empty() {
+                    app.source = source.clone();
+                }
+                app
+            })),
+            Err(e) => errors.push(format!("{source}: {e}")),
+        }
+    }
+    if queried > 0 && errors.len() == queried {
+        return Err(errors.join("; "));
+    }
+    Ok((apps, errors))
+}
+
+/// Retrieves every installed package known to winget, including ones it
+/// cannot manage.
+pub fn get_installed_apps() -> Result<Vec<InstalledApp>, String> {
+    parse_winget_list(&run_winget(&["list"])?)
+}
+
+/// Retrieves the installed packages, leaving out those matched to a
+/// `disabled` source. Unmanaged packages are always kept.
+pub fn get_installed_apps_excluding(disabled: &[String]) -> Result<Vec<InstalledApp>, String> {
+    let mut apps = get_installed_apps()?;
+    apps.retain(|app| !disabled.contains(&app.source));
+    Ok(apps)
+}
+
+/// Lists the configured sources.
+pub fn list_sources() -> Result<Vec<SourceInfo>, String> {
+    parse_winget_sources(&run_winget(&["source", "list"])?)
+}
+
+/// Returns the names of the sources not in `disabled`, in listed order.
+/// Explicit sources are left out, as a plain `winget upgrade` skips them too.
+fn enabled_sources(sources: &[SourceInfo], disabled: &[String]) -> Vec<String> {
+    sources
+        .iter()
+        .filter(|source| !source.explicit && !disabled.contains(&source.name))
+        .map(|source| source.name.clone())
+        .collect()
+}
+
+/// Searches the configured sources for packages matching `query`.
+pub fn search_packages(query: &str) -> Result<Vec<SearchResult>, String> {
+    parse_winget_search(&run_winget(&search_args(query))?)
+}
+
+/// Builds the `winget search` arguments. The query goes through `--query`
+/// so that text starting with `-` is not taken for a flag.
+fn search_args(query: &str) -> [&str; 4] {
+    ["search", "--query", query, "--accept-source-agreements"]
+}
+
+/// Runs winget with `args` and returns its standard output.
+fn run_winget(args: &[&str]) -> Result<String, String> {
     let mut cmd = Command::new("winget");
-    cmd.args(["upgrade", "--include-unknown"]);
+    cmd.args(args);
     #[cfg(windows)]
     cmd.creation_flags(CREATE_NO_WINDOW);
     let output = cmd
@@ -25,8 +150,7 @@ pub fn get_updatable_apps() -> Result<Vec<UpdatableApp>, String> {
         ));
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    parse_winget_output(&stdout)
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
 }
 
 /// Column positions parsed from the winget header line.
@@ -34,7 +158,20 @@ struct ColumnLayout {
     id_col: usize,
     version_col: usize,
     available_col: usize,
-    source_col: usize,
+    /// Absent when winget was asked for a single `--source`.
+    source_col: Option<usize>,
+}
+
+/// Column positions of a `winget list` or `winget search` table.
+///
+/// The column between `Version` and `Source` is `Available` for `list` and
+/// `Match` for `search`. It and `Source` are only printed when at least one
+/// row has a value for them.
+struct ListLayout {
+    id_col: usize,
+    version_col: usize,
+    extra_col: Option<usize>,
+    source_col: Option<usize>,
 }
 
 /// Sanitizes raw winget output by resolving carriage returns.
@@ -77,7 +214,7 @@ fn unwrap_output(output: &str) -> String {
 
     for line in &lines {
         current.push_str(line);
-        if line.len() < width {
+        if line.chars().count() < width {
             result.push(std::mem::take(&mut current));
         }
     }
@@ -113,6 +250,130 @@ fn detect_wrap_width(lines: &[&str]) -> Option<usize> {
 /// unwrapping line-wrapped output), then uses column positions from the header
 /// line so that app names containing spaces are parsed correctly.
 pub fn parse_winget_output(output: &str) -> Result<Vec<UpdatableApp>, String> {
+    let Some((header, rows)) = table_lines(output) else {
+        return Ok(Vec::new()); // No updates available
+    };
+
+    let layout = ColumnLayout {
+        id_col: column(&header, "Id").ok_or("Missing Id column in winget output")?,
+        version_col: column(&header, "Version")
+            .ok_or("Missing Version column in winget output")?,
+        available_col: column(&header, "Available")
+            .ok_or("Missing Available column in winget output")?,
+        source_col: column(&header, "Source"),
+    };
+
+    Ok(rows
+        .iter()
+        .filter_map(|line| parse_app_line(line, &layout))
+        .map(|mut app| {
+            app.name = clean_name(&app.name);
+            app
+        })
+        .collect())
+}
+
+/// Parses `winget list` output into the installed-package inventory.
+///
+/// Shares the table handling of [`parse_winget_output`], but tolerates the
+/// `Available` and `Source` columns being absent, and rows that leave them
+/// blank (packages without updates, and ARP/MSIX entries winget found on the
+/// machine but cannot match to a source).
+pub fn parse_winget_list(output: &str) -> Result<Vec<InstalledApp>, String> {
+    let Some((header, rows)) = table_lines(output) else {
+        return Ok(Vec::new());
+    };
+
+    let layout = list_layout(&header, "Available")?;
+    Ok(rows
+        .iter()
+        .filter_map(|line| split_list_line(line, &layout))
+        .map(|[name, id, version, available, source]| {
+            let name = clean_name(&name);
+            InstalledApp::new(name, id, version, available, source)
+        })
+        .collect())
+}
+
+/// Parses the output of `winget search` into a list of packages.
+///
+/// `Match` tells why a package matched when it wasn't by name or ID (e.g.
+/// `Tag: browser`).
+pub fn parse_winget_search(output: &str) -> Result<Vec<SearchResult>, String> {
+    let Some((header, rows)) = table_lines(output) else {
+        return Ok(Vec::new());
+    };
+
+    let layout = list_layout(&header, "Match")?;
+    Ok(rows
+        .iter()
+        .filter_map(|line| split_list_line(line, &layout))
+        .map(|[name, id, version, matched, source]| {
+            let name = clean_name(&name);
+            SearchResult::new(name, id, version, matched, source)
+        })
+        .collect())
+}
+
+/// Parses the output of `winget source list`.
+///
+/// Newer winget versions add an `Explicit` column after `Argument`.
+pub fn parse_winget_sources(output: &str) -> Result<Vec<SourceInfo>, String> {
+    let sanitized = sanitize_output(output);
+    let lines: Vec<&str> = sanitized.lines().collect();
+    let Some(header_idx) = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("Name") && line.contains("Argument"))
+    else {
+        return Ok(Vec::new()); // No sources configured
+    };
+    let header = lines[header_idx];
+    let argument_col = column(header, "Argument")
+        .ok_or("Missing Argument column in winget output")?;
+    let explicit_col = column(header, "Explicit");
+
+    Ok(lines
+        .iter()
+        .skip(header_idx + 1)
+        .skip_while(|line| line.trim().chars().all(|c| c == '-') && !line.trim().is_empty())
+        .take_while(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            let argument_end = explicit_col.unwrap_or(usize::MAX);
+            let name = safe_slice(line, 0, argument_col)?.trim().to_string();
+            let argument = safe_slice(line, argument_col, argument_end)?.trim().to_string();
+            let explicit = match explicit_col {
+                Some(col) => safe_slice(line, col, usize::MAX)?.trim() == "true",
+                None => false,
+            };
+            (!name.is_empty()).then_some(SourceInfo {
+                name,
+                argument,
+                explicit,
+            })
+        })
+        .collect())
+}
+
+/// Reads the column positions of a list-style header whose optional middle
+/// column is called `extra`.
+fn list_layout(header: &str, extra: &str) -> Result<ListLayout, String> {
+    Ok(ListLayout {
+        id_col: column(header, "Id").ok_or("Missing Id column in winget output")?,
+        version_col: column(header, "Version")
+            .ok_or("Missing Version column in winget output")?,
+        extra_col: column(header, extra),
+        source_col: column(header, "Source"),
+    })
+}
+
+/// Locates the table in winget output and returns its header line and data
+/// lines.
+///
+/// Sanitizes the output (stripping carriage-return progress indicators and
+/// unwrapping line-wrapped output) first. Data lines run from the separator
+/// below the header to the first blank line or the "upgrades available"
+/// footer. Returns `None` if there is no table.
+fn table_lines(output: &str) -> Option<(String, Vec<String>)> {
     let sanitized = sanitize_output(output);
     let unwrapped = unwrap_output(&sanitized);
     let lines: Vec<&str> = unwrapped.lines().collect();
@@ -120,28 +381,7 @@ pub fn parse_winget_output(output: &str) -> Result<Vec<UpdatableApp>, String> {
     // Locate the header line (e.g. "Name   Id   Version   Available   Source").
     let header_idx = lines.iter().position(|line| {
         line.contains("Name") && line.contains("Id") && line.contains("Version")
-    });
-
-    let header_idx = match header_idx {
-        Some(idx) => idx,
-        None => return Ok(Vec::new()), // No updates available
-    };
-
-    let header = lines[header_idx];
-    let layout = ColumnLayout {
-        id_col: header
-            .find("Id")
-            .ok_or("Missing Id column in winget output")?,
-        version_col: header
-            .find("Version")
-            .ok_or("Missing Version column in winget output")?,
-        available_col: header
-            .find("Available")
-            .ok_or("Missing Available column in winget output")?,
-        source_col: header
-            .find("Source")
-            .ok_or("Missing Source column in winget output")?,
-    };
+    })?;
 
     // Find the separator line that follows the header.
     let data_start = lines
@@ -152,41 +392,37 @@ pub fn parse_winget_output(output: &str) -> Result<Vec<UpdatableApp>, String> {
         .map(|(i, _)| i + 1)
         .unwrap_or(header_idx + 1);
 
-    let mut apps = Vec::new();
-    for line in lines.iter().skip(data_start) {
-        let trimmed = line.trim();
-        if trimmed.is_empty() || trimmed.contains("upgrades available") {
-            break;
-        }
-        if let Some(app) = parse_app_line(line, &layout) {
-            apps.push(app);
-        }
-    }
+    let rows = lines
+        .iter()
+        .skip(data_start)
+        .take_while(|line| {
+            let trimmed = line.trim();
+            !trimmed.is_empty() && !trimmed.contains("upgrades available")
+        })
+        .map(|line| line.to_string())
+        .collect();
 
-    Ok(apps)
+    Some((lines[header_idx].to_string(), rows))
 }
 
 /// Parses a single data line using the known column layout.
 fn parse_app_line(line: &str, layout: &ColumnLayout) -> Option<UpdatableApp> {
-    if line.len() <= layout.id_col {
+    if line.chars().count() <= layout.id_col {
         return None;
     }
 
-    let name = safe_slice(line, 0, layout.id_col).trim().to_string();
-    let id = safe_slice(line, layout.id_col, layout.version_col)
+    let name = safe_slice(line, 0, layout.id_col)?.trim().to_string();
+    let id = safe_slice(line, layout.id_col, layout.version_col)?
         .trim()
         .to_string();
-    let version = safe_slice(line, layout.version_col, layout.available_col)
+    let version = safe_slice(line, layout.version_col, layout.available_col)?
         .trim()
         .to_string();
-    let available = safe_slice(line, layout.available_col, layout.source_col)
+    let source_col = layout.source_col.unwrap_or(usize::MAX);
+    let available = safe_slice(line, layout.available_col, source_col)?
         .trim()
         .to_string();
-    let source = if line.len() > layout.source_col {
-        line[layout.source_col..].trim().to_string()
-    } else {
-        String::new()
-    };
+    let source = safe_slice(line, source_col, usize::MAX)?.trim().to_string();
 
     if name.is_empty() || id.is_empty() {
         return None;
@@ -195,35 +431,283 @@ fn parse_app_line(line: &str, layout: &ColumnLayout) -> Option<UpdatableApp> {
     Some(UpdatableApp::new(name, id, version, available, source))
 }
 
-/// Safely slices a string by byte range, clamping to the string length.
-fn safe_slice(s: &str, start: usize, end: usize) -> &str {
-    let start = start.min(s.len());
-    let end = end.min(s.len());
-    &s[start..end]
+/// Splits a list-style data line into name, ID, version, middle column and
+/// source; absent columns yield empty fields.
+fn split_list_line(line: &str, layout: &ListLayout) -> Option<[String; 5]> {
+    if line.chars().count() <= layout.id_col {
+        return None;
+    }
+
+    // Each column ends where the next present one starts.
+    let end = |col: Option<usize>| col.unwrap_or(usize::MAX);
+    let version_end = end(layout.extra_col.or(layout.source_col));
+    let extra_end = end(layout.source_col);
+    let optional = |col: Option<usize>, end: usize| match col {
+        Some(col) => safe_slice(line, col, end).map(|s| s.trim().to_string()),
+        None => Some(String::new()),
+    };
+
+    let name = safe_slice(line, 0, layout.id_col)?.trim().to_string();
+    let id = safe_slice(line, layout.id_col, layout.version_col)?
+        .trim()
+        .to_string();
+    let version = safe_slice(line, layout.version_col, version_end)?
+        .trim()
+        .to_string();
+    let extra = optional(layout.extra_col, extra_end)?;
+    let source = optional(layout.source_col, usize::MAX)?;
+
+    if name.is_empty() || id.is_empty() {
+        return None;
+    }
+
+    Some([name, id, version, extra, source])
+}
+
+/// Returns the character position of a column heading, if present.
+fn column(header: &str, name: &str) -> Option<usize> {
+    header.find(name).map(|i| header[..i].chars().count())
+}
+
+/// Slices a string by character range, clamping to the string length.
+///
+/// winget aligns its tables by character, so byte positions would land
+/// inside a `…` it added to a shortened name, or inside non-Latin text.
+/// Returns `None` rather than panicking if the range is not valid.
+fn safe_slice(s: &str, start: usize, end: usize) -> Option<&str> {
+    let offset = |col: usize| s.char_indices().nth(col).map_or(s.len(), |(i, _)| i);
+    let start = offset(start);
+    s.get(start..offset(end).max(start))
+}
+
+/// Updates a single application by its winget ID, from its source unless
+/// that is empty. Source agreements are only accepted if the request says
+/// so, so the Microsoft Store's can be agreed to separately.
+pub fn update_single_app(request: &UpgradeRequest) -> Result<String, String> {
+    let (success, stdout, combined) = run_package_command(&request.id, &update_args(request))?;
+    classify_update_result(&request.id, success, &stdout, &combined)
 }
 
-/// Updates a single application by its winget ID.
-pub fn update_single_app(app_id: &str) -> Result<String, String> {
+/// Builds the `winget upgrade` arguments for a single package.
+pub fn update_args(request: &UpgradeRequest) -> Vec<&str> {
+    let mut args = vec!["upgrade", "--id", request.id.as_str()];
+    if !request.source.is_empty() {
+        args.extend(["--source", request.source.as_str()]);
+    }
+    args.extend(request.options.args());
+    args.extend(request.extra_args.iter().map(String::as_str));
+    if request.accept_source_agreements {
+        args.push("--accept-source-agreements");
+    }
+    args.extend(["--accept-package-agreements", "-h"]);
+    args
+}
+
+/// Formats a winget invocation for display, quoting arguments with spaces.
+pub fn format_command_line(args: &[&str]) -> String {
+    std::iter::once("winget")
+        .chain(args.iter().copied())
+        .map(|arg| {
+            if arg.is_empty() || arg.contains(char::is_whitespace) {
+                format!("\"{arg}\"")
+            } else {
+                arg.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Runs a `winget source` subcommand. Agreements are only accepted for
+/// added sources, and only if `accept_agreements` is set.
+pub fn run_source_action(action: &SourceAction, accept_agreements: bool) -> Result<String, String> {
+    let label = action.name().unwrap_or("all sources");
+    let args = source_args(action, accept_agreements);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let (success, stdout, combined) = run_package_command(label, &args)?;
+    classify_source_result(action, success, &stdout, &combined)
+}
+
+/// Builds the `winget source` arguments for `action`.
+fn source_args(action: &SourceAction, accept_agreements: bool) -> Vec<String> {
+    let mut args = vec![String::from("source")];
+    let mut name_arg = |command: &str, name: Option<&String>| {
+        args.push(command.into());
+        if let Some(name) = name {
+            args.extend(["--name".into(), name.clone()]);
+        }
+    };
+    match action {
+        SourceAction::Add {
+            name,
+            argument,
+            kind,
+        } => {
+            name_arg("add", Some(name));
+            args.extend(["--arg".into(), argument.clone()]);
+            if !kind.is_empty() {
+                args.extend(["--type".into(), kind.clone()]);
+            }
+            if accept_agreements {
+                args.push("--accept-source-agreements".into());
+            }
+        }
+        SourceAction::Remove(name) => name_arg("remove", Some(name)),
+        SourceAction::Update(name) => name_arg("update", name.as_ref()),
+        SourceAction::Reset(name) => {
+            name_arg("reset", name.as_ref());
+            // Resetting every source needs confirming.
+            if name.is_none() {
+                args.push("--force".into());
+            }
+        }
+    }
+    args
+}
+
+/// Extracts the agreements winget asks to accept before using a source:
+/// the lines between its "requires that you view the following agreements"
+/// notice and the "Do you agree" prompt.
+pub fn source_agreements(output: &str) -> Vec<String> {
+    let sanitized = sanitize_output(output);
+    sanitized
+        .lines()
+        .skip_while(|line| !line.contains("view the following agreements"))
+        .skip(1)
+        .take_while(|line| !line.contains("Do you agree"))
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Classifies the result of a `winget source` subcommand.
+fn classify_source_result(
+    action: &SourceAction,
+    success: bool,
+    stdout: &str,
+    combined: &str,
+) -> Result<String, String> {
+    let label = action.name().unwrap_or("all sources");
+    let agreements = source_agreements(combined);
+    if !success && !agreements.is_empty() {
+        return Ok(format!(
+            "[!] {label} - requires accepting the source agreements: {}",
+            agreements.join(" ")
+        ));
+    }
+    if !success && combined.contains("administrator") {
+        return Ok(format!("[!] {label} - requires administrator privileges"));
+    }
+
+    if success {
+        let done = match action {
+            SourceAction::Add { .. } => "added",
+            SourceAction::Remove(_) => "removed",
+            SourceAction::Update(_) => "updated",
+            SourceAction::Reset(_) => "reset",
+        };
+        return Ok(format!("SUCCESS:{label} - source {done}"));
+    }
+    if combined.contains("already exists") {
+        return Err(format!("FAILURE:{label} - a source with this name already exists"));
+    }
+    if combined.contains("Did not find a source named") {
+        return Err(format!("FAILURE:{label} - source not found"));
+    }
+
+    Err(format!(
+        "FAILURE:{label} - {}",
+        extract_error(stdout, combined)
+    ))
+}
+
+/// Silently uninstalls a single package by its winget ID. Source agreements
+/// are only accepted if `accept_source_agreements` is set.
+pub fn uninstall_single_app(
+    app_id: &str,
+    accept_source_agreements: bool,
+) -> Result<String, String> {
+    let (success, stdout, combined) =
+        run_package_command(app_id, &uninstall_args(app_id, accept_source_agreements))?;
+    classify_uninstall_result(app_id, success, &stdout, &combined)
+}
+
+/// Builds the `winget uninstall` arguments for a single package.
+fn uninstall_args(app_id: &str, accept_source_agreements: bool) -> Vec<&str> {
+    let mut args = vec!["uninstall", "--id", app_id, "--exact", "--silent"];
+    if accept_source_agreements {
+        args.push("--accept-source-agreements");
+    }
+    args
+}
+
+/// Installs (or upgrades) a package with the requested version and scope.
+pub fn install_single_app(request: &InstallRequest) -> Result<String, String> {
+    let (success, stdout, combined) =
+        run_package_command(&request.id, &install_args(request))?;
+    if request.upgrade {
+        classify_update_result(&request.id, success, &stdout, &combined)
+    } else {
+        classify_install_result(&request.id, success, &stdout, &combined)
+    }
+}
+
+/// Builds the `winget install` (or `upgrade`) arguments for `request`.
+fn install_args(request: &InstallRequest) -> Vec<&str> {
+    let command = if request.upgrade { "upgrade" } else { "install" };
+    let mut args = vec![command, "--id", request.id.as_str(), "--exact"];
+    if !request.source.is_empty() {
+        args.extend(["--source", request.source.as_str()]);
+    }
+    if let Some(version) = &request.version {
+        args.extend(["--version", version.as_str()]);
+    }
+    let options = &request.options;
+    if let Some(scope) = request.scope.arg().or(options.scope.arg()) {
+        args.extend(["--scope", scope]);
+    }
+    if let Some(architecture) = options.architecture.arg() {
+        args.extend(["--architecture", architecture]);
+    }
+    if !options.installer_type.is_empty() {
+        args.extend(["--installer-type", options.installer_type.as_str()]);
+    }
+    if !options.locale.is_empty() {
+        args.extend(["--locale", options.locale.as_str()]);
+    }
+    args.extend(request.extra_args.iter().map(String::as_str));
+    args.push("--silent");
+    if request.accept_source_agreements {
+        args.push("--accept-source-agreements");
+    }
+    args.push("--accept-package-agreements");
+    args
+}
+
+/// Runs a per-package winget command and returns its exit status, standard
+/// output, and standard output and error combined.
+fn run_package_command(app_id: &str, args: &[&str]) -> Result<(bool, String, String), String> {
     let mut cmd = Command::new("winget");
-    cmd.args([
-        "upgrade",
-        "--id",
-        app_id,
-        "--accept-source-agreements",
-        "--accept-package-agreements",
-        "-h",
-    ]);
+    cmd.args(args);
     #[cfg(windows)]
     cmd.creation_flags(CREATE_NO_WINDOW);
     let output = cmd
         .output()
         .map_err(|e| format!("Failed to execute winget for {app_id}: {e}"))?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
     let stderr = String::from_utf8_lossy(&output.stderr);
     let combined = format!("{stdout}\n{stderr}");
+    Ok((output.status.success(), stdout, combined))
+}
 
-    classify_update_result(app_id, output.status.success(), &stdout, &combined)
+/// Returns `true` if winget reports that the package is running.
+fn needs_close(combined: &str) -> bool {
+    combined.contains("application must be closed")
+        || combined.contains("Close the application")
+        || combined.contains("currently in use")
+        || combined.contains("close all instances")
 }
 
 /// Classifies the update result based on winget output.
@@ -233,16 +717,14 @@ fn classify_update_result(
     stdout: &str,
     combined: &str,
 ) -> Result<String, String> {
-    let needs_close = combined.contains("application must be closed")
-        || combined.contains("Close the application")
-        || combined.contains("currently in use")
-        || combined.contains("close all instances");
-
-    if needs_close {
+    if needs_close(combined) {
         return Ok(format!(
             "[!] {app_id} - needs to be closed before updating"
         ));
     }
+    if let Some(result) = classify_store_outcome(app_id, success, combined) {
+        return Ok(result);
+    }
 
     if success {
         return Ok(classify_success(app_id, stdout));
@@ -254,6 +736,22 @@ fn classify_update_result(
     ))
 }
 
+/// Detects the outcomes specific to source agreements and Store-managed
+/// apps, which are worth a warning rather than a failure.
+fn classify_store_outcome(app_id: &str, success: bool, combined: &str) -> Option<String> {
+    if !success && !source_agreements(combined).is_empty() {
+        return Some(format!(
+            "[!] {app_id} - requires accepting the source agreements"
+        ));
+    }
+    if managed_by_store(combined) {
+        return Some(format!(
+            "[i] {app_id} - managed by the Microsoft Store; update it from the Store app"
+        ));
+    }
+    None
+}
+
 /// Classifies a successful winget exit into a specific result string.
 fn classify_success(app_id: &str, stdout: &str) -> String {
     if stdout.contains("Successfully installed") || stdout.contains("successfully") {
@@ -269,6 +767,96 @@ fn classify_success(app_id: &str, stdout: &str) -> String {
     }
 }
 
+/// Classifies the install result based on winget output.
+fn classify_install_result(
+    app_id: &str,
+    success: bool,
+    stdout: &str,
+    combined: &str,
+) -> Result<String, String> {
+    if needs_close(combined) {
+        return Ok(format!(
+            "[!] {app_id} - needs to be closed before installing"
+        ));
+    }
+
+    if let Some(result) = classify_store_outcome(app_id, success, combined) {
+        return Ok(result);
+    }
+    if stdout.contains("Found an existing package already installed")
+        && !stdout.contains("Successfully installed")
+    {
+        return Ok(format!("[i] {app_id} - already installed"));
+    }
+    if stdout.contains("No package found") {
+        return Err(format!("FAILURE:{app_id} - package not found"));
+    }
+    if stdout.contains("No version found") {
+        return Err(format!("FAILURE:{app_id} - requested version not found"));
+    }
+    if stdout.contains("No applicable installer found") {
+        return Err(format!("FAILURE:{app_id} - no installer for the requested scope"));
+    }
+
+    if success {
+        return Ok(if stdout.contains("Successfully installed") {
+            format!("SUCCESS:{app_id} - installed successfully")
+        } else {
+            format!("SUCCESS:{app_id} - completed")
+        });
+    }
+
+    Err(format!(
+        "FAILURE:{app_id} - {}",
+        extract_error(stdout, combined)
+    ))
+}
+
+/// Classifies the uninstall result based on winget output.
+///
+/// Uninstallers that ignore `--silent` and insist on showing their own UI are
+/// reported as warnings rather than failures, since the user can still remove
+/// them interactively.
+fn classify_uninstall_result(
+    app_id: &str,
+    success: bool,
+    stdout: &str,
+    combined: &str,
+) -> Result<String, String> {
+    if needs_close(combined) {
+        return Ok(format!(
+            "[!] {app_id} - needs to be closed before uninstalling"
+        ));
+    }
+
+    let lower = combined.to_lowercase();
+    if !success && (lower.contains("interactive") || lower.contains("user interaction")) {
+        return Ok(format!("[!] {app_id} - requires interactive uninstall"));
+    }
+
+    if stdout.contains("No installed package found") {
+        return Err(format!("FAILURE:{app_id} - package not found"));
+    }
+    if stdout.contains("Multiple installed packages found")
+        || stdout.contains("Multiple packages found")
+    {
+        return Err(format!("FAILURE:{app_id} - matches several packages"));
+    }
+
+    if success {
+        return Ok(if stdout.contains("Successfully uninstalled") {
+            format!("SUCCESS:{app_id} - uninstalled successfully")
+        } else {
+            format!("SUCCESS:{app_id} - completed")
+        });
+    }
+
+    Err(format!(
+        "FAILURE:{app_id} - {}",
+        extract_error(stdout, combined)
+    ))
+}
+
 /// Extracts a concise error message from winget output.
 fn extract_error(stdout: &str, combined: &str) -> String {
     let stderr_part = combined
@@ -282,8 +870,7 @@ fn extract_error(stdout: &str, combined: &str) -> String {
     } else {
         stdout
             .lines()
-            .filter(|l| !l.trim().is_empty())
-            .next_back()
+            .rfind(|l| !l.trim().is_empty())
             .unwrap_or("Update failed")
             .trim()
     };
@@ -295,6 +882,7 @@ fn extract_error(stdout: &str, combined: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::InstallScope;
 
     #[test]
     fn test_parse_empty_output() {
@@ -368,9 +956,9 @@ short";
     #[test]
     fn test_safe_slice_clamping() {
         let s = "hello";
-        assert_eq!(safe_slice(s, 0, 100), "hello");
-        assert_eq!(safe_slice(s, 3, 100), "lo");
-        assert_eq!(safe_slice(s, 100, 200), "");
+        assert_eq!(safe_slice(s, 0, 100), Some("hello"));
+        assert_eq!(safe_slice(s, 3, 100), Some("lo"));
+        assert_eq!(safe_slice(s, 100, 200), Some(""));
     }
 
     #[test]
@@ -643,7 +1231,7 @@ Notepad++  Notepad++.N 8.5.0     8.6.0       winget
             id_col: 10,
             version_col: 25,
             available_col: 40,
-            source_col: 55,
+            source_col: Some(55),
         };
         // Line exactly at id_col boundary
         let result = parse_app_line("0123456789", &layout);
@@ -657,7 +1245,7 @@ Notepad++  Notepad++.N 8.5.0     8.6.0       winget
             id_col: 10,
             version_col: 25,
             available_col: 40,
-            source_col: 55,
+            source_col: Some(55),
         };
         let line = format!(
             "{:<10}{:<15}{:<15}{:<15}{}",
@@ -715,14 +1303,555 @@ Extra text that should be ignored";
         assert_eq!(apps.len(), 1);
     }
 
+    #[test]
+    fn test_parse_winget_list_with_unmanaged_entries() {
+        let row = |name: &str, id: &str, version: &str, available: &str, source: &str| {
+            format!("{name:<30}{id:<45}{version:<15}{available:<15}{source}")
+                .trim_end()
+                .to_string()
+        };
+        let output = [
+            row("Name", "Id", "Version", "Available", "Source"),
+            "-".repeat(111),
+            row("Git", "Git.Git", "2.43.0", "2.44.0", "winget"),
+            row("Mozilla Firefox (x64 en-US)", "Mozilla.Firefox", "122.0", "", "winget"),
+            row("Contoso Legacy Tool", r"ARP\Machine\X64\{6F3D1A2B-0000-1111}", "3.1", "", ""),
+            row("Windows Calculator", r"MSIX\Microsoft.WindowsCalculator_11.2307", "11.2307.4.0", "", ""),
+        ]
+        .join("\n");
+
+        let apps = parse_winget_list(&output).unwrap();
+        assert_eq!(apps.len(), 4);
+
+        assert_eq!(apps[0].id, "Git.Git");
+        assert_eq!(apps[0].available, "2.44.0");
+        assert!(apps[0].is_managed());
+
+        assert_eq!(apps[1].name, "Mozilla Firefox (x64 en-US)");
+        assert_eq!(apps[1].version, "122.0");
+        assert_eq!(apps[1].available, "");
+        assert_eq!(apps[1].source, "winget");
+
+        assert_eq!(apps[2].name, "Contoso Legacy Tool");
+        assert_eq!(apps[2].id, r"ARP\Machine\X64\{6F3D1A2B-0000-1111}");
+        assert_eq!(apps[2].version, "3.1");
+        assert_eq!(apps[2].source, "");
+        assert!(!apps[2].is_managed());
+
+        assert_eq!(apps[3].version, "11.2307.4.0");
+        assert!(!apps[3].is_managed());
+    }
+
+    #[test]
+    fn test_parse_winget_list_without_optional_columns() {
+        let output = "\
+Name                Id                    Version
+--------------------------------------------------
+Contoso Tool        ARP\\Machine\\X64\\Tool   1.0
+Git                 Git.Git               2.43.0
+";
+        let apps = parse_winget_list(output).unwrap();
+        assert_eq!(apps.len(), 2);
+        assert_eq!(apps[0].id, "ARP\\Machine\\X64\\Tool");
+        assert_eq!(apps[0].version, "1.0");
+        assert_eq!(apps[1].version, "2.43.0");
+        assert_eq!(apps[1].available, "");
+        assert_eq!(apps[1].source, "");
+    }
+
+    #[test]
+    fn test_parse_winget_list_with_shortened_name() {
+        let output = "\
+Name                Id           Version Available Source
+---------------------------------------------------------
+Microsoft Visual S… Microsoft.VS 1.0     2.0       winget
+Git                 Git.Git      2.43.0            winget
+";
+        let apps = parse_winget_list(output).unwrap();
+        assert_eq!(apps.len(), 2);
+        assert_eq!(apps[0].name, "Microsoft Visual S");
+        assert_eq!(apps[0].id, "Microsoft.VS");
+        assert_eq!(apps[0].version, "1.0");
+        assert_eq!(apps[0].available, "2.0");
+        assert_eq!(apps[0].source, "winget");
+        assert_eq!(apps[1].id, "Git.Git");
+    }
+
+    #[test]
+    fn test_parse_winget_list_with_non_latin_name() {
+        let output = "\
+Name           Id                 Version Source
+------------------------------------------------
+Яндекс Браузер Yandex.Browser     24.1    winget
+Κείμενο        Contoso.Keimeno    2.0     winget
+";
+        let apps = parse_winget_list(output).unwrap();
+        assert_eq!(apps.len(), 2);
+        assert_eq!(apps[0].name, "Яндекс Браузер");
+        assert_eq!(apps[0].id, "Yandex.Browser");
+        assert_eq!(apps[0].version, "24.1");
+        assert_eq!(apps[1].name, "Κείμενο");
+        assert_eq!(apps[1].id, "Contoso.Keimeno");
+        assert_eq!(apps[1].source, "winget");
+    }
+
+    #[test]
+    fn test_parse_winget_list_empty() {
+        assert!(parse_winget_list("No installed package found matching input criteria.\n")
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn test_safe_slice_zero_range() {
-        assert_eq!(safe_slice("hello", 3, 3), "");
+        assert_eq!(safe_slice("hello", 3, 3), Some(""));
     }
 
     #[test]
     fn test_safe_slice_start_beyond_end() {
-        assert_eq!(safe_slice("hello", 10, 5), "");
+        assert_eq!(safe_slice("hello", 10, 5), Some(""));
+    }
+
+    #[test]
+    fn test_classify_uninstall_result_success() {
+        let result = classify_uninstall_result(
+            "Test.App",
+            true,
+            "Starting package uninstall...\nSuccessfully uninstalled",
+            "Starting package uninstall...\nSuccessfully uninstalled\n",
+        );
+        assert_eq!(result, Ok("SUCCESS:Test.App - uninstalled successfully".into()));
+    }
+
+    #[test]
+    fn test_classify_uninstall_result_requires_interactive() {
+        let output = "Uninstall failed. This package requires an interactive uninstall; \
+                      run winget uninstall --interactive.";
+        let result = classify_uninstall_result("Test.App", false, output, output);
+        assert_eq!(result, Ok("[!] Test.App - requires interactive uninstall".into()));
+    }
+
+    #[test]
+    fn test_classify_uninstall_result_success_mentioning_interactive() {
+        let output = "Uninstalling without user interaction...\nSuccessfully uninstalled";
+        let result = classify_uninstall_result("Test.App", true, output, output);
+        assert_eq!(result, Ok("SUCCESS:Test.App - uninstalled successfully".into()));
+    }
+
+    #[test]
+    fn test_classify_uninstall_result_needs_close() {
+        let result = classify_uninstall_result("Test.App", false, "", "currently in use");
+        assert!(result.unwrap().contains("before uninstalling"));
+    }
+
+    #[test]
+    fn test_classify_uninstall_result_not_found() {
+        let output = "No installed package found matching input criteria.";
+        let result = classify_uninstall_result("Gone.App", false, output, output);
+        assert_eq!(result, Err("FAILURE:Gone.App - package not found".into()));
+    }
+
+    #[test]
+    fn test_classify_uninstall_result_failure() {
+        let result = classify_uninstall_result("Test.App", false, "", "Uninstaller exited with 1603\n");
+        let err = result.unwrap_err();
+        assert!(err.starts_with("FAILURE:Test.App"));
+        assert!(err.contains("1603"));
+    }
+
+    #[test]
+    fn test_parse_winget_search_with_match_column() {
+        let row = |name: &str, id: &str, version: &str, matched: &str, source: &str| {
+            format!("{name:<25}{id:<30}{version:<15}{matched:<20}{source}")
+                .trim_end()
+                .to_string()
+        };
+        let output = [
+            row("Name", "Id", "Version", "Match", "Source"),
+            "-".repeat(96),
+            row("Mozilla Firefox", "Mozilla.Firefox", "131.0.3", "", "winget"),
+            row("Floorp", "Ablaze.Floorp", "11.19.1", "Tag: firefox", "winget"),
+            row("Firefox Nightly", "9NZW26FRNDLN", "Unknown", "", "msstore"),
+        ]
+        .join("\n");
+
+        let results = parse_winget_search(&output).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].name, "Mozilla Firefox");
+        assert_eq!(results[0].id, "Mozilla.Firefox");
+        assert_eq!(results[0].version, "131.0.3");
+        assert_eq!(results[0].matched, "");
+        assert_eq!(results[1].matched, "Tag: firefox");
+        assert_eq!(results[2].id, "9NZW26FRNDLN");
+        assert_eq!(results[2].source, "msstore");
+    }
+
+    #[test]
+    fn test_parse_winget_search_no_results() {
+        assert!(parse_winget_search("No package found matching input criteria.\n")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_update_args_include_package_options() {
+        let request = UpgradeRequest {
+            id: "Git.Git".into(),
+            source: "winget".into(),
+            accept_source_agreements: true,
+            ..UpgradeRequest::default()
+        };
+        assert_eq!(
+            update_args(&request),
+            [
+                "upgrade",
+                "--id",
+                "Git.Git",
+                "--source",
+                "winget",
+                "--accept-source-agreements",
+                "--accept-package-agreements",
+                "-h"
+            ]
+        );
+        let request = UpgradeRequest {
+            id: "Git.Git".into(),
+            options: "scope=machine arch=arm64 type=msi locale=en-US".parse().unwrap(),
+            extra_args: vec!["--custom".into(), "/NoShortcut".into()],
+            ..UpgradeRequest::default()
+        };
+        assert_eq!(
+            update_args(&request),
+            [
+                "upgrade",
+                "--id",
+                "Git.Git",
+                "--scope",
+                "machine",
+                "--architecture",
+                "arm64",
+                "--installer-type",
+                "msi",
+                "--locale",
+                "en-US",
+                "--custom",
+                "/NoShortcut",
+                "--accept-package-agreements",
+                "-h"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_command_line_quotes_spaced_arguments() {
+        assert_eq!(
+            format_command_line(&["upgrade", "--id", "Git.Git", "--override", "/S /D=C:\\Git"]),
+            "winget upgrade --id Git.Git --override \"/S /D=C:\\Git\""
+        );
     }
-}
 
+    #[test]
+    fn test_search_args_pass_query_as_value() {
+        assert_eq!(
+            search_args("--help"),
+            ["search", "--query", "--help", "--accept-source-agreements"]
+        );
+    }
+
+    #[test]
+    fn test_install_args() {
+        let mut request = InstallRequest::new("Git".into(), "Git.Git".into(), "winget".into());
+        assert_eq!(
+            install_args(&request),
+            [
+                "install",
+                "--id",
+                "Git.Git",
+                "--exact",
+                "--source",
+                "winget",
+                "--silent",
+                "--accept-source-agreements",
+                "--accept-package-agreements",
+            ]
+        );
+
+        request.version = Some("2.44.0".into());
+        request.scope = InstallScope::Machine;
+        let args = install_args(&request);
+        assert!(args.windows(2).any(|w| w == ["--version", "2.44.0"]));
+        assert!(args.windows(2).any(|w| w == ["--scope", "machine"]));
+
+        request.upgrade = true;
+        assert_eq!(install_args(&request)[0], "upgrade");
+    }
+
+    #[test]
+    fn test_classify_install_result() {
+        let ok = "Found Git [Git.Git]\nSuccessfully installed";
+        assert_eq!(
+            classify_install_result("Git.Git", true, ok, ok),
+            Ok("SUCCESS:Git.Git - installed successfully".into())
+        );
+
+        let existing = "Found an existing package already installed.";
+        assert!(classify_install_result("Git.Git", false, existing, existing)
+            .unwrap()
+            .starts_with("[i]"));
+
+        let no_version = "No version found matching: 0.1";
+        assert_eq!(
+            classify_install_result("Git.Git", false, no_version, no_version),
+            Err("FAILURE:Git.Git - requested version not found".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_winget_output_without_source_column() {
+        // `winget upgrade --source X` leaves the Source column out.
+        let output = "Name     Id        Version Available\n\
+                      -------------------------------------\n\
+                      Git      Git.Git   2.43.0  2.44.0\n";
+        let apps = parse_winget_output(output).unwrap();
+        assert_eq!(apps.len(), 1);
+        assert_eq!(apps[0].available, "2.44.0");
+        assert_eq!(apps[0].source, "");
+    }
+
+    #[test]
+    fn test_parse_winget_sources() {
+        let output = "Name     Argument                                      Explicit\n\
+                      ---------------------------------------------------------------\n\
+                      msstore  https://storeedgefd.dsx.mp.microsoft.com/v9.0 false\n\
+                      winget   https://cdn.winget.microsoft.com/cache        false\n\
+                      contoso  https://packages.contoso.com/api              true\n";
+        let sources = parse_winget_sources(output).unwrap();
+        assert_eq!(sources.len(), 3);
+        assert_eq!(sources[0].name, "msstore");
+        assert_eq!(sources[1].argument, "https://cdn.winget.microsoft.com/cache");
+        assert!(!sources[1].explicit);
+        assert!(sources[2].explicit);
+
+        // Older winget versions have no Explicit column.
+        let output = "\r-\r\\\rName   Argument\n\
+                      ----------------------------------------------\n\
+                      winget https://cdn.winget.microsoft.com/cache\n";
+        let sources = parse_winget_sources(output).unwrap();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].argument, "https://cdn.winget.microsoft.com/cache");
+
+        assert!(parse_winget_sources("There are no sources configured.\n")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_enabled_sources() {
+        let source = |name: &str| SourceInfo {
+            name: name.into(),
+            argument: String::new(),
+            explicit: false,
+        };
+        let sources = [source("msstore"), source("winget"), source("contoso")];
+        assert_eq!(
+            enabled_sources(&sources, &["msstore".into()]),
+            ["winget", "contoso"]
+        );
+
+        let explicit = SourceInfo {
+            explicit: true,
+            ..source("private")
+        };
+        assert_eq!(enabled_sources(&[source("winget"), explicit], &[]), ["winget"]);
+    }
+
+    #[test]
+    fn test_merge_source_listings_collects_failures() {
+        let output = "Name Id Version Available\n\
+                      --------------------------\n\
+                      Git  Git.Git 2.0 2.1\n";
+        let (apps, errors) = merge_source_listings(vec![
+            ("winget".into(), Ok(output.into())),
+            ("contoso".into(), Err("source unreachable".into())),
+        ])
+        .unwrap();
+        assert_eq!(apps.len(), 1);
+        assert_eq!(apps[0].source, "winget");
+        assert_eq!(errors, ["contoso: source unreachable"]);
+
+        let all_failed = merge_source_listings(vec![("contoso".into(), Err("down".into()))]);
+        assert_eq!(all_failed.unwrap_err(), "contoso: down");
+        assert_eq!(merge_source_listings(Vec::new()).unwrap(), (Vec::new(), Vec::new()));
+    }
+
+    #[test]
+    fn test_source_args() {
+        let add = SourceAction::Add {
+            name: "contoso".into(),
+            argument: "https://packages.contoso.com/api".into(),
+            kind: "Microsoft.Rest".into(),
+        };
+        assert_eq!(
+            source_args(&add, false),
+            [
+                "source",
+                "add",
+                "--name",
+                "contoso",
+                "--arg",
+                "https://packages.contoso.com/api",
+                "--type",
+                "Microsoft.Rest"
+            ]
+        );
+        assert_eq!(
+            source_args(&add, true).last().map(String::as_str),
+            Some("--accept-source-agreements")
+        );
+        assert_eq!(
+            source_args(&SourceAction::Remove("contoso".into()), true),
+            ["source", "remove", "--name", "contoso"]
+        );
+        assert_eq!(source_args(&SourceAction::Update(None), false), ["source", "update"]);
+        assert_eq!(
+            source_args(&SourceAction::Reset(Some("winget".into())), false),
+            ["source", "reset", "--name", "winget"]
+        );
+        assert_eq!(
+            source_args(&SourceAction::Reset(None), false),
+            ["source", "reset", "--force"]
+        );
+    }
+
+    #[test]
+    fn test_source_agreements() {
+        let output = "The `msstore` source requires that you view the following agreements before using.\n\
+                      Terms of Transaction: https://aka.ms/microsoft-store-terms-of-transaction\n\
+                      The source requires the current machine's 2-letter geographic region to be sent to the backend service to function properly (ex. \"US\").\n\
+                      \n\
+                      Do you agree to all the source agreements terms?\n\
+                      [Y] Yes  [N] No: ";
+        let agreements = source_agreements(output);
+        assert_eq!(agreements.len(), 2);
+        assert!(agreements[0].starts_with("Terms of Transaction:"));
+        assert!(source_agreements("Done").is_empty());
+
+        let add = SourceAction::Add {
+            name: "msstore".into(),
+            argument: "https://storeedgefd.dsx.mp.microsoft.com/v9.0".into(),
+            kind: String::new(),
+        };
+        let result = classify_source_result(&add, false, output, output).unwrap();
+        assert!(result.starts_with("[!] msstore - requires accepting the source agreements:"));
+        assert!(result.contains("https://aka.ms/microsoft-store-terms-of-transaction"));
+    }
+
+    #[test]
+    fn test_classify_source_result() {
+        let remove = SourceAction::Remove("contoso".into());
+        assert_eq!(
+            classify_source_result(&remove, true, "Done", "Done\n"),
+            Ok("SUCCESS:contoso - source removed".into())
+        );
+        let missing = "Did not find a source named: contoso";
+        assert_eq!(
+            classify_source_result(&remove, false, missing, missing),
+            Err("FAILURE:contoso - source not found".into())
+        );
+        let admin = "This command requires administrator privileges to execute.";
+        assert!(classify_source_result(&SourceAction::Reset(None), false, admin, admin)
+            .unwrap()
+            .starts_with("[!] all sources - requires administrator"));
+        let note = "Source added; administrators can restrict it with group policy.";
+        assert_eq!(
+            classify_source_result(&SourceAction::Update(None), true, note, note),
+            Ok("SUCCESS:all sources - source updated".into())
+        );
+    }
+
+    #[test]
+    fn test_is_store_product_id() {
+        assert!(is_store_product_id("9NBLGGH4NNS1"));
+        assert!(is_store_product_id("XP89DCGQ3K6VLD"));
+        assert!(!is_store_product_id("Git.Git"));
+        assert!(!is_store_product_id("9nblggh4nns1"));
+        assert!(!is_store_product_id("9NBLGGH4NNS"));
+    }
+
+    #[test]
+    fn test_clean_name_and_unnamed_store_apps() {
+        assert_eq!(clean_name("Visual Studio Co…"), "Visual Studio Co");
+        assert_eq!(clean_name("Paint"), "Paint");
+        assert!(is_unnamed_store_app("9NBLGGH4NNS1", "9NBLGGH4NNS1", STORE_SOURCE));
+        assert!(is_unnamed_store_app("", "XP89DCGQ3K6VLD", STORE_SOURCE));
+        assert!(!is_unnamed_store_app("Paint", "9PCFS5B6T72H", STORE_SOURCE));
+        assert!(!is_unnamed_store_app("9NBLGGH4NNS1", "9NBLGGH4NNS1", "winget"));
+    }
+
+    #[test]
+    fn test_parse_winget_list_keeps_raw_store_ids() {
+        let output = "Name           Id             Version Source\n\
+                      ---------------------------------------------\n\
+                      9NBLGGH4NNS1   9NBLGGH4NNS1   1.0     msstore\n";
+        let apps = parse_winget_list(output).unwrap();
+        assert_eq!(apps[0].name, "9NBLGGH4NNS1");
+        assert!(is_unnamed_store_app(&apps[0].name, &apps[0].id, &apps[0].source));
+        assert_eq!(apps[0].id, "9NBLGGH4NNS1");
+    }
+
+    #[test]
+    fn test_classify_store_outcomes() {
+        let managed = "This package is managed by the Microsoft Store.";
+        assert_eq!(
+            classify_update_result("9NBLGGH4NNS1", false, managed, managed),
+            Ok("[i] 9NBLGGH4NNS1 - managed by the Microsoft Store; update it from the Store app"
+                .into())
+        );
+
+        let agreements = "The `msstore` source requires that you view the following agreements before using.\n\
+                          Terms of Transaction: https://aka.ms/microsoft-store-terms-of-transaction\n\
+                          Do you agree to all the source agreements terms?";
+        assert_eq!(
+            classify_install_result("9NBLGGH4NNS1", false, agreements, agreements),
+            Ok("[!] 9NBLGGH4NNS1 - requires accepting the source agreements".into())
+        );
+    }
+
+    #[test]
+    fn test_uninstall_args() {
+        assert_eq!(
+            uninstall_args("Foo", true),
+            ["uninstall", "--id", "Foo", "--exact", "--silent", "--accept-source-agreements"]
+        );
+        assert!(!uninstall_args("9NBLGGH4NNS1", false).contains(&"--accept-source-agreements"));
+    }
+
+    #[test]
+    fn test_install_args_apply_options_to_upgrades() {
+        let mut request = InstallRequest::new("Git".into(), "Git.Git".into(), String::new());
+        request.upgrade = true;
+        request.options = "scope=machine arch=x64 locale=en-US".parse().unwrap();
+        let args = install_args(&request);
+        assert!(args.windows(2).any(|w| w == ["--scope", "machine"]));
+        assert!(args.windows(2).any(|w| w == ["--architecture", "x64"]));
+        assert!(args.windows(2).any(|w| w == ["--locale", "en-US"]));
+
+        request.scope = InstallScope::User;
+        assert!(install_args(&request).windows(2).any(|w| w == ["--scope", "user"]));
+
+        request.extra_args = vec!["--custom".into(), "/NoShortcut".into()];
+        assert!(install_args(&request)
+            .windows(2)
+            .any(|w| w == ["--custom", "/NoShortcut"]));
+    }
+
+    #[test]
+    fn test_install_args_without_source_agreements() {
+        let mut request =
+            InstallRequest::new("Paint".into(), "9PCFS5B6T72H".into(), STORE_SOURCE.into());
+        request.accept_source_agreements = false;
+        let args = install_args(&request);
+        assert!(!args.contains(&"--accept-source-agreements"));
+        assert!(args.contains(&"--accept-package-agreements"));
+    }
+}
@@ -1,4 +1,8 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::Path;
 use win_apps_updater::models::{AppItem, UpdatableApp};
+use win_apps_updater::rest_source::{RestServer, RestSource};
 use win_apps_updater::winget::parse_winget_output;
 
 // ── Model tests ──────────────────────────────────────────────────────
@@ -350,9 +354,11 @@ fn test_parse_wrapped_winget_output() {
 
 #[test]
 fn test_app_state_progress_percentage() {
-    let mut state = win_apps_updater::app::AppState::default();
-    state.update_total = 5;
-    state.update_completed = 3;
+    let state = win_apps_updater::app::AppState {
+        update_total: 5,
+        update_completed: 3,
+        ..Default::default()
+    };
     let pct = if state.update_total > 0 {
         state.update_completed as f32 / state.update_total as f32 * 100.0
     } else {
@@ -472,3 +478,115 @@ fn test_parse_winget_many_apps() {
     assert_eq!(apps[9].name, "App 9");
 }
 
+// ── REST source stand-in ─────────────────────────────────────────────
+
+fn fixture_server() -> RestServer {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/rest-source");
+    let source = RestSource::load(&dir).unwrap();
+    assert_eq!(source.len(), 2);
+    RestServer::start(source, "127.0.0.1:0").unwrap()
+}
+
+/// Sends one request and returns the status code and body.
+fn http(server: &RestServer, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(server.addr()).unwrap();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, body.to_string())
+}
+
+#[test]
+fn test_rest_source_serves_fixture_manifests() {
+    let server = fixture_server();
+    assert!(server.url().starts_with("http://127.0.0.1:"));
+
+    let (status, body) = http(&server, "GET", "/information", "");
+    assert_eq!(status, 200);
+    let info: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(info["Data"]["SourceIdentifier"], "rest-source");
+
+    let (status, body) = http(
+        &server,
+        "POST",
+        "/manifestSearch",
+        r#"{"Query": {"KeyWord": "contoso-tool", "MatchType": "Exact"}}"#,
+    );
+    assert_eq!(status, 200);
+    let results: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(results["Data"][0]["PackageIdentifier"], "Contoso.Tool");
+    assert_eq!(results["Data"][0]["Versions"].as_array().unwrap().len(), 2);
+
+    let (status, body) = http(&server, "GET", "/packageManifests/Contoso.Agent", "");
+    assert_eq!(status, 200);
+    let manifest: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(
+        manifest["Data"]["Versions"][0]["Installers"][0]["InstallerType"],
+        "exe"
+    );
+
+    let (status, body) = http(&server, "GET", "/packageManifests/Missing.App", "");
+    assert_eq!((status, body.as_str()), (204, ""));
+}
+
+#[test]
+fn test_rest_source_rejects_oversized_bodies() {
+    let server = fixture_server();
+    let mut stream = TcpStream::connect(server.addr()).unwrap();
+    write!(
+        stream,
+        "POST /manifestSearch HTTP/1.1\r\nHost: localhost\r\nContent-Length: 4294967295\r\n\r\n{{}}"
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 413 Payload Too Large"));
+}
+
+#[test]
+fn test_rest_source_rejects_overlong_header_lines() {
+    let server = fixture_server();
+    let mut stream = TcpStream::connect(server.addr()).unwrap();
+    let cookie = "x".repeat(16 * 1024);
+    write!(stream, "GET /information HTTP/1.1\r\nCookie: {cookie}\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 431 Request Header Fields Too Large"));
+}
+
+#[test]
+fn test_rest_source_times_out_idle_clients() {
+    let server = fixture_server();
+    // Connects but never sends a request.
+    let _idle = TcpStream::connect(server.addr()).unwrap();
+    let (status, _) = http(&server, "GET", "/information", "");
+    assert_eq!(status, 200);
+
+    let _idle = TcpStream::connect(server.addr()).unwrap();
+    drop(server);
+}
+
+#[test]
+fn test_rest_source_stops_when_dropped() {
+    let server = fixture_server();
+    let addr = server.addr();
+    drop(server);
+    assert!(TcpStream::connect(addr).is_err());
+}
+
+#[test]
+fn test_rest_source_on_wildcard_address_stops_when_dropped() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/rest-source");
+    let server = RestServer::start(RestSource::load(&dir).unwrap(), "0.0.0.0:0").unwrap();
+    let port = server.addr().port();
+    drop(server);
+    assert!(TcpStream::connect(("127.0.0.1", port)).is_err());
+}
+